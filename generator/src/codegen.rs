@@ -4,11 +4,12 @@ use std::io::{BufWriter, Write};
 
 use anyhow::Context;
 use convert_case::{Case, Casing};
-use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{format_ident, quote};
 use serde_json::Value;
 
-use crate::{Enum, GeneratedManifest, module_tree, naming, ObjectPrototype, Property, Type};
+use crate::{ArrayType, Enum, FixedArrayType, GeneratedManifest, IntEnum, module_tree, naming, ObjectPrototype, Property, references, Type};
+use crate::constraints::{Condition, Constraints};
 use crate::module_builder::ModuleBuilder;
 use crate::module_tree::ModuleTree;
 use crate::naming::{generate_enum_type_identifier, generate_option_identifier, generate_property_identifier};
@@ -46,28 +47,127 @@ fn write_embedded_enum(
     default: &Option<Value>,
 ) -> TokenStream {
     let enum_identifier = generate_enum_type_identifier(property_name);
-    let enum_options = enumeration.options.iter().map(|option| {
-        let identifier = generate_option_identifier(option);
-
+    let identifiers: Vec<Ident> = enumeration
+        .options
+        .iter()
+        .map(|option| generate_option_identifier(option))
+        .collect();
+    let enum_options = enumeration.options.iter().zip(&identifiers).map(|(option, identifier)| {
         let is_default = match &default {
             Some(Value::String(string)) => string == option,
             _ => false,
         };
 
+        // Extensible enums are converted through strings, so their options are not renamed by serde
+        let rename_declaration = (!enumeration.extensible).then(|| quote! { #[serde(rename=#option)] });
         let default_declaration = is_default.then(|| quote! { #[default] });
         quote! {
-            #[serde(rename=#option)]
+            #rename_declaration
             #default_declaration
             #identifier
         }
     });
 
+    let default_declaration = default.as_ref().map(|_| quote! { #[derive(Default)] });
+    if !enumeration.extensible {
+        return quote! {
+            #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+            #default_declaration
+            pub enum #enum_identifier{
+                #(#enum_options),*
+            }
+        };
+    }
+
+    let options = &enumeration.options;
+    quote! {
+        #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+        #default_declaration
+        #[serde(from = "String", into = "String")]
+        pub enum #enum_identifier{
+            #(#enum_options,)*
+            #[doc="A value that is not defined by the specification, e.g. one introduced by an extension."]
+            Other(String),
+        }
+
+        impl From<String> for #enum_identifier {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    #(#options => #enum_identifier::#identifiers,)*
+                    _ => #enum_identifier::Other(value),
+                }
+            }
+        }
+
+        impl From<#enum_identifier> for String {
+            fn from(value: #enum_identifier) -> Self {
+                match value {
+                    #(#enum_identifier::#identifiers => String::from(#options),)*
+                    #enum_identifier::Other(other) => other,
+                }
+            }
+        }
+    }
+}
+
+fn write_embedded_int_enum(
+    property_name: &str,
+    enumeration: &IntEnum,
+    default: &Option<Value>,
+) -> TokenStream {
+    let enum_identifier = generate_enum_type_identifier(property_name);
+    let identifiers: Vec<Ident> = enumeration
+        .options
+        .iter()
+        .map(|option| generate_option_identifier(&option.name))
+        .collect();
+    let values: Vec<Literal> = enumeration
+        .options
+        .iter()
+        .map(|option| Literal::i64_unsuffixed(option.value))
+        .collect();
+    let enum_options = enumeration.options.iter().zip(&identifiers).zip(&values).map(|((option, identifier), value)| {
+        let is_default = default.as_ref().and_then(Value::as_i64) == Some(option.value);
+        let default_declaration = is_default.then(|| quote! { #[default] });
+        quote! {
+            #default_declaration
+            #identifier = #value
+        }
+    });
+
+    // The fallback variant needs a discriminant that does not collide with any of the options
+    let other_discriminant = Literal::i64_unsuffixed(
+        enumeration.options.iter().map(|option| option.value).max().unwrap() + 1,
+    );
+
     let default_declaration = default.as_ref().map(|_| quote! { #[derive(Default)] });
     quote! {
-        #[derive(Serialize, Deserialize, Debug)]
+        #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
         #default_declaration
+        #[serde(from = "i64", into = "i64")]
+        #[repr(u32)]
         pub enum #enum_identifier{
-            #(#enum_options),*
+            #(#enum_options,)*
+            #[doc="A value that is not defined by the specification, e.g. one introduced by an extension."]
+            Other(i64) = #other_discriminant,
+        }
+
+        impl From<i64> for #enum_identifier {
+            fn from(value: i64) -> Self {
+                match value {
+                    #(#values => #enum_identifier::#identifiers,)*
+                    other => #enum_identifier::Other(other),
+                }
+            }
+        }
+
+        impl From<#enum_identifier> for i64 {
+            fn from(value: #enum_identifier) -> Self {
+                match value {
+                    #(#enum_identifier::#identifiers => #values,)*
+                    #enum_identifier::Other(other) => other,
+                }
+            }
         }
     }
 }
@@ -89,6 +189,7 @@ fn write_embedded_type(
                     .unwrap_or_else(|| plural_to_singular(property_name)),
                 prototype,
                 None,
+                None,
                 resolver,
             )
                 .with_context(|| {
@@ -99,29 +200,89 @@ fn write_embedded_type(
                 })?,
         ),
         Type::Enum(enumeration) => Some(write_embedded_enum(property_name, enumeration, default)),
+        Type::IntEnum(enumeration) => {
+            Some(write_embedded_int_enum(property_name, enumeration, default))
+        }
         _ => None,
     })
 }
 
+/// Generates an expression for the default value of a property, or None if the default value
+/// can not be represented by the rust type of the property.
+fn generate_default_value(ty: &Type, default: &Value, field_name: &str) -> Option<TokenStream> {
+    match (ty, default) {
+        (Type::Number, Value::Number(number)) => {
+            let literal = Literal::f64_unsuffixed(number.as_f64()?);
+            Some(quote! { #literal })
+        }
+        (Type::Integer, Value::Number(number)) => {
+            let literal = Literal::i64_unsuffixed(number.as_i64()?);
+            Some(quote! { #literal })
+        }
+        (Type::Boolean, Value::Bool(value)) => Some(quote! { #value }),
+        (Type::String, Value::String(value)) => Some(quote! { String::from(#value) }),
+        (Type::FixedArray(array_type), Value::Array(values))
+        if values.len() == array_type.length as usize =>
+            {
+                let items = values
+                    .iter()
+                    .map(|value| generate_default_value(&array_type.item, value, field_name))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(quote! { [#(#items),*] })
+            }
+        // Embedded enums derive Default when the property has a default value
+        (Type::Enum(_) | Type::IntEnum(_), _) => {
+            let ident = generate_enum_type_identifier(field_name);
+            Some(quote! { #ident::default() })
+        }
+        _ => None,
+    }
+}
+
+/// How the value of a property is stored in its field.
+enum Storage {
+    Required,
+    Optional,
+    /// An optional property with a default value, it is set when it differs from the default.
+    Default,
+    /// An optional array with a minimum length of 1, it is set when it is not empty.
+    NonEmpty,
+}
+
+fn property_storage(property: &Property) -> Storage {
+    let has_default = property
+        .default
+        .as_ref()
+        .and_then(|default| generate_default_value(&property.ty, default, &property.name))
+        .is_some();
+
+    match (&property.ty, property.optional) {
+        (_, false) => Storage::Required,
+        _ if has_default => Storage::Default,
+        // Remove the Option for optional Vec's with a minimum length of 1
+        // This way we can guarantee this invariant by telling serde to not serialize zero length vecs.
+        (Type::Array(array_type), true) if array_type.min_length == Some(1) => Storage::NonEmpty,
+        (_, true) => Storage::Optional,
+    }
+}
+
 fn write_property(
     resolver: &SchemaResolver,
     writer: &mut RustTypeWriter,
     property: &Property,
 ) -> anyhow::Result<TokenStream> {
-    let rust_type = match (&property.ty, property.optional) {
-        // Remove the Option for optional Vec's with a minimum length of 1
-        // This way we can guarantee this invariant by telling serde to not serialize zero length vecs.
-        (Type::Array(array_type), true)
-        if array_type.min_length.is_some() && array_type.min_length.unwrap() == 1 =>
-            {
-                generate_rust_type(resolver, &property.ty, &property.name)
-            }
+    // Optional properties with a default value are not wrapped in an Option, instead serde fills in
+    // the default value when the property is not present.
+    let default_value = property
+        .default
+        .as_ref()
+        .filter(|_| property.optional)
+        .and_then(|default| generate_default_value(&property.ty, default, &property.name));
 
-        (_, true) => {
-            let rust_type: TokenStream = generate_rust_type(resolver, &property.ty, &property.name);
-            quote! { Option::<#rust_type> }
-        }
-        _ => generate_rust_type(resolver, &property.ty, &property.name),
+    let rust_type = generate_rust_type(resolver, &property.ty, &property.name);
+    let rust_type = match property_storage(property) {
+        Storage::Optional => quote! { Option::<#rust_type> },
+        _ => rust_type,
     };
 
     let property_identifier = generate_property_identifier(&property.name);
@@ -133,9 +294,44 @@ fn write_property(
         writer.embedded_types.push(embedded_type);
     }
 
-    let default_declaration = match property.optional {
-        true => Some(quote! { #[serde(default)]}),
-        false => None,
+    let (default_declaration, skip_declaration) = if let Some(default_value) = default_value {
+        // Properties equal to their default value are omitted, like properties that are not set
+        let default_function = format_ident!("default_{}", property_identifier);
+        let is_default_function = format_ident!("is_default_{}", property_identifier);
+        let default_function_name = default_function.to_string();
+        let is_default_function_name = is_default_function.to_string();
+        let doc = format!("The default value of `{}`.", property.name);
+        writer.default_declarations.push(quote! {
+            #[doc=#doc]
+            pub fn #default_function() -> #rust_type {
+                #default_value
+            }
+
+            fn #is_default_function(value: &#rust_type) -> bool {
+                *value == #default_function()
+            }
+        });
+
+        (
+            Some(quote! { #[serde(default = #default_function_name)] }),
+            Some(quote! { #[serde(skip_serializing_if = #is_default_function_name)] }),
+        )
+    } else {
+        let default_declaration = match property.optional {
+            true => Some(quote! { #[serde(default)]}),
+            false => None,
+        };
+
+        // Optional properties that are not set must be omitted, the schema does not allow null values
+        // or empty arrays in their place.
+        let skip_declaration = match (&property.ty, property.optional) {
+            (Type::Array(array_type), true) if array_type.min_length == Some(1) => {
+                Some(quote! { #[serde(skip_serializing_if = "Vec::is_empty")] })
+            }
+            (_, true) => Some(quote! { #[serde(skip_serializing_if = "Option::is_none")] }),
+            _ => None,
+        };
+        (default_declaration, skip_declaration)
     };
 
     // If the property identifier is different from the one in the spec we need to add a serde
@@ -152,14 +348,301 @@ fn write_property(
     Ok(quote! {
         #rename_declaration
         #default_declaration
+        #skip_declaration
         #docstring
         pub #property_identifier: #rust_type
     })
 }
 
+/// Implements `Get` for the collections of an object that are referred to by ids.
+fn write_collection_lookups(
+    schema: &SchemaUri,
+    type_identifier: &Ident,
+    prototype: &ObjectPrototype,
+    resolver: &SchemaResolver,
+) -> Vec<TokenStream> {
+    prototype
+        .properties
+        .iter()
+        .filter(|property| references::is_collection(&schema.path, &property.name))
+        .filter_map(|property| {
+            let Type::Array(array_type) = &property.ty else {
+                return None;
+            };
+            let item_rust_type = generate_rust_type(resolver, &array_type.item, &property.name);
+            let property_identifier = generate_property_identifier(&property.name);
+            Some(quote! {
+                impl crate::Get<#item_rust_type> for #type_identifier {
+                    fn get(&self, index: crate::Index<#item_rust_type>) -> Option<&#item_rust_type> {
+                        self.#property_identifier.get(index.value())
+                    }
+                }
+            })
+        })
+        .collect()
+}
+
+fn optional_tokens<T: quote::ToTokens>(value: Option<T>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
+/// Generates the checks of a value against the constraints of its schema. The checks refer to the
+/// value as `value` and to its location as `pointer`.
+fn write_value_validation(ty: &Type, constraints: &Constraints) -> Option<TokenStream> {
+    match ty {
+        Type::Number | Type::Integer => {
+            if constraints.minimum.is_none()
+                && constraints.exclusive_minimum.is_none()
+                && constraints.maximum.is_none()
+            {
+                return None;
+            }
+            let minimum = optional_tokens(constraints.minimum.map(Literal::f64_unsuffixed));
+            let exclusive_minimum =
+                optional_tokens(constraints.exclusive_minimum.map(Literal::f64_unsuffixed));
+            let maximum = optional_tokens(constraints.maximum.map(Literal::f64_unsuffixed));
+            Some(quote! {
+                crate::validation::check_number(pointer, *value as f64, #minimum, #exclusive_minimum, #maximum, errors);
+            })
+        }
+        Type::String => {
+            if constraints.min_length.is_none() && constraints.pattern.is_none() {
+                return None;
+            }
+            let min_length = optional_tokens(constraints.min_length);
+            let pattern = optional_tokens(constraints.pattern.as_ref());
+            Some(quote! {
+                crate::validation::check_string(pointer, value, #min_length, #pattern, errors);
+            })
+        }
+        Type::Array(ArrayType { item, .. }) | Type::FixedArray(FixedArrayType { item, .. }) => {
+            let no_constraints = Constraints::default();
+            let item_constraints = constraints.items.as_deref().unwrap_or(&no_constraints);
+            let check = write_value_validation(item, item_constraints)?;
+            Some(quote! {
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    #check
+                }
+            })
+        }
+        Type::TypedObject(_) | Type::EmbeddedObject { .. } => Some(quote! {
+            crate::validation::Validate::validate_at(value, pointer, errors);
+        }),
+        _ => None,
+    }
+}
+
+fn write_property_validation(property: &Property) -> Option<TokenStream> {
+    let check = write_value_validation(&property.ty, &property.constraints)?;
+    let property_identifier = generate_property_identifier(&property.name);
+    let name = &property.name;
+    let value = match property_storage(property) {
+        Storage::Optional => quote! {
+            if let Some(value) = &self.#property_identifier {
+                #check
+            }
+        },
+        _ => quote! {
+            let value = &self.#property_identifier;
+            #check
+        },
+    };
+    Some(quote! {
+        {
+            let pointer = &format!("{}/{}", pointer, #name);
+            #value
+        }
+    })
+}
+
+/// Generates an expression for a constant value of a property, used to compare the property against.
+fn generate_constant_value(ty: &Type, value: &Value, field_name: &str) -> Option<TokenStream> {
+    let option_name = match (ty, value) {
+        (Type::Enum(enumeration), Value::String(option)) => {
+            enumeration.options.iter().find(|name| *name == option)?
+        }
+        (Type::IntEnum(enumeration), Value::Number(number)) => enumeration
+            .options
+            .iter()
+            .find(|option| Some(option.value) == number.as_i64())
+            .map(|option| &option.name)?,
+        (Type::Enum(_) | Type::IntEnum(_), _) => return None,
+        _ => return generate_default_value(ty, value, field_name),
+    };
+    let enum_identifier = generate_enum_type_identifier(field_name);
+    let option_identifier = generate_option_identifier(option_name);
+    Some(quote! { #enum_identifier::#option_identifier })
+}
+
+fn find_property<'a>(prototype: &'a ObjectPrototype, name: &str) -> anyhow::Result<&'a Property> {
+    prototype
+        .properties
+        .iter()
+        .find(|property| property.name == name)
+        .with_context(|| format!("constraint refers to unknown property {name}"))
+}
+
+/// Generates an expression that is true when a property is set. Properties with a default value
+/// are considered set when they differ from the default, since their presence is not recorded.
+fn write_presence(prototype: &ObjectPrototype, name: &str) -> anyhow::Result<TokenStream> {
+    let property = find_property(prototype, name)?;
+    let property_identifier = generate_property_identifier(&property.name);
+    Ok(match property_storage(property) {
+        Storage::Required => quote! { true },
+        Storage::Optional => quote! { self.#property_identifier.is_some() },
+        Storage::Default => {
+            let is_default_function = format_ident!("is_default_{}", property_identifier);
+            quote! { !#is_default_function(&self.#property_identifier) }
+        }
+        Storage::NonEmpty => quote! { !self.#property_identifier.is_empty() },
+    })
+}
+
+/// Returns true if the expression of a condition combines several operands, requiring parentheses
+/// when it is nested in another condition.
+fn is_compound(condition: &Condition) -> bool {
+    match condition {
+        Condition::Required(names) => names.len() > 1,
+        Condition::Const(..) => false,
+        Condition::All(conditions) | Condition::Any(conditions) => match conditions.as_slice() {
+            [condition] => is_compound(condition),
+            conditions => conditions.len() > 1,
+        },
+    }
+}
+
+fn write_condition(
+    prototype: &ObjectPrototype,
+    condition: &Condition,
+) -> anyhow::Result<TokenStream> {
+    let write_operands = |conditions: &[Condition]| {
+        conditions
+            .iter()
+            .map(|condition| {
+                let expression = write_condition(prototype, condition)?;
+                Ok(if is_compound(condition) {
+                    quote! { (#expression) }
+                } else {
+                    expression
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+    };
+
+    let (operands, operator) = match condition {
+        Condition::All(conditions) | Condition::Any(conditions) if conditions.len() == 1 => {
+            return write_condition(prototype, &conditions[0]);
+        }
+        Condition::Required(names) => (
+            names
+                .iter()
+                .map(|name| write_presence(prototype, name))
+                .collect::<anyhow::Result<Vec<_>>>()?,
+            quote! { && },
+        ),
+        Condition::Const(name, value) => {
+            let property = find_property(prototype, name)?;
+            let property_identifier = generate_property_identifier(&property.name);
+            let constant = generate_constant_value(&property.ty, value, &property.name)
+                .with_context(|| format!("unsupported constant {value} for property {name}"))?;
+            return Ok(match property_storage(property) {
+                Storage::Optional => quote! { self.#property_identifier == Some(#constant) },
+                _ => quote! { self.#property_identifier == #constant },
+            });
+        }
+        Condition::All(conditions) => (write_operands(conditions)?, quote! { && }),
+        Condition::Any(conditions) => (write_operands(conditions)?, quote! { || }),
+    };
+
+    match operands.split_first() {
+        Some((first, rest)) => Ok(quote! { #first #(#operator #rest)* }),
+        None => Ok(quote! { true }),
+    }
+}
+
+fn write_object_validation(prototype: &ObjectPrototype) -> anyhow::Result<Vec<TokenStream>> {
+    let constraints = &prototype.constraints;
+    let mut checks = Vec::new();
+
+    for (name, dependencies) in &constraints.dependencies {
+        let presence = write_presence(prototype, name)?;
+        for dependency in dependencies {
+            let dependency_presence = write_presence(prototype, dependency)?;
+            checks.push(quote! {
+                if #presence && !#dependency_presence {
+                    errors.push(crate::validation::ValidationError {
+                        pointer: format!("{}/{}", pointer, #name),
+                        kind: crate::validation::ValidationErrorKind::MissingDependency {
+                            property: String::from(#name),
+                            dependency: String::from(#dependency),
+                        },
+                    });
+                }
+            });
+        }
+    }
+
+    if let Some(not) = &constraints.not {
+        let condition = write_condition(prototype, not)?;
+        checks.push(quote! {
+            if #condition {
+                errors.push(crate::validation::ValidationError {
+                    pointer: pointer.to_string(),
+                    kind: crate::validation::ValidationErrorKind::ForbiddenCombination,
+                });
+            }
+        });
+    }
+
+    if !constraints.one_of.is_empty() {
+        let conditions = constraints
+            .one_of
+            .iter()
+            .map(|condition| write_condition(prototype, condition))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        checks.push(quote! {
+            let matches = [#(#conditions),*].into_iter().filter(|matches| *matches).count();
+            if matches != 1 {
+                errors.push(crate::validation::ValidationError {
+                    pointer: pointer.to_string(),
+                    kind: crate::validation::ValidationErrorKind::OneOfMismatch(matches),
+                });
+            }
+        });
+    }
+
+    Ok(checks)
+}
+
+/// Implements `Validate`, checking the constraints of the schema that the type can not express.
+fn write_validation(
+    type_identifier: &Ident,
+    prototype: &ObjectPrototype,
+) -> anyhow::Result<TokenStream> {
+    let object_checks = write_object_validation(prototype)?;
+    let property_checks = prototype
+        .properties
+        .iter()
+        .filter_map(write_property_validation);
+    Ok(quote! {
+        impl crate::validation::Validate for #type_identifier {
+            #[allow(unused_variables)]
+            fn validate_at(&self, pointer: &str, errors: &mut Vec<crate::validation::ValidationError>) {
+                #(#object_checks)*
+                #(#property_checks)*
+            }
+        }
+    })
+}
+
 fn generate_structure(
     name: &str,
     prototype: &ObjectPrototype,
+    schema: Option<&SchemaUri>,
     extension: Option<&str>,
     resolver: &SchemaResolver,
 ) -> anyhow::Result<TokenStream> {
@@ -210,8 +693,15 @@ fn generate_structure(
         None
     };
 
+    let validation = write_validation(&type_identifier, prototype)
+        .with_context(|| format!("failed to generate validation for {name}"))?;
+
+    let collection_lookups = schema
+        .map(|schema| write_collection_lookups(schema, &type_identifier, prototype, resolver))
+        .unwrap_or_default();
+
     Ok(quote! {
-        mod #mod_identifier{
+        pub mod #mod_identifier{
             use serde::{Serialize, Deserialize};
             use serde_json::{Map, Value};
 
@@ -220,13 +710,20 @@ fn generate_structure(
             #[derive(Serialize, Deserialize, Debug)]
             #doc
             pub struct #type_identifier{
-                #(#property_tokens),*
+                #(#property_tokens,)*
+                #[serde(flatten)]
+                #[doc="Properties that are not part of the schema, kept so they survive a round trip."]
+                pub unknown: Map<String, Value>,
             }
 
             #gltf_extension_trait
 
             #gltf_object_trait
 
+            #validation
+
+            #(#collection_lookups)*
+
             #(#default_declarations)*
 
         }
@@ -266,11 +763,15 @@ fn generate_rust_type(resolver: &SchemaResolver, ty: &Type, field_name: &String)
         Type::Integer => quote! { i64 },
         Type::Number => quote! { f64 },
         Type::String => quote! { String },
-        Type::Enum(_) => {
+        Type::Enum(_) | Type::IntEnum(_) => {
             let ident = Ident::new(&field_name.to_case(Case::UpperCamel), Span::call_site());
             quote! { #ident }
         }
         Type::TypedObject(uri) => generate_named_type_path(resolver, uri),
+        Type::Index(item) => {
+            let item_rust_type = generate_rust_type(resolver, item, field_name);
+            quote! { crate::Index<#item_rust_type> }
+        }
         Type::MapOfObjects => quote! { Map<String, Value> },
         Type::EmbeddedObject { name, prototype: _ } => {
             let ident = Ident::new(
@@ -290,14 +791,20 @@ pub fn write_submodule(resolver: &SchemaResolver, item: &module_tree::Item) -> a
         let ident = Ident::new(name, Span::call_site());
         let module_contents = write_submodule(resolver, module)?;
         Ok(quote! {
-            mod #ident{
+            pub mod #ident{
                 #module_contents
             }
         })
     }).collect::<anyhow::Result<Vec<_>>>()?;
 
     let contained_types: Vec<TokenStream> = item.objects.iter().map(|ty| {
-        generate_structure(&ty.name, &ty.prototype, ty.extension.as_deref(), resolver)
+        generate_structure(
+            &ty.name,
+            &ty.prototype,
+            Some(&ty.schema),
+            ty.extension.as_deref(),
+            resolver,
+        )
     }).collect::<anyhow::Result<Vec<_>>>()?;
 
     Ok(quote! {
@@ -307,13 +814,40 @@ pub fn write_submodule(resolver: &SchemaResolver, item: &module_tree::Item) -> a
     })
 }
 
+/// Re-exports every extension object at the root of its module, named after the object it extends.
+/// For example `node::Extension` becomes available as `NodeExtension`. The extension of the glTF
+/// root object is named `RootExtension`, so that it does not shadow the `GltfExtension` trait.
+fn write_extension_exports(module: &ModuleBuilder) -> Vec<TokenStream> {
+    module
+        .types
+        .values()
+        .filter(|ty| ty.extension.is_some())
+        .map(|ty| {
+            let path = ty
+                .module_path
+                .iter()
+                .map(|segment| Ident::new(segment, Span::call_site()));
+            let type_identifier = naming::generate_type_identifier(&ty.name);
+            let object = match ty.module_path.join("_") {
+                object if object == "gltf" => String::from("root"),
+                object => object,
+            };
+            let alias = naming::generate_type_identifier(&format!("{}_{}", object, ty.name));
+            quote! { pub use #(#path::)*#type_identifier as #alias; }
+        })
+        .collect()
+}
+
 pub fn write_module(module: &ModuleBuilder) -> anyhow::Result<()> {
     let tree = ModuleTree::build(module);
     let contents = write_submodule(module.resolver, &tree.root)?;
+    let extension_exports = write_extension_exports(module);
 
     let rust = quote! {
         #![allow(clippy::all, unused_imports)]
 
+        #(#extension_exports)*
+
         #contents
     };
 
@@ -331,18 +865,51 @@ pub fn write_root_module(generated_path: &str, generated_manifest: &GeneratedMan
     let output = File::create(format!("{generated_path}/mod.rs")).unwrap();
     let mut writer = BufWriter::new(output);
 
-    let extension_modules: Vec<TokenStream> = generated_manifest
+    let extension_idents: Vec<Ident> = generated_manifest
         .extension_modules
         .iter()
-        .map(|module_name| {
-            let ident = Ident::new(module_name, Span::call_site());
-            quote! { pub mod #ident; }
-        })
+        .map(|module_name| Ident::new(module_name, Span::call_site()))
         .collect();
 
+    // Every extension object is parsed into its type by the object it extends and its name
+    let extension_parsers = generated_manifest.extension_objects.iter().map(|extension_object| {
+        let module = Ident::new(&extension_object.module, Span::call_site());
+        let object_module = Ident::new(&extension_object.object, Span::call_site());
+        let object = &extension_object.object;
+        let extension = &extension_object.extension;
+        quote! {
+            (#object, #extension) => parse::<#module::#object_module::Extension>(value),
+        }
+    });
+
     let rust_file: syn::File = syn::parse2(quote! {
         pub mod gltf;
-        #(#extension_modules)*
+        #(pub mod #extension_idents;)*
+
+        /// Groups the modules generated for each extension schema.
+        pub mod extensions {
+            #(pub use super::#extension_idents;)*
+        }
+
+        /// Parses an extension of an object into the type generated for it. The object is named by
+        /// its module in the extension modules, e.g. `node` or `mesh_primitive`. Returns None if no
+        /// type is generated for the extension on that object.
+        pub(crate) fn parse_extension(
+            object: &str,
+            extension: &str,
+            value: &serde_json::Value,
+        ) -> Option<Result<Box<dyn std::any::Any + Send + Sync>, serde_json::Error>> {
+            fn parse<T: crate::GltfExtension + Send + Sync + 'static>(
+                value: &serde_json::Value,
+            ) -> Result<Box<dyn std::any::Any + Send + Sync>, serde_json::Error> {
+                T::deserialize(value).map(|extension| Box::new(extension) as _)
+            }
+
+            Some(match (object, extension) {
+                #(#extension_parsers)*
+                _ => return None,
+            })
+        }
     })
         .unwrap();
 
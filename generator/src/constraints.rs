@@ -0,0 +1,125 @@
+//! Constraints of the schemas that the generated types can not express, checked by the generated
+//! `validate` methods instead.
+
+use serde_json::Value;
+
+use crate::schema::{Dependency, Schema, SchemaContext};
+
+/// Constraints on the value of a property.
+#[derive(Default, PartialEq)]
+pub struct Constraints {
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+    pub exclusive_minimum: Option<f64>,
+    pub min_length: Option<usize>,
+    pub pattern: Option<String>,
+    /// Constraints on the items of an array.
+    pub items: Option<Box<Constraints>>,
+}
+
+impl Constraints {
+    pub fn read(context: &SchemaContext, schema: &Schema) -> Self {
+        let items = schema
+            .items(context)
+            .map(|(context, items)| Constraints::read(&context, items))
+            .filter(|items| !items.is_empty())
+            .map(Box::new);
+
+        Constraints {
+            minimum: schema.minimum(),
+            maximum: schema.maximum(),
+            exclusive_minimum: schema.exclusive_minimum(),
+            min_length: schema.min_length(),
+            pattern: schema.pattern().map(String::from),
+            items,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == Constraints::default()
+    }
+}
+
+/// A condition on the properties of an object.
+pub enum Condition {
+    /// All of the properties are present.
+    Required(Vec<String>),
+    /// A property holds a constant value.
+    Const(String, Value),
+    All(Vec<Condition>),
+    Any(Vec<Condition>),
+}
+
+impl Condition {
+    /// Reads a condition from a schema, only `required`, `anyOf` and properties with a `const`
+    /// value are supported.
+    fn read(context: &SchemaContext, schema: &Schema) -> anyhow::Result<Self> {
+        let mut conditions = Vec::new();
+        if !schema.required().is_empty() {
+            conditions.push(Condition::Required(schema.required().to_vec()));
+        }
+
+        for (_, name, property) in schema.properties(context) {
+            match property.const_value() {
+                Some(value) => conditions.push(Condition::Const(name.to_string(), value.clone())),
+                None => anyhow::bail!("property {name} of a condition is not a constant"),
+            }
+        }
+
+        let any_of = schema
+            .any_of(context)
+            .map(|(context, schema)| Condition::read(&context, schema))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        if !any_of.is_empty() {
+            conditions.push(Condition::Any(any_of));
+        }
+
+        Ok(Condition::All(conditions))
+    }
+}
+
+/// Constraints between the properties of an object.
+#[derive(Default)]
+pub struct ObjectConstraints {
+    /// Properties that require other properties to be present.
+    pub dependencies: Vec<(String, Vec<String>)>,
+    /// A condition the object must not match.
+    pub not: Option<Condition>,
+    /// Conditions of which the object must match exactly one.
+    pub one_of: Vec<Condition>,
+}
+
+impl ObjectConstraints {
+    pub fn read(context: &SchemaContext, schema: &Schema) -> anyhow::Result<Self> {
+        let dependencies = schema
+            .dependencies()
+            .filter_map(|(name, dependency)| match dependency {
+                Dependency::Properties(properties) => Some((name.clone(), properties.clone())),
+                Dependency::Schema(_) => {
+                    println!(
+                        "Schema dependency of {name} in {} is not supported",
+                        context.uri()
+                    );
+                    None
+                }
+            })
+            .collect();
+
+        let not = schema
+            .not()
+            .map(|not| Condition::read(context, not))
+            .transpose()?;
+
+        let one_of = schema
+            .one_of()
+            .iter()
+            .map(|option| Condition::read(context, option))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(ObjectConstraints {
+            dependencies,
+            not,
+            one_of,
+        })
+    }
+}
@@ -1,6 +1,5 @@
 use std::collections::HashMap;
 use std::fs::read_dir;
-use std::io::Write;
 use std::vec::Vec;
 
 use anyhow::Context;
@@ -8,10 +7,12 @@ use convert_case::{Case, Casing};
 use itertools::Itertools;
 use serde_json::Value;
 
+use crate::constraints::{Constraints, ObjectConstraints};
 use crate::module_builder::{ModuleBuilder, TypeDescription};
 use crate::schema::{Schema, SchemaContext, SchemaResolver, SchemaStore, SchemaStoreMeta};
 use crate::schema_uri::SchemaUri;
 
+mod constraints;
 mod module_builder;
 mod naming;
 mod schema;
@@ -19,9 +20,21 @@ mod schema_uri;
 mod type_deduction;
 mod codegen;
 mod module_tree;
+mod references;
 
 pub struct Enum {
     options: Vec<String>,
+    /// Whether values other than the options are allowed, i.e. the enum is an anyOf that includes a plain string.
+    extensible: bool,
+}
+
+pub struct IntEnumOption {
+    value: i64,
+    name: String,
+}
+
+pub struct IntEnum {
+    options: Vec<IntEnumOption>,
 }
 
 pub struct ArrayType {
@@ -48,12 +61,16 @@ pub enum Type {
     Number,
     Integer,
     Enum(Enum),
+    IntEnum(IntEnum),
+    /// An id referring to an object of the contained type, see `references`.
+    Index(Box<Type>),
     MapOfObjects,
 }
 
 pub struct ObjectPrototype {
     pub comment: Option<String>,
     pub properties: Vec<Property>,
+    pub constraints: ObjectConstraints,
 }
 
 pub struct ObjectType {
@@ -68,6 +85,7 @@ pub struct Property {
     pub optional: bool,
     pub default: Option<Value>,
     pub comment: Option<String>,
+    pub constraints: Constraints,
 }
 
 struct PropertyListBuilder {
@@ -94,6 +112,7 @@ impl PropertyListBuilder {
                 optional: true,
                 comment: None,
                 default: None,
+                constraints: Constraints::default(),
             });
             self.properties.last_mut().unwrap()
         }
@@ -160,6 +179,10 @@ impl PropertyListBuilder {
                 property.default = field_schema.default().cloned();
             }
 
+            if property.constraints.is_empty() {
+                property.constraints = Constraints::read(&context, field_schema);
+            }
+
             if schema.required().iter().contains(&name.to_string()) {
                 property.optional = false;
             }
@@ -180,8 +203,9 @@ fn load_extensions(
         .filter(|entry| {
             entry
                 .file_type()
-                .map_or(false, |file_type| file_type.is_dir())
+                .is_ok_and(|file_type| file_type.is_dir())
         })
+        .sorted_by_key(|entry| entry.file_name())
     {
         // Figure out the extension name and vendor prefix
         let extension_name = entry.file_name().to_string_lossy().to_string();
@@ -198,7 +222,7 @@ fn load_extensions(
         schemas_path.push("schema");
         let extension_schema_suffix = format!("{}.schema.json", &extension_name);
 
-        let mut extension_schema_store = SchemaStore::read(
+        let extension_schema_store = SchemaStore::read(
             SchemaStoreMeta::Extension(extension_name.clone()),
             &schemas_path.to_string_lossy(),
         )
@@ -207,12 +231,12 @@ fn load_extensions(
 
         let mut specification_builder = ModuleBuilder::new(
             generated_path,
-            &extension_name,
+            &extension_module_name,
             &resolver,
             &extension_schema_store,
         );
 
-        for (context, schema) in extension_schema_store.schemas() {
+        for (context, _) in extension_schema_store.schemas() {
             // If a schema ends with {Prefix}.ExtensionName.schema.json it represents the extension object with the extension name on that object
             let uri = context.uri();
 
@@ -234,6 +258,12 @@ fn load_extensions(
             let base_module_name = naming::generate_base_module_identifier(base_object_name).to_string();
 
 
+            generated_manifest.extension_objects.push(ExtensionObject {
+                module: extension_module_name.clone(),
+                object: base_module_name.clone(),
+                extension: extension_name.clone(),
+            });
+
             specification_builder.push(TypeDescription {
                 schema: uri.clone(),
                 module_path_override: Some(vec![base_module_name]),
@@ -254,12 +284,24 @@ fn load_extensions(
 
 struct GeneratedManifest {
     extension_modules: Vec<String>,
+    extension_objects: Vec<ExtensionObject>,
+}
+
+/// An object an extension adds to an object of the core specification
+struct ExtensionObject {
+    /// Module generated for the extension
+    module: String,
+    /// Module of the extended object within the extension module, e.g. `node`
+    object: String,
+    /// Name of the extension as used in the extensions of an object
+    extension: String,
 }
 
 impl GeneratedManifest {
     fn new() -> Self {
         Self {
             extension_modules: Vec::new(),
+            extension_objects: Vec::new(),
         }
     }
 }
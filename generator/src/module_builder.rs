@@ -4,6 +4,7 @@ use std::sync::Arc;
 use anyhow::Context;
 
 use crate::{codegen, naming, ObjectPrototype, ObjectType, PropertyListBuilder, Type};
+use crate::constraints::ObjectConstraints;
 use crate::schema::{Schema, SchemaContext, SchemaResolver, SchemaStore};
 use crate::schema_uri::SchemaUri;
 
@@ -15,6 +16,7 @@ pub struct TypeDescription {
 }
 
 pub struct ResolvedType {
+    pub schema: SchemaUri,
     pub module_path: Vec<String>,
     pub name: String,
     pub prototype: ObjectPrototype,
@@ -43,11 +45,15 @@ fn read_typed_object(
         .recursive_read_properties(resolver, context, schema)
         .with_context(|| format!("Failed to read properties for schema {}", context.uri()))
         .unwrap();
+    let constraints = ObjectConstraints::read(context, schema)
+        .with_context(|| format!("Failed to read constraints for schema {}", context.uri()))
+        .unwrap();
     ObjectType {
         name,
         prototype: ObjectPrototype {
             comment,
             properties: properties.properties,
+            constraints,
         },
     }
 }
@@ -89,6 +95,7 @@ impl<'a> ModuleBuilder<'a> {
                 })
             }
             Type::Array(array) => self.visit_type(&array.item),
+            Type::Index(item) => self.visit_type(item),
             _ => (),
         }
     }
@@ -113,6 +120,7 @@ impl<'a> ModuleBuilder<'a> {
                 ty.schema.clone(),
                 Arc::new(
                     ResolvedType {
+                        schema: ty.schema.clone(),
                         name: ty.name_override.unwrap_or(object_type.name),
                         module_path: ty.module_path_override.unwrap_or(vec![]),
                         prototype: object_type.prototype,
@@ -1,9 +1,10 @@
+//! Builds a tree from a flat module representation
+
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use crate::module_builder::{ModuleBuilder, ResolvedType};
 
-///! Builds a tree from a flat module representation
 #[derive(Default)]
 pub struct Item {
     pub children: BTreeMap<String, Item>,
@@ -15,10 +16,10 @@ pub struct ModuleTree {
 }
 
 fn insert(item: &mut Item, path: &[String], ty: &Arc<ResolvedType>) {
-    if path.len() == 0 {
+    if path.is_empty() {
         item.objects.push(ty.clone());
     } else {
-        let item = item.children.entry(path[0].clone()).or_insert_with(Item::default);
+        let item = item.children.entry(path[0].clone()).or_default();
         insert(item, &path[1..], ty);
     }
 }
@@ -0,0 +1,190 @@
+//! Known targets of references to glTFid.schema.json
+//!
+//! The schemas only describe an id as a non-negative integer, the collection it indexes into is
+//! mentioned in the description of the property. This table provides that collection so ids can be
+//! generated as an `Index` of the type they refer to.
+
+use crate::schema_uri::SchemaUri;
+
+/// A collection of objects that an id indexes into, an array property of a schema.
+pub struct Collection {
+    pub schema: &'static str,
+    pub property: &'static str,
+}
+
+const fn collection(schema: &'static str, property: &'static str) -> Collection {
+    Collection { schema, property }
+}
+
+const ACCESSORS: Collection = collection("glTF.schema.json", "accessors");
+const BUFFER_VIEWS: Collection = collection("glTF.schema.json", "bufferViews");
+const BUFFERS: Collection = collection("glTF.schema.json", "buffers");
+const CAMERAS: Collection = collection("glTF.schema.json", "cameras");
+const IMAGES: Collection = collection("glTF.schema.json", "images");
+const MATERIALS: Collection = collection("glTF.schema.json", "materials");
+const MESHES: Collection = collection("glTF.schema.json", "meshes");
+const NODES: Collection = collection("glTF.schema.json", "nodes");
+const SAMPLERS: Collection = collection("glTF.schema.json", "samplers");
+const SCENES: Collection = collection("glTF.schema.json", "scenes");
+const SKINS: Collection = collection("glTF.schema.json", "skins");
+const TEXTURES: Collection = collection("glTF.schema.json", "textures");
+
+/// The collection referred to by each id, keyed by the schema and the name of the property holding the id.
+const REFERENCES: &[(&str, &str, Collection)] = &[
+    ("accessor.schema.json", "bufferView", BUFFER_VIEWS),
+    (
+        "accessor.sparse.indices.schema.json",
+        "bufferView",
+        BUFFER_VIEWS,
+    ),
+    (
+        "accessor.sparse.values.schema.json",
+        "bufferView",
+        BUFFER_VIEWS,
+    ),
+    (
+        "animation.channel.schema.json",
+        "sampler",
+        collection("animation.schema.json", "samplers"),
+    ),
+    ("animation.channel.target.schema.json", "node", NODES),
+    ("animation.sampler.schema.json", "input", ACCESSORS),
+    ("animation.sampler.schema.json", "output", ACCESSORS),
+    ("bufferView.schema.json", "buffer", BUFFERS),
+    ("glTF.schema.json", "scene", SCENES),
+    ("image.schema.json", "bufferView", BUFFER_VIEWS),
+    ("mesh.primitive.schema.json", "indices", ACCESSORS),
+    ("mesh.primitive.schema.json", "material", MATERIALS),
+    ("node.schema.json", "camera", CAMERAS),
+    ("node.schema.json", "children", NODES),
+    ("node.schema.json", "mesh", MESHES),
+    ("node.schema.json", "skin", SKINS),
+    ("scene.schema.json", "nodes", NODES),
+    ("skin.schema.json", "inverseBindMatrices", ACCESSORS),
+    ("skin.schema.json", "joints", NODES),
+    ("skin.schema.json", "skeleton", NODES),
+    ("texture.schema.json", "sampler", SAMPLERS),
+    ("texture.schema.json", "source", IMAGES),
+    ("textureInfo.schema.json", "index", TEXTURES),
+    // Extensions
+    (
+        "bufferView.EXT_meshopt_compression.schema.json",
+        "buffer",
+        BUFFERS,
+    ),
+    ("imageBasedLight.schema.json", "specularImages", IMAGES),
+    ("light.profile.schema.json", "bufferView", BUFFER_VIEWS),
+    ("material.MSFT_lod.schema.json", "ids", MATERIALS),
+    (
+        "mesh.EXT_mesh_manifold.schema.json",
+        "mergeIndices",
+        ACCESSORS,
+    ),
+    (
+        "mesh.EXT_mesh_manifold.schema.json",
+        "mergeValues",
+        ACCESSORS,
+    ),
+    (
+        "mesh.primitive.CESIUM_primitive_outline.schema.json",
+        "indices",
+        ACCESSORS,
+    ),
+    (
+        "mesh.primitive.KHR_draco_mesh_compression.schema.json",
+        "bufferView",
+        BUFFER_VIEWS,
+    ),
+    (
+        "mesh.primitive.KHR_materials_variants.schema.json",
+        "material",
+        MATERIALS,
+    ),
+    (
+        "node.EXT_lights_ies.schema.json",
+        "light",
+        collection("glTF.EXT_lights_ies.schema.json", "lights"),
+    ),
+    (
+        "node.KHR_lights_punctual.schema.json",
+        "light",
+        collection("glTF.KHR_lights_punctual.schema.json", "lights"),
+    ),
+    ("node.MSFT_lod.schema.json", "ids", NODES),
+    (
+        "scene.EXT_lights_image_based.schema.json",
+        "light",
+        collection("glTF.EXT_lights_image_based.schema.json", "lights"),
+    ),
+    ("texture.EXT_texture_webp.schema.json", "source", IMAGES),
+    ("texture.KHR_texture_basisu.schema.json", "source", IMAGES),
+    ("texture.MSFT_texture_dds.schema.json", "source", IMAGES),
+];
+
+/// The objects extended by extension schemas that are not named after them, keyed by the schema.
+/// Most of these are named after the glTF root, CESIUM_primitive_outline leaves out the mesh.
+const EXTENDED_OBJECTS: &[(&str, &[&str])] = &[
+    (
+        "glTF.ADOBE_materials_clearcoat_specular.schema.json",
+        &["material"],
+    ),
+    (
+        "glTF.ADOBE_materials_thin_transparency.schema.json",
+        &["material"],
+    ),
+    ("glTF.EXT_mesh_gpu_instancing.schema.json", &["node"]),
+    ("glTF.EXT_texture_webp.schema.json", &["texture"]),
+    ("glTF.MSFT_lod.schema.json", &["node", "material"]),
+    (
+        "glTF.MSFT_packing_normalRoughnessMetallic.schema.json",
+        &["material"],
+    ),
+    (
+        "glTF.MSFT_packing_occlusionRoughnessMetallic.schema.json",
+        &["material"],
+    ),
+    ("glTF.MSFT_texture_dds.schema.json", &["texture"]),
+    (
+        "primitive.CESIUM_primitive_outline.schema.json",
+        &["mesh.primitive"],
+    ),
+];
+
+/// Finds the collection referred to by an id, given the location of the id in its schema.
+/// For example `node.schema.json#properties/children/items` refers to the nodes of the glTF root.
+pub fn find_collection(uri: &SchemaUri) -> Option<&'static Collection> {
+    // The id belongs to the innermost property, ids in embedded objects are keyed by their own name
+    let fragment = uri.fragment.as_deref()?;
+    let (_, property) = fragment.rsplit_once("properties/")?;
+    let property = property.split('/').next()?;
+
+    REFERENCES
+        .iter()
+        .find(|(schema, name, _)| *schema == uri.path && *name == property)
+        .map(|(_, _, collection)| collection)
+}
+
+/// Returns the names a schema file is known by. Extension schemas listed in `EXTENDED_OBJECTS` are
+/// renamed after each object they extend, for example `glTF.EXT_texture_webp.schema.json` becomes
+/// `texture.EXT_texture_webp.schema.json`.
+pub fn extended_schemas(file_name: &str) -> Vec<String> {
+    let objects = EXTENDED_OBJECTS
+        .iter()
+        .find(|(schema, _)| *schema == file_name)
+        .map(|(_, objects)| *objects);
+
+    match (objects, file_name.split_once('.')) {
+        (Some(objects), Some((_, extension))) => objects
+            .iter()
+            .map(|object| format!("{}.{}", object, extension))
+            .collect(),
+        _ => vec![file_name.to_string()],
+    }
+}
+
+/// Returns true if a property of a schema is the target of ids.
+pub fn is_collection(schema: &str, property: &str) -> bool {
+    REFERENCES
+        .iter()
+        .any(|(_, _, collection)| collection.schema == schema && collection.property == property)
+}
@@ -1,14 +1,15 @@
 use std::collections::BTreeMap;
 use std::default::Default;
 use std::error::Error;
-use std::fs::{read_dir, File};
-use std::io::{BufReader, ErrorKind};
+use std::fs::{read_dir, read_to_string};
+use std::io::ErrorKind;
 use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use thiserror::Error;
 
+use crate::references;
 use crate::schema_uri::SchemaUri;
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -49,17 +50,9 @@ impl SchemaContext {
         &self.meta
     }
 
-    pub fn relative_url(&self) -> &str {
-        &self.uri.path
-    }
-
     pub fn is_schema_root(&self) -> bool {
         self.uri.is_schema_root()
     }
-
-    pub fn definition_name(&self) -> Option<&str> {
-        self.uri.definition_name()
-    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Default)]
@@ -82,6 +75,32 @@ pub struct ObjectRules {
     additional_properties: Schema,
 
     required: Vec<String>,
+
+    dependencies: BTreeMap<String, Dependency>,
+}
+
+/// A dependency of an object property, either other properties that must be present or a schema
+/// the object must match when the property is present.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum Dependency {
+    Properties(Vec<String>),
+    Schema(Schema),
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NumberRules {
+    minimum: Option<f64>,
+    maximum: Option<f64>,
+    exclusive_minimum: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct StringRules {
+    min_length: Option<usize>,
+    pattern: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default)]
@@ -114,6 +133,16 @@ pub struct SchemaObject {
     #[serde(flatten)]
     array_rules: ArrayRules,
 
+    #[serde(flatten)]
+    number_rules: NumberRules,
+
+    #[serde(flatten)]
+    string_rules: StringRules,
+
+    not: Option<Box<Schema>>,
+
+    one_of: Vec<Schema>,
+
     #[serde(rename = "const")]
     const_value: Option<Value>,
 
@@ -141,9 +170,6 @@ impl Schema {
     pub fn true_schema() -> Schema {
         Schema::Boolean(true)
     }
-    pub fn false_schema() -> Schema {
-        Schema::Boolean(false)
-    }
 
     pub fn title(&self) -> Option<&str> {
         match self {
@@ -192,7 +218,7 @@ impl Schema {
         }
     }
 
-    pub fn instance_type(&self) -> InstanceTypes {
+    pub fn instance_type(&self) -> InstanceTypes<'_> {
         InstanceTypes(match self {
             Schema::Object(object) => object.as_ref().ty.as_ref(),
             _ => None,
@@ -271,6 +297,64 @@ impl Schema {
             _ => None,
         }
     }
+
+    pub fn minimum(&self) -> Option<f64> {
+        match self {
+            Schema::Object(object) => object.number_rules.minimum,
+            _ => None,
+        }
+    }
+
+    pub fn maximum(&self) -> Option<f64> {
+        match self {
+            Schema::Object(object) => object.number_rules.maximum,
+            _ => None,
+        }
+    }
+
+    pub fn exclusive_minimum(&self) -> Option<f64> {
+        match self {
+            Schema::Object(object) => object.number_rules.exclusive_minimum,
+            _ => None,
+        }
+    }
+
+    pub fn min_length(&self) -> Option<usize> {
+        match self {
+            Schema::Object(object) => object.string_rules.min_length,
+            _ => None,
+        }
+    }
+
+    pub fn pattern(&self) -> Option<&str> {
+        match self {
+            Schema::Object(object) => object.string_rules.pattern.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn dependencies(&self) -> impl Iterator<Item = (&String, &Dependency)> {
+        match self {
+            Schema::Object(object) => Some(object.object_rules.dependencies.iter()),
+            _ => None,
+        }
+        .into_iter()
+        .flatten()
+    }
+
+    pub fn not(&self) -> Option<&Schema> {
+        match self {
+            Schema::Object(object) => object.not.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn one_of(&self) -> &[Schema] {
+        match self {
+            Schema::Object(object) => &object.one_of,
+            _ => Default::default(),
+        }
+    }
 }
 
 pub struct SubSchemaIterator<'a> {
@@ -290,7 +374,7 @@ impl<'a> Iterator for SubSchemaIterator<'a> {
 }
 
 impl<'a> SubSchemaIterator<'a> {
-    pub fn empty(context: &SchemaContext) -> SubSchemaIterator {
+    pub fn empty(context: &SchemaContext) -> SubSchemaIterator<'_> {
         SubSchemaIterator {
             inner: Default::default(),
             context,
@@ -378,27 +462,30 @@ impl SchemaStore {
             full_path.push(file_name.clone());
 
             // Read the requested schema
-            let file = File::open(&full_path).map_err(|e| {
-                SchemaError::FailedToOpenSchema(
-                    full_path.to_string_lossy().to_string(),
-                    Box::new(e),
-                )
-            })?;
-            let reader = BufReader::new(file);
-            let root_schema = serde_json::from_reader(reader).map_err(|e| {
+            let contents = read_to_string(&full_path).map_err(|e| {
                 SchemaError::FailedToOpenSchema(
                     full_path.to_string_lossy().to_string(),
                     Box::new(e),
                 )
             })?;
 
-            map.insert(file_name.clone(), root_schema);
+            // Extension schemas named after the glTF root are stored under the objects they extend
+            for name in references::extended_schemas(&file_name) {
+                let root_schema = serde_json::from_str(&contents).map_err(|e| {
+                    SchemaError::FailedToOpenSchema(
+                        full_path.to_string_lossy().to_string(),
+                        Box::new(e),
+                    )
+                })?;
+
+                map.insert(name, root_schema);
+            }
         }
 
         Ok(SchemaStore { meta, map })
     }
 
-    pub fn schemas(&self) -> SchemaIterator {
+    pub fn schemas(&self) -> SchemaIterator<'_> {
         SchemaIterator {
             store: self,
             inner: self.map.iter(),
@@ -440,15 +527,11 @@ impl<'a> SchemaResolver<'a> {
         };
 
         // Find the schema store containing the URI
-        let (store, schema) = match self
+        let (store, schema) = self
             .order
             .iter()
             .filter_map(|store| store.map.get(schema_path).map(|schema| (*store, schema)))
-            .next()
-        {
-            Some(tuple) => tuple,
-            None => return None,
-        };
+            .next()?;
 
         let schema = match uri.definition_name() {
             Some(def) => match &schema.schema {
@@ -2,9 +2,13 @@ use anyhow::Context;
 use itertools::Itertools;
 use serde_json::Value;
 
+use crate::constraints::ObjectConstraints;
 use crate::schema::{InstanceType, Schema, SchemaContext, SchemaResolver};
 use crate::schema_uri::SchemaUri;
-use crate::{ArrayType, Enum, FixedArrayType, ObjectPrototype, PropertyListBuilder, Type};
+use crate::{references, 
+    ArrayType, Enum, FixedArrayType, IntEnum, IntEnumOption, ObjectPrototype, PropertyListBuilder,
+    Type,
+};
 
 pub fn handle_field(
     resolver: &SchemaResolver,
@@ -17,8 +21,8 @@ pub fn handle_field(
     }
 
     // Extensible int enum
-    if try_match_int_enum(context, schema).is_some() {
-        return Ok(Type::Integer);
+    if let Some(enumeration) = try_match_int_enum(context, schema) {
+        return Ok(Type::IntEnum(enumeration));
     }
 
     // Specific string enum
@@ -28,6 +32,7 @@ pub fn handle_field(
                 .iter()
                 .map(|value| value.as_str().unwrap().to_string())
                 .collect(),
+            extensible: false,
         }));
     }
 
@@ -36,6 +41,7 @@ pub fn handle_field(
 
 fn try_match_string_enum(context: &SchemaContext, schema: &Schema) -> Option<Enum> {
     let mut options = Vec::new();
+    let mut extensible = false;
     for (_, option) in schema.any_of(context) {
         let is_string_constant = match option.const_value() {
             Some(Value::String(option)) => {
@@ -49,21 +55,30 @@ fn try_match_string_enum(context: &SchemaContext, schema: &Schema) -> Option<Enu
         if !is_string && !is_string_constant {
             return None;
         }
+        extensible |= is_string && !is_string_constant;
     }
 
     if options.is_empty() {
         return None;
     }
 
-    Some(Enum { options })
+    Some(Enum {
+        options,
+        extensible,
+    })
 }
 
-fn try_match_int_enum(context: &SchemaContext, schema: &Schema) -> Option<()> {
+fn try_match_int_enum(context: &SchemaContext, schema: &Schema) -> Option<IntEnum> {
     let mut options = Vec::new();
     for (_, option) in schema.any_of(context) {
-        let is_number_constant = match option.const_value() {
-            Some(Value::Number(option)) => {
-                options.push(option.clone());
+        let is_number_constant = match option.const_value().and_then(Value::as_i64) {
+            Some(value) => {
+                // The description of a constant holds the name of the GL enum, e.g. UNSIGNED_SHORT
+                let name = option
+                    .description()
+                    .map(String::from)
+                    .unwrap_or_else(|| format!("Value{}", value));
+                options.push(IntEnumOption { value, name });
                 true
             }
             _ => false,
@@ -80,7 +95,7 @@ fn try_match_int_enum(context: &SchemaContext, schema: &Schema) -> Option<()> {
         return None;
     }
 
-    Some(())
+    Some(IntEnum { options })
 }
 
 fn handle_object_type(
@@ -88,6 +103,15 @@ fn handle_object_type(
     context: &SchemaContext,
     schema: &Schema,
 ) -> anyhow::Result<Option<Type>> {
+    // An object may be described by a reference next to its type, like the lights of KHR_lights_punctual
+    if let Some(reference) = schema.reference() {
+        let uri = SchemaUri::from_str(reference);
+        let (context, reference) = resolver
+            .resolve(&uri, Some(context.uri()))
+            .with_context(|| format!("failed to resolve object schema {}", reference))?;
+        return Ok(Some(handle_type(resolver, &context, reference)?));
+    }
+
     if schema.additional_properties(context).is_some() && schema.properties(context).count() == 0 {
         return Ok(Some(Type::MapOfObjects));
     }
@@ -103,12 +127,16 @@ fn handle_object_type(
         .recursive_read_properties(resolver, context, schema)
         .context("Failed to read properties for embedded object")?;
 
+    let constraints = ObjectConstraints::read(context, schema)
+        .context("Failed to read constraints for embedded object")?;
+
     let name = None; // TODO;
     Ok(Some(Type::EmbeddedObject {
         name,
         prototype: ObjectPrototype {
             properties: properties.properties,
             comment,
+            constraints,
         },
     }))
 }
@@ -152,6 +180,12 @@ fn handle_type(
     // Check if we deduce from a reference
     if let Some(reference) = schema.reference() {
         let uri = SchemaUri::from_str(reference);
+        if uri.path == "glTFid.schema.json" {
+            if let Some(ty) = handle_id(resolver, context)? {
+                return Ok(ty);
+            }
+        }
+
         let (context, reference) = resolver.resolve(&uri, Some(context.uri())).unwrap();
         return handle_type(resolver, &context, reference);
     }
@@ -160,6 +194,27 @@ fn handle_type(
     Ok(Type::Any)
 }
 
+/// Deduces the type of an id from the collection it refers to, or None if the collection is unknown.
+fn handle_id(resolver: &SchemaResolver, context: &SchemaContext) -> anyhow::Result<Option<Type>> {
+    let Some(collection) = references::find_collection(context.uri()) else {
+        println!("Id {} has no known collection, it is generated as an integer", context.uri());
+        return Ok(None);
+    };
+
+    let (collection_context, collection_schema) = resolver
+        .resolve(&SchemaUri::from_str(collection.schema), Some(context.uri()))
+        .with_context(|| format!("failed to resolve collection schema {}", collection.schema))?;
+    let (property_context, _, property_schema) = collection_schema
+        .properties(&collection_context)
+        .find(|(_, name, _)| *name == collection.property)
+        .with_context(|| format!("collection {} does not exist", collection.property))?;
+
+    match handle_type(resolver, &property_context, property_schema)? {
+        Type::Array(array) => Ok(Some(Type::Index(array.item))),
+        _ => anyhow::bail!("collection {} is not an array", collection.property),
+    }
+}
+
 fn handle_array(
     resolver: &SchemaResolver,
     context: &SchemaContext,
@@ -171,8 +226,7 @@ fn handle_array(
 
     let min_items = schema.min_items();
     let max_items = schema.max_items();
-    if min_items.is_some() && min_items == max_items {
-        let fixed_length = min_items.unwrap();
+    if let Some(fixed_length) = min_items.filter(|_| min_items == max_items) {
         return Ok(Type::FixedArray(FixedArrayType {
             item: Box::new(item_type),
             length: fixed_length as u32,
@@ -0,0 +1,483 @@
+//! Decoding of accessor data into typed elements.
+
+use std::marker::PhantomData;
+
+use thiserror::Error;
+
+pub use crate::generated::gltf::accessor::ComponentType;
+use crate::generated::gltf::accessor::Type;
+use crate::generated::gltf::Accessor as InnerAccessor;
+
+/// The size of a single component in bytes, or None if the component type is not defined by the specification.
+pub fn component_size(component_type: ComponentType) -> Option<usize> {
+    match component_type {
+        ComponentType::Byte | ComponentType::UnsignedByte => Some(1),
+        ComponentType::Short | ComponentType::UnsignedShort => Some(2),
+        ComponentType::UnsignedInt | ComponentType::Float => Some(4),
+        ComponentType::Other(_) => None,
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum AccessorError {
+    #[error("Component type {0} is not valid")]
+    UnknownComponentType(i64),
+    #[error("Accessor type {0} is not valid")]
+    UnknownType(String),
+    #[error("Components of type {component_type:?} cannot be read as {requested}")]
+    IncompatibleComponentType {
+        component_type: ComponentType,
+        requested: &'static str,
+    },
+    #[error("Accessor has {expected} components per element but {requested} were requested")]
+    ComponentCountMismatch { expected: usize, requested: usize },
+    #[error("Buffer view {0} does not exist or its buffer has no data")]
+    BufferViewUnavailable(usize),
+    #[error("Offset {0} is not a multiple of the component size")]
+    MisalignedOffset(usize),
+    #[error("Byte stride {0} is not a multiple of the component size")]
+    MisalignedStride(usize),
+    #[error("Byte stride {stride} is smaller than the element size of {element_size} bytes")]
+    StrideTooSmall { stride: usize, element_size: usize },
+    #[error("Accessor requires {required} bytes but its buffer view only contains {available}")]
+    OutOfBounds { required: usize, available: usize },
+    #[error("Component type {0} is not valid for sparse indices")]
+    InvalidSparseIndexType(i64),
+    #[error("Sparse index {index} is out of range for an accessor of {count} elements")]
+    SparseIndexOutOfRange { index: usize, count: usize },
+    #[error("Sparse index at position {position} does not increase over the previous index")]
+    SparseIndicesNotIncreasing { position: usize },
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A primitive type that accessor components can be read as.
+///
+/// Floats accept every component type, normalized integers are converted to the [0, 1] or [-1, 1]
+/// range and other integers keep their value. Integer types only accept component types they can
+/// represent without loss.
+pub trait Component: Copy + Default + sealed::Sealed {
+    #[doc(hidden)]
+    fn accepts(component_type: ComponentType) -> bool;
+    #[doc(hidden)]
+    fn decode(component_type: ComponentType, bytes: &[u8], normalized: bool) -> Self;
+}
+
+/// An element that an accessor can be read as, either a single [`Component`] for scalar accessors
+/// or an array of components for vector and matrix accessors. Matrices are read in column-major order.
+pub trait Element: Copy + sealed::Sealed {
+    type Component: Component;
+    const COMPONENTS: usize;
+
+    #[doc(hidden)]
+    fn zeroed() -> Self;
+    #[doc(hidden)]
+    fn decode(layout: &Layout, bytes: &[u8]) -> Self;
+}
+
+fn read_integer(component_type: ComponentType, bytes: &[u8]) -> i64 {
+    match component_type {
+        ComponentType::Byte => bytes[0] as i8 as i64,
+        ComponentType::UnsignedByte => bytes[0] as i64,
+        ComponentType::Short => i16::from_le_bytes([bytes[0], bytes[1]]) as i64,
+        ComponentType::UnsignedShort => u16::from_le_bytes([bytes[0], bytes[1]]) as i64,
+        ComponentType::UnsignedInt => {
+            u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as i64
+        }
+        ComponentType::Float | ComponentType::Other(_) => {
+            unreachable!("only integer components are read as integers")
+        }
+    }
+}
+
+impl sealed::Sealed for f32 {}
+impl Component for f32 {
+    fn accepts(_: ComponentType) -> bool {
+        true
+    }
+
+    fn decode(component_type: ComponentType, bytes: &[u8], normalized: bool) -> Self {
+        if component_type == ComponentType::Float {
+            return f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+
+        let value = read_integer(component_type, bytes) as f32;
+        match (normalized, component_type) {
+            (true, ComponentType::Byte) => (value / 127.0).max(-1.0),
+            (true, ComponentType::UnsignedByte) => value / 255.0,
+            (true, ComponentType::Short) => (value / 32767.0).max(-1.0),
+            (true, ComponentType::UnsignedShort) => value / 65535.0,
+            _ => value,
+        }
+    }
+}
+
+macro_rules! integer_component {
+    ($ty:ty, $($accepts:ident),*) => {
+        impl sealed::Sealed for $ty {}
+        impl Component for $ty {
+            fn accepts(component_type: ComponentType) -> bool {
+                matches!(component_type, $(ComponentType::$accepts)|*)
+            }
+
+            fn decode(component_type: ComponentType, bytes: &[u8], _normalized: bool) -> Self {
+                read_integer(component_type, bytes) as $ty
+            }
+        }
+    };
+}
+
+integer_component!(i8, Byte);
+integer_component!(u8, UnsignedByte);
+integer_component!(i16, Byte, UnsignedByte, Short);
+integer_component!(u16, UnsignedByte, UnsignedShort);
+integer_component!(u32, UnsignedByte, UnsignedShort, UnsignedInt);
+
+macro_rules! scalar_element {
+    ($($ty:ty),*) => {
+        $(
+            impl Element for $ty {
+                type Component = $ty;
+                const COMPONENTS: usize = 1;
+
+                fn zeroed() -> Self {
+                    <$ty>::default()
+                }
+
+                fn decode(layout: &Layout, bytes: &[u8]) -> Self {
+                    <$ty as Component>::decode(layout.component_type, bytes, layout.normalized)
+                }
+            }
+        )*
+    };
+}
+
+scalar_element!(f32, i8, u8, i16, u16, u32);
+
+impl<T: Component, const N: usize> sealed::Sealed for [T; N] {}
+impl<T: Component, const N: usize> Element for [T; N] {
+    type Component = T;
+    const COMPONENTS: usize = N;
+
+    fn zeroed() -> Self {
+        [T::default(); N]
+    }
+
+    fn decode(layout: &Layout, bytes: &[u8]) -> Self {
+        std::array::from_fn(|i| {
+            T::decode(
+                layout.component_type,
+                &bytes[layout.component_offset(i)..],
+                layout.normalized,
+            )
+        })
+    }
+}
+
+/// Describes how the components of a single element are laid out in memory.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct Layout {
+    component_type: ComponentType,
+    component_size: usize,
+    normalized: bool,
+    rows: usize,
+    columns: usize,
+}
+
+impl Layout {
+    pub(crate) fn new(accessor: &InnerAccessor) -> Result<Self, AccessorError> {
+        let component_type = accessor.component_type;
+        let component_size = component_size(component_type)
+            .ok_or(AccessorError::UnknownComponentType(component_type.into()))?;
+        let (rows, columns) = match &accessor.ty {
+            Type::Scalar => (1, 1),
+            Type::Vec2 => (2, 1),
+            Type::Vec3 => (3, 1),
+            Type::Vec4 => (4, 1),
+            Type::Mat2 => (2, 2),
+            Type::Mat3 => (3, 3),
+            Type::Mat4 => (4, 4),
+            Type::Other(ty) => return Err(AccessorError::UnknownType(ty.clone())),
+        };
+        Ok(Layout {
+            component_type,
+            component_size,
+            normalized: accessor.normalized,
+            rows,
+            columns,
+        })
+    }
+
+    /// The same layout with integer components read as they are stored.
+    pub(crate) fn unnormalized(self) -> Self {
+        Layout {
+            normalized: false,
+            ..self
+        }
+    }
+
+    /// The number of components in an element.
+    pub(crate) fn components(&self) -> usize {
+        self.rows * self.columns
+    }
+
+    /// The distance between matrix columns, which start on 4-byte boundaries.
+    fn column_stride(&self) -> usize {
+        let size = self.rows * self.component_size;
+        if self.columns > 1 {
+            size.next_multiple_of(4)
+        } else {
+            size
+        }
+    }
+
+    /// The size of a tightly packed element in bytes, including the padding of matrix columns.
+    pub(crate) fn element_size(&self) -> usize {
+        self.columns * self.column_stride()
+    }
+
+    fn component_offset(&self, index: usize) -> usize {
+        (index / self.rows) * self.column_stride() + (index % self.rows) * self.component_size
+    }
+
+    /// Checks that the elements of this layout can be read as `T`.
+    pub(crate) fn check<T: Element>(&self) -> Result<(), AccessorError> {
+        if !T::Component::accepts(self.component_type) {
+            return Err(AccessorError::IncompatibleComponentType {
+                component_type: self.component_type,
+                requested: std::any::type_name::<T::Component>(),
+            });
+        }
+        if T::COMPONENTS != self.components() {
+            return Err(AccessorError::ComponentCountMismatch {
+                expected: self.components(),
+                requested: T::COMPONENTS,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// The buffer view that an accessor reads its elements from.
+pub(crate) struct View<'a> {
+    /// The contents of the buffer view.
+    pub data: &'a [u8],
+    /// The `byteOffset` of the buffer view within its buffer.
+    pub offset: usize,
+    pub stride: Option<usize>,
+}
+
+impl<'a> View<'a> {
+    /// Returns the contents of the view starting at `byte_offset`, after checking that `count`
+    /// elements of `element_size` bytes each, placed `stride` bytes apart, fit within the view.
+    /// Sizes that overflow are out of bounds, with a required size of `usize::MAX`.
+    fn elements(
+        &self,
+        byte_offset: usize,
+        component_size: usize,
+        stride: usize,
+        element_size: usize,
+        count: usize,
+    ) -> Result<&'a [u8], AccessorError> {
+        let out_of_bounds = |required| AccessorError::OutOfBounds {
+            required,
+            available: self.data.len(),
+        };
+        let start = self
+            .offset
+            .checked_add(byte_offset)
+            .ok_or_else(|| out_of_bounds(usize::MAX))?;
+        if !start.is_multiple_of(component_size) {
+            return Err(AccessorError::MisalignedOffset(start));
+        }
+
+        let required = match count {
+            0 => Some(byte_offset),
+            _ => stride
+                .checked_mul(count - 1)
+                .and_then(|size| size.checked_add(byte_offset))
+                .and_then(|size| size.checked_add(element_size)),
+        }
+        .unwrap_or(usize::MAX);
+        if required > self.data.len() {
+            return Err(out_of_bounds(required));
+        }
+        Ok(&self.data[byte_offset..])
+    }
+}
+
+/// The sparse storage of an accessor, which substitutes the elements at the given indices.
+pub(crate) struct Sparse<'a> {
+    pub count: usize,
+    pub indices: View<'a>,
+    pub indices_byte_offset: usize,
+    pub indices_component_type: ComponentType,
+    pub values: View<'a>,
+    pub values_byte_offset: usize,
+}
+
+/// Iterates over the elements of an accessor.
+pub struct Iter<'a, T> {
+    layout: Layout,
+    /// The buffer view contents starting at the first element, or None if the accessor is zero initialized.
+    data: Option<&'a [u8]>,
+    stride: usize,
+    index: usize,
+    count: usize,
+    /// The strictly increasing indices of the elements substituted by sparse values.
+    sparse_indices: Vec<usize>,
+    /// The tightly packed sparse values, in the same order as the sparse indices.
+    sparse_values: &'a [u8],
+    /// The position in the sparse indices of the next substituted element.
+    sparse_position: usize,
+    _element: PhantomData<T>,
+}
+
+impl<'a, T: Element> Iter<'a, T> {
+    pub(crate) fn new(
+        layout: Layout,
+        view: Option<View<'a>>,
+        byte_offset: usize,
+        count: usize,
+        sparse: Option<Sparse<'a>>,
+    ) -> Result<Self, AccessorError> {
+        layout.check::<T>()?;
+
+        let element_size = layout.element_size();
+        let component_size = layout.component_size;
+        let (data, stride) = match view {
+            Some(view) => {
+                let stride = view.stride.unwrap_or(element_size);
+                if !stride.is_multiple_of(component_size) {
+                    return Err(AccessorError::MisalignedStride(stride));
+                }
+                if stride < element_size {
+                    return Err(AccessorError::StrideTooSmall {
+                        stride,
+                        element_size,
+                    });
+                }
+
+                let data =
+                    view.elements(byte_offset, component_size, stride, element_size, count)?;
+                (Some(data), stride)
+            }
+            None => (None, element_size),
+        };
+
+        let (sparse_indices, sparse_values) = match sparse {
+            Some(sparse) => {
+                let indices = read_sparse_indices(&sparse, count)?;
+                let values = sparse.values.elements(
+                    sparse.values_byte_offset,
+                    component_size,
+                    element_size,
+                    element_size,
+                    sparse.count,
+                )?;
+                (indices, values)
+            }
+            None => (Vec::new(), &[][..]),
+        };
+
+        Ok(Iter {
+            layout,
+            data,
+            stride,
+            index: 0,
+            count,
+            sparse_indices,
+            sparse_values,
+            sparse_position: 0,
+            _element: PhantomData,
+        })
+    }
+}
+
+/// Reads the indices of a sparse accessor, and checks that they strictly increase and are within
+/// the `count` elements of the accessor.
+fn read_sparse_indices(sparse: &Sparse, count: usize) -> Result<Vec<usize>, AccessorError> {
+    let (component_type, size) = match sparse.indices_component_type {
+        ComponentType::UnsignedByte => (ComponentType::UnsignedByte, 1),
+        ComponentType::UnsignedShort => (ComponentType::UnsignedShort, 2),
+        ComponentType::UnsignedInt => (ComponentType::UnsignedInt, 4),
+        other => return Err(AccessorError::InvalidSparseIndexType(other.into())),
+    };
+    let data =
+        sparse
+            .indices
+            .elements(sparse.indices_byte_offset, size, size, size, sparse.count)?;
+
+    let mut indices = Vec::with_capacity(sparse.count);
+    for position in 0..sparse.count {
+        let index = read_integer(component_type, &data[position * size..]) as usize;
+        if index >= count {
+            return Err(AccessorError::SparseIndexOutOfRange { index, count });
+        }
+        if indices.last().is_some_and(|&previous| index <= previous) {
+            return Err(AccessorError::SparseIndicesNotIncreasing { position });
+        }
+        indices.push(index);
+    }
+    Ok(indices)
+}
+
+impl<'a, T: Element> Iterator for Iter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let element = if self.sparse_indices.get(self.sparse_position) == Some(&self.index) {
+            let offset = self.sparse_position * self.layout.element_size();
+            self.sparse_position += 1;
+            T::decode(&self.layout, &self.sparse_values[offset..])
+        } else {
+            match self.data {
+                Some(data) => T::decode(&self.layout, &data[self.index * self.stride..]),
+                None => T::zeroed(),
+            }
+        };
+        self.index += 1;
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: Element> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T: Element> Iter<'a, T> {
+    /// Iterates over the elements that are stored, with their index. Without a buffer view only
+    /// the sparse values are visited, since the other elements are zero. This suits applying
+    /// sparse displacements, e.g. those of morph targets.
+    pub fn stored(self) -> Stored<'a, T> {
+        Stored { inner: self }
+    }
+}
+
+/// Iterates over the stored elements of an accessor, see [`Iter::stored`].
+pub struct Stored<'a, T> {
+    inner: Iter<'a, T>,
+}
+
+impl<'a, T: Element> Iterator for Stored<'a, T> {
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter = &mut self.inner;
+        if iter.data.is_none() {
+            iter.index = *iter.sparse_indices.get(iter.sparse_position)?;
+        }
+        let index = iter.index;
+        iter.next().map(|element| (index, element))
+    }
+}
@@ -0,0 +1,340 @@
+//! Evaluation of animations into the pose of the nodes of a document, e.g. to bake an animation
+//! into keyframes at a fixed rate.
+
+use thiserror::Error;
+
+use crate::accessor::AccessorError;
+use crate::import::{Accessor, Animation, Gltf};
+use crate::morph;
+use crate::transform::{self, Matrix, Transform};
+
+use crate::generated::gltf::animation_channel_target::Path;
+use crate::generated::gltf::animation_sampler::Interpolation;
+
+#[derive(Error, Debug)]
+pub enum AnimationError {
+    #[error("Channel {channel} refers to sampler {sampler} which does not exist")]
+    MissingSampler { channel: usize, sampler: usize },
+    #[error("Sampler {sampler} refers to accessor {accessor} which does not exist")]
+    MissingAccessor { sampler: usize, accessor: usize },
+    #[error("Keyframes of sampler {sampler} can not be read: {source}")]
+    Accessor {
+        sampler: usize,
+        source: AccessorError,
+    },
+    #[error(
+        "Sampler {sampler} has {outputs} output values which do not match its {inputs} keyframes"
+    )]
+    KeyframeMismatch {
+        sampler: usize,
+        inputs: usize,
+        outputs: usize,
+    },
+    #[error("Sampler {sampler} uses interpolation {interpolation} which is not supported")]
+    UnsupportedInterpolation {
+        sampler: usize,
+        interpolation: String,
+    },
+}
+
+/// The local transform and morph target weights of every node, indexed by node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pose {
+    pub transforms: Vec<Transform>,
+    /// The weights of the morph targets of the mesh of each node, empty for nodes without one.
+    pub weights: Vec<Vec<f32>>,
+}
+
+impl Pose {
+    /// The pose of the nodes without any animation applied. The weights of a node default to the
+    /// weights of its mesh.
+    pub fn rest(gltf: &Gltf) -> Self {
+        Pose {
+            transforms: gltf.nodes().map(|node| node.transform()).collect(),
+            weights: gltf.nodes().map(|node| morph::weights(&node)).collect(),
+        }
+    }
+
+    /// The world matrix of every node in this pose, which includes the transforms of its ancestors.
+    pub fn world_matrices(&self, gltf: &Gltf) -> Vec<Matrix> {
+        let count = self.transforms.len();
+        let mut parents = vec![None; count];
+        for node in gltf.nodes() {
+            for child in node.children().filter(|child| child.index() < count) {
+                parents[child.index()] = Some(node.index());
+            }
+        }
+
+        let mut world: Vec<Option<Matrix>> = vec![None; count];
+        let mut chain = Vec::new();
+        for index in 0..count {
+            // Collect the ancestors without a world matrix yet, the length is bounded in case the
+            // hierarchy of an invalid document contains a cycle
+            let mut current = Some(index);
+            while let Some(node) = current.filter(|&node| world[node].is_none()) {
+                if chain.len() > count {
+                    break;
+                }
+                chain.push(node);
+                current = parents[node];
+            }
+            while let Some(node) = chain.pop() {
+                let local = self.transforms[node].matrix();
+                world[node] = Some(match parents[node].and_then(|parent| world[parent]) {
+                    Some(parent) => transform::multiply(&parent, &local),
+                    None => local,
+                });
+            }
+        }
+        world.into_iter().flatten().collect()
+    }
+}
+
+/// The property of a node animated by a channel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Property {
+    Translation,
+    Rotation,
+    Scale,
+    Weights,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+    Step,
+    Linear,
+    CubicSpline,
+}
+
+/// The keyframes of a channel.
+#[derive(Debug)]
+struct Track {
+    node: usize,
+    property: Property,
+    mode: Mode,
+    times: Vec<f32>,
+    /// The keyframe values, each preceded by an in-tangent and followed by an out-tangent for
+    /// cubic spline interpolation.
+    values: Vec<f32>,
+    /// The number of components of a value, e.g. 4 for a rotation.
+    width: usize,
+}
+
+/// Samples the channels of an animation. The keyframes are read from the accessors once, so the
+/// animation can be sampled at many points in time.
+#[derive(Debug)]
+pub struct Evaluator {
+    tracks: Vec<Track>,
+}
+
+impl Evaluator {
+    /// Reads the keyframes of an animation. Channels that do not target a node, e.g. those of
+    /// `KHR_animation_pointer`, or that animate a property not defined by the specification are ignored.
+    pub fn new(animation: &Animation) -> Result<Self, AnimationError> {
+        let mut tracks = Vec::new();
+        for channel in animation.channels() {
+            let property = match channel.target_path() {
+                Path::Translation => Property::Translation,
+                Path::Rotation => Property::Rotation,
+                Path::Scale => Property::Scale,
+                Path::Weights => Property::Weights,
+                Path::Other(_) => continue,
+            };
+            let Some(node) = channel.target_node() else {
+                continue;
+            };
+            let sampler_index = channel.inner().sampler.value();
+            let sampler = channel.sampler().ok_or(AnimationError::MissingSampler {
+                channel: channel.index(),
+                sampler: sampler_index,
+            })?;
+            let mode = match sampler.interpolation() {
+                Interpolation::Step => Mode::Step,
+                Interpolation::Linear => Mode::Linear,
+                Interpolation::Cubicspline => Mode::CubicSpline,
+                Interpolation::Other(other) => {
+                    return Err(AnimationError::UnsupportedInterpolation {
+                        sampler: sampler_index,
+                        interpolation: other.clone(),
+                    })
+                }
+            };
+
+            let missing = |accessor: usize| AnimationError::MissingAccessor {
+                sampler: sampler_index,
+                accessor,
+            };
+            let read_error = |source| AnimationError::Accessor {
+                sampler: sampler_index,
+                source,
+            };
+            let input = sampler
+                .input()
+                .ok_or_else(|| missing(sampler.inner().input.value()))?;
+            let output = sampler
+                .output()
+                .ok_or_else(|| missing(sampler.inner().output.value()))?;
+            let times: Vec<f32> = input.read::<f32>().map_err(read_error)?.collect();
+            let values = match property {
+                Property::Translation | Property::Scale => read_flat::<3>(&output),
+                Property::Rotation => read_flat::<4>(&output),
+                Property::Weights => read_flat::<1>(&output),
+            }
+            .map_err(read_error)?;
+
+            // A cubic spline stores an in-tangent, value and out-tangent for every keyframe
+            let elements = times.len() * if mode == Mode::CubicSpline { 3 } else { 1 };
+            let width = match property {
+                Property::Translation | Property::Scale => 3,
+                Property::Rotation => 4,
+                Property::Weights if elements == 0 => 0,
+                Property::Weights => values.len() / elements,
+            };
+            if values.len() != elements * width {
+                return Err(AnimationError::KeyframeMismatch {
+                    sampler: sampler_index,
+                    inputs: times.len(),
+                    outputs: output.count(),
+                });
+            }
+
+            tracks.push(Track {
+                node: node.index(),
+                property,
+                mode,
+                times,
+                values,
+                width,
+            });
+        }
+        Ok(Evaluator { tracks })
+    }
+
+    /// The time in seconds of the last keyframe of the animation.
+    pub fn duration(&self) -> f32 {
+        self.tracks
+            .iter()
+            .filter_map(|track| track.times.last().copied())
+            .fold(0.0, f32::max)
+    }
+
+    /// Samples every channel at a time in seconds and writes the result into the pose. Times
+    /// outside the keyframes of a channel are clamped to its first or last keyframe.
+    pub fn sample(&self, time: f32, pose: &mut Pose) {
+        for track in &self.tracks {
+            let Some(value) = track.sample(time) else {
+                continue;
+            };
+            let Some(transform) = pose.transforms.get_mut(track.node) else {
+                continue;
+            };
+            match track.property {
+                Property::Translation => transform.translation = to_array(&value),
+                Property::Rotation => transform.rotation = to_array(&value),
+                Property::Scale => transform.scale = to_array(&value),
+                Property::Weights => pose.weights[track.node] = value,
+            }
+        }
+    }
+}
+
+impl Track {
+    /// The value of keyframe `index`, or its in- or out-tangent for an offset of 0 or 2 in a cubic spline.
+    fn element(&self, index: usize, offset: usize) -> &[f32] {
+        let element = match self.mode {
+            Mode::CubicSpline => index * 3 + offset,
+            Mode::Step | Mode::Linear => index,
+        };
+        &self.values[element * self.width..(element + 1) * self.width]
+    }
+
+    fn value(&self, index: usize) -> &[f32] {
+        self.element(index, 1)
+    }
+
+    fn sample(&self, time: f32) -> Option<Vec<f32>> {
+        let last = self.times.len().checked_sub(1)?;
+        let next = self.times.partition_point(|&keyframe| keyframe <= time);
+        if next == 0 {
+            return Some(self.value(0).to_vec());
+        }
+        if next > last {
+            return Some(self.value(last).to_vec());
+        }
+
+        let previous = next - 1;
+        let delta = self.times[next] - self.times[previous];
+        let t = (time - self.times[previous]) / delta;
+        let (a, b) = (self.value(previous), self.value(next));
+        let value = match (self.mode, self.property) {
+            (Mode::Step, _) => a.to_vec(),
+            (Mode::Linear, Property::Rotation) => slerp(to_array(a), to_array(b), t).to_vec(),
+            (Mode::Linear, _) => a.iter().zip(b).map(|(a, b)| a + (b - a) * t).collect(),
+            (Mode::CubicSpline, property) => {
+                // Hermite basis functions, the tangents are scaled by the duration of the keyframe
+                let (t2, t3) = (t * t, t * t * t);
+                let weights = [
+                    2.0 * t3 - 3.0 * t2 + 1.0,
+                    delta * (t3 - 2.0 * t2 + t),
+                    -2.0 * t3 + 3.0 * t2,
+                    delta * (t3 - t2),
+                ];
+                let (out_tangent, in_tangent) = (self.element(previous, 2), self.element(next, 0));
+                let value: Vec<f32> = (0..self.width)
+                    .map(|i| {
+                        weights[0] * a[i]
+                            + weights[1] * out_tangent[i]
+                            + weights[2] * b[i]
+                            + weights[3] * in_tangent[i]
+                    })
+                    .collect();
+                match property {
+                    Property::Rotation => normalize(to_array(&value)).to_vec(),
+                    _ => value,
+                }
+            }
+        };
+        Some(value)
+    }
+}
+
+/// Reads the components of every element of an accessor in order.
+fn read_flat<const N: usize>(accessor: &Accessor) -> Result<Vec<f32>, AccessorError> {
+    Ok(accessor.read::<[f32; N]>()?.flatten().collect())
+}
+
+fn to_array<const N: usize>(value: &[f32]) -> [f32; N] {
+    std::array::from_fn(|i| value[i])
+}
+
+fn dot(a: [f32; 4], b: [f32; 4]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn normalize(q: [f32; 4]) -> [f32; 4] {
+    let length = dot(q, q).sqrt();
+    if length == 0.0 {
+        return q;
+    }
+    q.map(|value| value / length)
+}
+
+/// Interpolates between two unit quaternions along the shortest arc.
+fn slerp(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    let cos = dot(a, b);
+    // q and -q are the same rotation, flip one to take the shortest arc
+    let (b, cos) = if cos < 0.0 {
+        (b.map(|value| -value), -cos)
+    } else {
+        (b, cos)
+    };
+
+    // Nearly parallel quaternions are interpolated linearly to avoid dividing by a small sine
+    if cos > 0.9995 {
+        return normalize(std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t));
+    }
+    let angle = cos.acos();
+    let sin = angle.sin();
+    let (wa, wb) = (((1.0 - t) * angle).sin() / sin, (t * angle).sin() / sin);
+    std::array::from_fn(|i| wa * a[i] + wb * b[i])
+}
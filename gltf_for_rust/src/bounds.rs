@@ -0,0 +1,329 @@
+//! Bounding volumes computed from the positions of primitives, e.g. for culling or to fit a camera
+//! to a scene, rather than taken from the `min` and `max` of their accessors.
+
+use thiserror::Error;
+
+use crate::accessor::{AccessorError, ComponentType};
+use crate::animation::Pose;
+use crate::import::{Accessor, Mesh, Node, Primitive, Scene};
+use crate::morph::{self, MorphError};
+use crate::skinning::{self, Influences, SkinningError};
+use crate::transform::{self, Matrix};
+
+#[derive(Error, Debug)]
+pub enum BoundsError {
+    #[error("Positions can not be read: {0}")]
+    Positions(AccessorError),
+    #[error("Positions of morph target {target} can not be read: {source}")]
+    TargetPositions {
+        target: usize,
+        source: AccessorError,
+    },
+    #[error("Morph targets can not be applied: {0}")]
+    Morph(MorphError),
+    #[error("Skin can not be applied: {0}")]
+    Skinning(SkinningError),
+}
+
+/// An axis-aligned bounding box.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+    pub min: [f32; 3],
+    pub max: [f32; 3],
+}
+
+impl Aabb {
+    /// The smallest box containing the points, or None if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = [f32; 3]>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let aabb = Aabb {
+            min: first,
+            max: first,
+        };
+        Some(points.fold(aabb, |aabb, point| Aabb {
+            min: std::array::from_fn(|i| aabb.min[i].min(point[i])),
+            max: std::array::from_fn(|i| aabb.max[i].max(point[i])),
+        }))
+    }
+
+    /// The smallest box containing both boxes.
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min: std::array::from_fn(|i| self.min[i].min(other.min[i])),
+            max: std::array::from_fn(|i| self.max[i].max(other.max[i])),
+        }
+    }
+
+    pub fn center(&self) -> [f32; 3] {
+        std::array::from_fn(|i| (self.min[i] + self.max[i]) / 2.0)
+    }
+
+    /// The smallest box containing this box after a transform.
+    pub fn transform(&self, matrix: &Matrix) -> Aabb {
+        let center = transform::transform_point(matrix, self.center());
+        let half: [f32; 3] = std::array::from_fn(|i| (self.max[i] - self.min[i]) / 2.0);
+        // Every axis of the box contributes its projection onto each axis of the result
+        let extent: [f32; 3] =
+            std::array::from_fn(|i| (0..3).map(|j| matrix[j * 4 + i].abs() * half[j]).sum());
+        Aabb {
+            min: std::array::from_fn(|i| center[i] - extent[i]),
+            max: std::array::from_fn(|i| center[i] + extent[i]),
+        }
+    }
+}
+
+/// A bounding sphere.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sphere {
+    pub center: [f32; 3],
+    pub radius: f32,
+}
+
+impl Sphere {
+    /// A sphere around the center of the bounding box of the points, which contains all of them.
+    /// Returns None if there are no points.
+    pub fn from_points(points: &[[f32; 3]]) -> Option<Self> {
+        let center = Aabb::from_points(points.iter().copied())?.center();
+        let radius = points
+            .iter()
+            .map(|&point| length(sub(point, center)))
+            .fold(0.0, f32::max);
+        Some(Sphere { center, radius })
+    }
+
+    /// The smallest sphere containing both spheres.
+    pub fn union(&self, other: &Sphere) -> Sphere {
+        let distance = length(sub(other.center, self.center));
+        if distance + other.radius <= self.radius {
+            return *self;
+        }
+        if distance + self.radius <= other.radius {
+            return *other;
+        }
+        let radius = (distance + self.radius + other.radius) / 2.0;
+        let offset = (radius - self.radius) / distance;
+        Sphere {
+            center: std::array::from_fn(|i| {
+                self.center[i] + (other.center[i] - self.center[i]) * offset
+            }),
+            radius,
+        }
+    }
+
+    /// A sphere containing this sphere after a transform, scaled by the largest scale of the
+    /// transform.
+    pub fn transform(&self, matrix: &Matrix) -> Sphere {
+        let scale = (0..3)
+            .map(|column| length([0, 1, 2].map(|row| matrix[column * 4 + row])))
+            .fold(0.0, f32::max);
+        Sphere {
+            center: transform::transform_point(matrix, self.center),
+            radius: self.radius * scale,
+        }
+    }
+}
+
+/// Bounds as both a box and a sphere, which is often tighter than the sphere around the box.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bounds {
+    pub aabb: Aabb,
+    pub sphere: Sphere,
+}
+
+impl Bounds {
+    /// The bounds of the points, or None if there are none.
+    pub fn from_points(points: &[[f32; 3]]) -> Option<Self> {
+        Some(Bounds {
+            aabb: Aabb::from_points(points.iter().copied())?,
+            sphere: Sphere::from_points(points)?,
+        })
+    }
+
+    pub fn union(&self, other: &Bounds) -> Bounds {
+        Bounds {
+            aabb: self.aabb.union(&other.aabb),
+            sphere: self.sphere.union(&other.sphere),
+        }
+    }
+
+    pub fn transform(&self, matrix: &Matrix) -> Bounds {
+        Bounds {
+            aabb: self.aabb.transform(matrix),
+            sphere: self.sphere.transform(matrix),
+        }
+    }
+}
+
+/// The bounds of a primitive with its morph targets applied at `weights`. Each target extends the
+/// bounds by the `min` and `max` of its position displacements times its weight, which are
+/// computed from the displacements when the target does not specify them or stores integers.
+/// Returns None if the primitive has no positions.
+pub fn primitive(primitive: &Primitive, weights: &[f32]) -> Result<Option<Bounds>, BoundsError> {
+    let Some(positions) = read_positions(primitive)? else {
+        return Ok(None);
+    };
+    let Some(mut bounds) = Bounds::from_points(&positions) else {
+        return Ok(None);
+    };
+
+    for (target, &weight) in primitive.targets().zip(weights) {
+        let Some(displacements) = target.positions().filter(|_| weight != 0.0) else {
+            continue;
+        };
+        let extents = extents(&displacements).map_err(|source| BoundsError::TargetPositions {
+            target: target.index(),
+            source,
+        })?;
+        let Some(extents) = extents else {
+            continue;
+        };
+        for i in 0..3 {
+            let (a, b) = (extents.min[i] * weight, extents.max[i] * weight);
+            bounds.aabb.min[i] += a.min(b);
+            bounds.aabb.max[i] += a.max(b);
+        }
+        let farthest = std::array::from_fn(|i| extents.min[i].abs().max(extents.max[i].abs()));
+        bounds.sphere.radius += weight.abs() * length(farthest);
+    }
+    Ok(Some(bounds))
+}
+
+/// The bounds of the primitives of a mesh with its morph targets applied at `weights`, see
+/// [`primitive`].
+pub fn mesh(mesh: &Mesh, weights: &[f32]) -> Result<Option<Bounds>, BoundsError> {
+    let mut bounds = None;
+    for primitive in mesh.primitives() {
+        bounds = union(bounds, self::primitive(&primitive, weights)?);
+    }
+    Ok(bounds)
+}
+
+/// The bounds of a skinned primitive, computed from its vertices after applying its morph targets
+/// at `weights` and skinning them with the joint matrices, see [`skinning::joint_matrices`].
+pub fn skinned(
+    primitive: &Primitive,
+    weights: &[f32],
+    joint_matrices: &[Matrix],
+) -> Result<Option<Bounds>, BoundsError> {
+    let positions = match weights.iter().any(|&weight| weight != 0.0) {
+        true => Some(
+            morph::blend(primitive, weights)
+                .map_err(BoundsError::Morph)?
+                .positions,
+        ),
+        false => read_positions(primitive)?,
+    };
+    let Some(positions) = positions else {
+        return Ok(None);
+    };
+    let influences = Influences::read(primitive).map_err(BoundsError::Skinning)?;
+    let skinned = skinning::skin(&positions, &[], &influences, joint_matrices)
+        .map_err(BoundsError::Skinning)?;
+    Ok(Bounds::from_points(&skinned.positions))
+}
+
+/// The bounds of a node and its descendants in world space, in a pose with the given world
+/// matrices, e.g. from [`Pose::world_matrices`]. Meshes are morphed with the weights of the pose,
+/// and skinned meshes are bounded by their skinned vertices.
+pub fn node(
+    node: &Node,
+    pose: &Pose,
+    world_matrices: &[Matrix],
+) -> Result<Option<Bounds>, BoundsError> {
+    let mut bounds = None;
+    let mut visited = vec![false; world_matrices.len()];
+    let mut stack = vec![*node];
+    while let Some(node) = stack.pop() {
+        // Also guards against cycles in the hierarchy of an invalid document
+        match visited.get_mut(node.index()) {
+            Some(visited) if !*visited => *visited = true,
+            _ => continue,
+        }
+        stack.extend(node.children());
+        bounds = union(bounds, node_mesh(&node, pose, world_matrices)?);
+    }
+    Ok(bounds)
+}
+
+/// The bounds of the nodes of a scene in world space, see [`node`].
+pub fn scene(
+    scene: &Scene,
+    pose: &Pose,
+    world_matrices: &[Matrix],
+) -> Result<Option<Bounds>, BoundsError> {
+    let mut bounds = None;
+    for root in scene.nodes() {
+        bounds = union(bounds, node(&root, pose, world_matrices)?);
+    }
+    Ok(bounds)
+}
+
+/// The bounds of the mesh of a node in world space.
+fn node_mesh(
+    node: &Node,
+    pose: &Pose,
+    world_matrices: &[Matrix],
+) -> Result<Option<Bounds>, BoundsError> {
+    let (Some(mesh), Some(world)) = (node.mesh(), world_matrices.get(node.index())) else {
+        return Ok(None);
+    };
+    let weights = pose
+        .weights
+        .get(node.index())
+        .map_or(&[][..], Vec::as_slice);
+    let local = match node.skin() {
+        Some(skin) => {
+            let joint_matrices = skinning::joint_matrices(&skin, node, world_matrices)
+                .map_err(BoundsError::Skinning)?;
+            let mut bounds = None;
+            for primitive in mesh.primitives() {
+                bounds = union(bounds, skinned(&primitive, weights, &joint_matrices)?);
+            }
+            bounds
+        }
+        None => self::mesh(&mesh, weights)?,
+    };
+    Ok(local.map(|bounds| bounds.transform(world)))
+}
+
+/// The box containing the elements of a position accessor as they are stored, ignoring
+/// `normalized`, which is what its `min` and `max` describe.
+pub(crate) fn stored_extents(accessor: &Accessor) -> Result<Option<Aabb>, AccessorError> {
+    Ok(Aabb::from_points(accessor.read_unnormalized::<[f32; 3]>()?))
+}
+
+/// The box containing the position displacements of a morph target.
+fn extents(accessor: &Accessor) -> Result<Option<Aabb>, AccessorError> {
+    let (min, max) = (accessor.min(), accessor.max());
+    if accessor.component_type() == ComponentType::Float && min.len() == 3 && max.len() == 3 {
+        return Ok(Some(Aabb {
+            min: std::array::from_fn(|i| min[i] as f32),
+            max: std::array::from_fn(|i| max[i] as f32),
+        }));
+    }
+    Ok(Aabb::from_points(accessor.read::<[f32; 3]>()?))
+}
+
+fn read_positions(primitive: &Primitive) -> Result<Option<Vec<[f32; 3]>>, BoundsError> {
+    let Some(positions) = primitive.positions() else {
+        return Ok(None);
+    };
+    let positions = positions.read().map_err(BoundsError::Positions)?;
+    Ok(Some(positions.collect()))
+}
+
+fn union(a: Option<Bounds>, b: Option<Bounds>) -> Option<Bounds> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.union(&b)),
+        (a, b) => a.or(b),
+    }
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    std::array::from_fn(|i| a[i] - b[i])
+}
+
+fn length(v: [f32; 3]) -> f32 {
+    v.iter().map(|value| value * value).sum::<f32>().sqrt()
+}
@@ -0,0 +1,408 @@
+//! Assembly of glTF assets from code, e.g. by procedural generators. Objects are appended to the
+//! document and referred to by the typed indices the builder returns.
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Map, Value};
+
+use crate::accessor::ComponentType;
+use crate::import::Gltf;
+use crate::semantic::Semantic;
+use crate::transform::Transform;
+use crate::Index;
+
+use crate::generated::gltf::accessor::Type;
+use crate::generated::gltf::buffer_view::Target;
+use crate::generated::gltf::image::MimeType;
+use crate::generated::gltf::Accessor as InnerAccessor;
+use crate::generated::gltf::Buffer as InnerBuffer;
+use crate::generated::gltf::BufferView as InnerBufferView;
+use crate::generated::gltf::Gltf as InnerGltf;
+use crate::generated::gltf::Image as InnerImage;
+use crate::generated::gltf::Material as InnerMaterial;
+use crate::generated::gltf::MaterialPbrMetallicRoughness as InnerPbrMetallicRoughness;
+use crate::generated::gltf::Mesh as InnerMesh;
+use crate::generated::gltf::MeshPrimitive as InnerPrimitive;
+use crate::generated::gltf::Node as InnerNode;
+use crate::generated::gltf::Scene as InnerScene;
+use crate::generated::gltf::Texture as InnerTexture;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A primitive type that accessors can be built from, stored as the matching component type.
+pub trait BuilderComponent: Copy + sealed::Sealed {
+    #[doc(hidden)]
+    const COMPONENT_TYPE: ComponentType;
+    #[doc(hidden)]
+    fn write(self, data: &mut Vec<u8>);
+    #[doc(hidden)]
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! component {
+    ($($ty:ty => $component_type:ident),*) => {
+        $(
+            impl sealed::Sealed for $ty {}
+            impl BuilderComponent for $ty {
+                const COMPONENT_TYPE: ComponentType = ComponentType::$component_type;
+
+                fn write(self, data: &mut Vec<u8>) {
+                    data.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn to_f64(self) -> f64 {
+                    self.into()
+                }
+            }
+
+            impl BuilderElement for $ty {
+                type Component = $ty;
+                const TYPE: Type = Type::Scalar;
+
+                fn components(&self) -> &[$ty] {
+                    std::slice::from_ref(self)
+                }
+            }
+        )*
+    };
+}
+
+/// An element that accessors can be built from: a scalar, a vector of two to four components, or
+/// a 3x3 or 4x4 float matrix in column-major order.
+pub trait BuilderElement: Copy + sealed::Sealed {
+    type Component: BuilderComponent;
+    #[doc(hidden)]
+    const TYPE: Type;
+
+    #[doc(hidden)]
+    fn components(&self) -> &[Self::Component];
+}
+
+component!(
+    f32 => Float,
+    i8 => Byte,
+    u8 => UnsignedByte,
+    i16 => Short,
+    u16 => UnsignedShort,
+    u32 => UnsignedInt
+);
+
+macro_rules! array_element {
+    ($($n:literal => $ty:ident),*) => {
+        $(
+            impl<T: BuilderComponent> sealed::Sealed for [T; $n] {}
+            impl<T: BuilderComponent> BuilderElement for [T; $n] {
+                type Component = T;
+                const TYPE: Type = Type::$ty;
+
+                fn components(&self) -> &[T] {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+array_element!(2 => Vec2, 3 => Vec3, 4 => Vec4);
+
+// Matrices of smaller components pad their columns, so only float matrices are supported
+macro_rules! matrix_element {
+    ($($n:literal => $ty:ident),*) => {
+        $(
+            impl sealed::Sealed for [f32; $n] {}
+            impl BuilderElement for [f32; $n] {
+                type Component = f32;
+                const TYPE: Type = Type::$ty;
+
+                fn components(&self) -> &[f32] {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+matrix_element!(9 => Mat3, 16 => Mat4);
+
+/// Builds a document whose binary data is stored in a single buffer.
+#[derive(Debug)]
+pub struct DocumentBuilder {
+    document: InnerGltf,
+    data: Vec<u8>,
+}
+
+impl Default for DocumentBuilder {
+    fn default() -> Self {
+        DocumentBuilder::new()
+    }
+}
+
+impl DocumentBuilder {
+    /// Starts an empty glTF 2.0 document.
+    pub fn new() -> Self {
+        let asset = json!({
+            "version": "2.0",
+            "generator": concat!("gltf_for_rust ", env!("CARGO_PKG_VERSION")),
+        });
+        DocumentBuilder {
+            document: object(json!({ "asset": asset })),
+            data: Vec::new(),
+        }
+    }
+
+    /// The document built so far, to set properties the builder has no method for, e.g. the
+    /// extensions of an object.
+    pub fn document_mut(&mut self) -> &mut InnerGltf {
+        &mut self.document
+    }
+
+    /// Appends data to the buffer in a buffer view of its own, which starts on a 4-byte boundary.
+    pub fn buffer_view(&mut self, data: &[u8], target: Option<Target>) -> Index<InnerBufferView> {
+        self.push_view(data.len(), None, target, |buffer| {
+            buffer.extend_from_slice(data)
+        })
+    }
+
+    /// Appends elements in a buffer view of their own and adds an accessor for them, with their
+    /// `min` and `max`. Suits data that is not a vertex attribute, e.g. animation keyframes or
+    /// inverse bind matrices. Returns None if there are no elements, as an accessor can not be
+    /// empty.
+    pub fn accessor<T: BuilderElement>(&mut self, elements: &[T]) -> Option<Index<InnerAccessor>> {
+        self.push_accessor(elements, None, false)
+    }
+
+    /// Adds an accessor for a vertex attribute, like [`DocumentBuilder::accessor`]. Elements are
+    /// padded to 4 bytes, as the specification requires of vertex attributes.
+    pub fn vertices<T: BuilderElement>(&mut self, elements: &[T]) -> Option<Index<InnerAccessor>> {
+        self.push_accessor(elements, Some(Target::ArrayBuffer), false)
+    }
+
+    /// Adds an accessor for a vertex attribute of integers that are read as normalized values,
+    /// e.g. colors stored as unsigned bytes.
+    pub fn normalized_vertices<T: BuilderElement>(
+        &mut self,
+        elements: &[T],
+    ) -> Option<Index<InnerAccessor>> {
+        self.push_accessor(elements, Some(Target::ArrayBuffer), true)
+    }
+
+    /// Adds an accessor for the vertex indices of a primitive, stored as unsigned shorts if they
+    /// fit and as unsigned ints otherwise. Returns None if there are no indices.
+    pub fn indices(&mut self, indices: &[u32]) -> Option<Index<InnerAccessor>> {
+        // The largest value of a component type restarts primitives, so it can not be an index
+        match indices.iter().all(|&index| index < u16::MAX as u32) {
+            true => {
+                let indices: Vec<u16> = indices.iter().map(|&index| index as u16).collect();
+                self.push_accessor(&indices, Some(Target::ElementArrayBuffer), false)
+            }
+            false => self.push_accessor(indices, Some(Target::ElementArrayBuffer), false),
+        }
+    }
+
+    /// Stores an encoded image, e.g. a PNG file, in the buffer.
+    pub fn image(&mut self, data: &[u8], mime_type: MimeType) -> Index<InnerImage> {
+        let buffer_view = self.buffer_view(data, None);
+        let mut image: InnerImage = object(json!({}));
+        image.buffer_view = Some(buffer_view);
+        image.mime_type = Some(mime_type);
+        push(&mut self.document.images, image)
+    }
+
+    /// Adds a texture that samples an image with the default sampler.
+    pub fn texture(&mut self, image: Index<InnerImage>) -> Index<InnerTexture> {
+        let mut texture: InnerTexture = object(json!({}));
+        texture.source = Some(image);
+        push(&mut self.document.textures, texture)
+    }
+
+    /// Adds a metallic-roughness material with constant factors. Textures and other properties
+    /// can be set through [`DocumentBuilder::document_mut`].
+    pub fn material(
+        &mut self,
+        base_color_factor: [f32; 4],
+        metallic_factor: f32,
+        roughness_factor: f32,
+    ) -> Index<InnerMaterial> {
+        let mut material: InnerMaterial = object(json!({}));
+        let mut pbr: InnerPbrMetallicRoughness = object(json!({}));
+        pbr.base_color_factor = base_color_factor.map(f64::from);
+        pbr.metallic_factor = metallic_factor.into();
+        pbr.roughness_factor = roughness_factor.into();
+        material.pbr_metallic_roughness = Some(pbr);
+        push(&mut self.document.materials, material)
+    }
+
+    /// Adds a mesh without primitives.
+    pub fn mesh(&mut self) -> Index<InnerMesh> {
+        push(
+            &mut self.document.meshes,
+            object(json!({ "primitives": [] })),
+        )
+    }
+
+    /// Adds a primitive that draws a list of triangles to a mesh.
+    pub fn primitive(
+        &mut self,
+        mesh: Index<InnerMesh>,
+        attributes: impl IntoIterator<Item = (Semantic, Index<InnerAccessor>)>,
+        indices: Option<Index<InnerAccessor>>,
+        material: Option<Index<InnerMaterial>>,
+    ) {
+        let mut primitive: InnerPrimitive = object(json!({ "attributes": {} }));
+        primitive.attributes = attributes
+            .into_iter()
+            .map(|(semantic, accessor)| (semantic.to_string(), accessor.value().into()))
+            .collect();
+        primitive.indices = indices;
+        primitive.material = material;
+        self.document.meshes[mesh.value()]
+            .primitives
+            .push(primitive);
+    }
+
+    /// Adds a node with a transform relative to its parent, which instantiates a mesh if given.
+    pub fn node(
+        &mut self,
+        mesh: Option<Index<InnerMesh>>,
+        transform: Transform,
+    ) -> Index<InnerNode> {
+        let mut node: InnerNode = object(json!({}));
+        node.mesh = mesh;
+        node.translation = transform.translation.map(f64::from);
+        node.rotation = transform.rotation.map(f64::from);
+        node.scale = transform.scale.map(f64::from);
+        push(&mut self.document.nodes, node)
+    }
+
+    /// Makes a node a child of another node.
+    pub fn add_child(&mut self, parent: Index<InnerNode>, child: Index<InnerNode>) {
+        self.document.nodes[parent.value()].children.push(child);
+    }
+
+    /// Adds a scene with root nodes. The first scene is displayed when the document is loaded.
+    pub fn scene(&mut self, nodes: &[Index<InnerNode>]) -> Index<InnerScene> {
+        let mut scene: InnerScene = object(json!({}));
+        scene.nodes = nodes.to_vec();
+        let scene = push(&mut self.document.scenes, scene);
+        self.document.scene.get_or_insert(scene);
+        scene
+    }
+
+    /// Finishes the document. The buffer has no `uri`, so it is stored in the binary chunk when
+    /// exported as GLB and embedded when exported as JSON.
+    pub fn finish(mut self) -> Gltf {
+        let mut buffers = Vec::new();
+        if let Some(buffer) = self.document.buffers.first_mut() {
+            self.data.resize(self.data.len().next_multiple_of(4), 0);
+            buffer.byte_length = self.data.len() as i64;
+            buffers.push(Some(self.data));
+        }
+        Gltf::from_document(self.document, buffers)
+    }
+
+    /// Appends data written by `write` in a buffer view of `byte_length` bytes.
+    fn push_view(
+        &mut self,
+        byte_length: usize,
+        byte_stride: Option<usize>,
+        target: Option<Target>,
+        write: impl FnOnce(&mut Vec<u8>),
+    ) -> Index<InnerBufferView> {
+        if self.document.buffers.is_empty() {
+            self.document.buffers.push(InnerBuffer {
+                extensions: None,
+                extras: None,
+                name: None,
+                byte_length: 0,
+                uri: None,
+                unknown: Map::new(),
+            });
+        }
+        self.data.resize(self.data.len().next_multiple_of(4), 0);
+        let byte_offset = self.data.len();
+        write(&mut self.data);
+        let view = InnerBufferView {
+            extensions: None,
+            extras: None,
+            name: None,
+            buffer: Index::new(0),
+            byte_length: byte_length as i64,
+            byte_offset: byte_offset as i64,
+            byte_stride: byte_stride.map(|stride| stride as i64),
+            target,
+            unknown: Map::new(),
+        };
+        push(&mut self.document.buffer_views, view)
+    }
+
+    fn push_accessor<T: BuilderElement>(
+        &mut self,
+        elements: &[T],
+        target: Option<Target>,
+        normalized: bool,
+    ) -> Option<Index<InnerAccessor>> {
+        if elements.is_empty() {
+            return None;
+        }
+        let size = std::mem::size_of::<T>();
+        let stride = match target {
+            Some(Target::ArrayBuffer) => size.next_multiple_of(4),
+            _ => size,
+        };
+        let byte_length = stride * (elements.len() - 1) + size;
+        let byte_stride = (stride != size).then_some(stride);
+        let buffer_view = self.push_view(byte_length, byte_stride, target, |data| {
+            for (index, element) in elements.iter().enumerate() {
+                if index > 0 {
+                    data.resize(data.len() + stride - size, 0);
+                }
+                for &component in element.components() {
+                    component.write(data);
+                }
+            }
+        });
+
+        let (mut min, mut max) = (Vec::new(), Vec::new());
+        for element in elements {
+            let components = element.components().iter().map(|value| value.to_f64());
+            if min.is_empty() {
+                min = components.clone().collect();
+                max = components.collect();
+                continue;
+            }
+            for (i, value) in components.enumerate() {
+                min[i] = f64::min(min[i], value);
+                max[i] = f64::max(max[i], value);
+            }
+        }
+
+        let accessor = InnerAccessor {
+            extensions: None,
+            extras: None,
+            name: None,
+            buffer_view: Some(buffer_view),
+            byte_offset: 0,
+            component_type: T::Component::COMPONENT_TYPE,
+            count: elements.len() as i64,
+            max,
+            min,
+            normalized,
+            sparse: None,
+            ty: T::TYPE,
+            unknown: Map::new(),
+        };
+        Some(push(&mut self.document.accessors, accessor))
+    }
+}
+
+/// Deserializes an object from its required properties, which leaves the others at their default
+/// as the generated structures have no other way to construct them.
+fn object<T: DeserializeOwned>(properties: Value) -> T {
+    serde_json::from_value(properties).expect("the required properties of the object are given")
+}
+
+fn push<T>(collection: &mut Vec<T>, item: T) -> Index<T> {
+    collection.push(item);
+    Index::new(collection.len() as u32 - 1)
+}
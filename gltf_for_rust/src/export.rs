@@ -0,0 +1,185 @@
+//! Serialization of glTF assets to the JSON (.gltf) and binary (.glb) formats.
+
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::accessor::AccessorError;
+use crate::bounds;
+use crate::glb;
+use crate::import::Gltf;
+use crate::uri;
+
+/// Options controlling how an asset is exported.
+#[derive(Clone, Debug, Default)]
+pub struct ExportOptions {
+    /// Embeds buffers that refer to external resources as base64 data URIs.
+    /// Buffers without a `uri` are always embedded when exporting to JSON, as required by the specification.
+    pub embed_buffers: bool,
+    /// Recomputes the `min` and `max` of the accessors used as `POSITION`, including those of
+    /// morph targets, from their data.
+    pub update_position_bounds: bool,
+}
+
+#[derive(Error, Debug)]
+pub enum ExportError {
+    #[error("IO error: {0}")]
+    Io(std::io::Error),
+    #[error("Serialization failed: {0}")]
+    SerializeError(serde_json::Error),
+    #[error("GLB container of {0} bytes exceeds the maximum size of 4 GiB")]
+    GlbTooLarge(usize),
+    #[error("URI {0} has a scheme that is not supported, only data URIs and relative paths are")]
+    UnsupportedScheme(String),
+    #[error("URI {0} is an absolute path or leaves the directory of the exported file")]
+    PathOutsideDirectory(String),
+    #[error("Bounds of accessor {accessor} can not be computed: {source}")]
+    PositionBounds {
+        accessor: usize,
+        source: AccessorError,
+    },
+}
+
+/// Exports a glTF asset to a file, in the binary format if the path has a .glb extension and in the
+/// JSON format otherwise. External buffers and images that are not embedded are written relative
+/// to the directory containing the file. Nothing is written if the `uri` of one of them has a
+/// scheme, is absolute or leaves that directory.
+pub fn export(gltf: &Gltf, path: &str, options: &ExportOptions) -> Result<(), ExportError> {
+    let path = Path::new(path);
+    let binary = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("glb"));
+    let base = path.parent().unwrap_or(Path::new(""));
+    let document = gltf.document();
+    // Embedded buffers and the buffer stored in the BIN chunk have no file of their own
+    let buffers = document
+        .buffers
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !(options.embed_buffers || (binary && *index == 0)))
+        .map(|(index, buffer)| (&buffer.uri, gltf.buffer_data(index)));
+    let images = document
+        .images
+        .iter()
+        .enumerate()
+        .map(|(index, image)| (&image.uri, gltf.image_data(index)));
+    let mut resources = Vec::new();
+    for (uri, data) in buffers.chain(images) {
+        let (Some(uri), Some(data)) = (uri, data) else {
+            continue;
+        };
+        if uri::is_data_uri(uri) {
+            continue;
+        }
+        if uri::has_scheme(uri) {
+            return Err(ExportError::UnsupportedScheme(uri.clone()));
+        }
+        let decoded = uri::decode_path(uri);
+        let relative = uri::contained_path(&decoded)
+            .ok_or_else(|| ExportError::PathOutsideDirectory(uri.clone()))?;
+        resources.push((base.join(relative), data));
+    }
+
+    let data = match binary {
+        true => to_glb(gltf, options)?,
+        false => to_json(gltf, options)?,
+    };
+    fs::write(path, data).map_err(ExportError::Io)?;
+    for (path, data) in resources {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(ExportError::Io)?;
+        }
+        fs::write(path, data).map_err(ExportError::Io)?;
+    }
+    Ok(())
+}
+
+/// Serializes a glTF asset to JSON. Properties that are not set are omitted.
+pub fn to_json(gltf: &Gltf, options: &ExportOptions) -> Result<Vec<u8>, ExportError> {
+    let document = serialize_document(gltf, options, false)?;
+    serde_json::to_vec_pretty(&document).map_err(ExportError::SerializeError)
+}
+
+/// Serializes a glTF asset to a binary container. The contents of the first buffer are stored in
+/// the BIN chunk.
+pub fn to_glb(gltf: &Gltf, options: &ExportOptions) -> Result<Vec<u8>, ExportError> {
+    let document = serialize_document(gltf, options, true)?;
+    let json = serde_json::to_vec(&document).map_err(ExportError::SerializeError)?;
+    glb::write(&json, gltf.buffer_data(0))
+}
+
+fn serialize_document(
+    gltf: &Gltf,
+    options: &ExportOptions,
+    binary: bool,
+) -> Result<Value, ExportError> {
+    let mut document =
+        serde_json::to_value(gltf.document()).map_err(ExportError::SerializeError)?;
+    if options.update_position_bounds {
+        update_position_bounds(gltf, &mut document)?;
+    }
+
+    let Some(buffers) = document.get_mut("buffers").and_then(Value::as_array_mut) else {
+        return Ok(document);
+    };
+    for (index, buffer) in buffers.iter_mut().enumerate() {
+        let (Some(buffer), Some(data)) = (buffer.as_object_mut(), gltf.buffer_data(index)) else {
+            continue;
+        };
+
+        // The first buffer of a binary container refers to the BIN chunk by omitting its uri
+        if binary && index == 0 {
+            buffer.remove("uri");
+            continue;
+        }
+
+        let embed = match buffer.get("uri").and_then(Value::as_str) {
+            Some(uri) => options.embed_buffers && !uri::is_data_uri(uri),
+            None => true,
+        };
+        if embed {
+            buffer.insert("uri".to_string(), Value::String(uri::encode_data_uri(data)));
+        }
+    }
+    Ok(document)
+}
+
+/// Sets the `min` and `max` of every accessor used as `POSITION` to the bounds of its data.
+fn update_position_bounds(gltf: &Gltf, document: &mut Value) -> Result<(), ExportError> {
+    let primitives = gltf.meshes().flat_map(|mesh| mesh.primitives());
+    let mut positions: Vec<_> = primitives
+        .flat_map(|primitive| {
+            let targets = primitive.targets().filter_map(|target| target.positions());
+            primitive.positions().into_iter().chain(targets)
+        })
+        .collect();
+    positions.sort_by_key(|accessor| accessor.index());
+    positions.dedup_by_key(|accessor| accessor.index());
+
+    for accessor in positions {
+        let index = accessor.index();
+        let extents =
+            bounds::stored_extents(&accessor).map_err(|source| ExportError::PositionBounds {
+                accessor: index,
+                source,
+            })?;
+        let object = document
+            .get_mut("accessors")
+            .and_then(|accessors| accessors.get_mut(index))
+            .and_then(Value::as_object_mut);
+        let (Some(extents), Some(object)) = (extents, object) else {
+            continue;
+        };
+        object.insert(
+            "min".to_string(),
+            extents.min.map(f64::from).to_vec().into(),
+        );
+        object.insert(
+            "max".to_string(),
+            extents.max.map(f64::from).to_vec().into(),
+        );
+    }
+    Ok(())
+}
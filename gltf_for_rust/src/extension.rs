@@ -0,0 +1,434 @@
+//! Extensions of the objects of a document, parsed into their generated types once when the
+//! document is imported. Extensions without a generated type are left to
+//! [`GltfObject::parse_extension`], and extensions that fail to parse are kept as diagnostics of the
+//! import, see [`Gltf::extension_errors`](crate::import::Gltf::extension_errors). Validation reads
+//! the same parsed extensions and errors.
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::generated;
+use crate::schema::Gltf as InnerGltf;
+use crate::validation::escape;
+use crate::{GltfExtension, GltfObject};
+
+/// An extension of an object that does not match the schema of its generated type.
+#[derive(Error, Debug)]
+#[error("{pointer}: Extension could not be parsed: {source}")]
+pub struct ExtensionError {
+    /// JSON pointer to the extension, e.g. `/nodes/0/extensions/KHR_lights_punctual`.
+    pub pointer: String,
+    pub source: serde_json::Error,
+}
+
+/// The texture slots that extensions add to materials, by extension and property. Their values
+/// are textureInfo objects, so they can have extensions of their own, e.g. KHR_texture_transform.
+const MATERIAL_EXTENSION_TEXTURES: &[(&str, &str)] = &[
+    ("KHR_materials_anisotropy", "anisotropyTexture"),
+    ("KHR_materials_clearcoat", "clearcoatTexture"),
+    ("KHR_materials_clearcoat", "clearcoatRoughnessTexture"),
+    ("KHR_materials_clearcoat", "clearcoatNormalTexture"),
+    ("KHR_materials_iridescence", "iridescenceTexture"),
+    ("KHR_materials_iridescence", "iridescenceThicknessTexture"),
+    ("KHR_materials_sheen", "sheenColorTexture"),
+    ("KHR_materials_sheen", "sheenRoughnessTexture"),
+    ("KHR_materials_specular", "specularTexture"),
+    ("KHR_materials_specular", "specularColorTexture"),
+    ("KHR_materials_transmission", "transmissionTexture"),
+    ("KHR_materials_volume", "thicknessTexture"),
+    (
+        "ADOBE_materials_clearcoat_specular",
+        "clearcoatSpecularTexture",
+    ),
+    ("ADOBE_materials_clearcoat_tint", "clearcoatTintTexture"),
+    ("ADOBE_materials_thin_transparency", "transmissionTexture"),
+    (
+        "MSFT_packing_normalRoughnessMetallic",
+        "normalRoughnessMetallicTexture",
+    ),
+    (
+        "MSFT_packing_occlusionRoughnessMetallic",
+        "occlusionRoughnessMetallicTexture",
+    ),
+    (
+        "MSFT_packing_occlusionRoughnessMetallic",
+        "roughnessMetallicOcclusionTexture",
+    ),
+    ("MSFT_packing_occlusionRoughnessMetallic", "normalTexture"),
+];
+
+/// A slot of a material that refers to a texture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum TextureSlot {
+    BaseColor,
+    MetallicRoughness,
+    Normal,
+    Occlusion,
+    Emissive,
+    /// A slot added by a material extension, one of [`MATERIAL_EXTENSION_TEXTURES`].
+    Extension {
+        extension: &'static str,
+        property: &'static str,
+    },
+}
+
+impl TextureSlot {
+    /// Parses the segments of a JSON pointer to a slot, relative to its material.
+    fn from_segments(segments: &[&str]) -> Option<Self> {
+        Some(match *segments {
+            ["pbrMetallicRoughness", "baseColorTexture"] => TextureSlot::BaseColor,
+            ["pbrMetallicRoughness", "metallicRoughnessTexture"] => TextureSlot::MetallicRoughness,
+            ["normalTexture"] => TextureSlot::Normal,
+            ["occlusionTexture"] => TextureSlot::Occlusion,
+            ["emissiveTexture"] => TextureSlot::Emissive,
+            ["extensions", extension, property] => {
+                let &(extension, property) = MATERIAL_EXTENSION_TEXTURES
+                    .iter()
+                    .find(|&&slot| slot == (extension, property))?;
+                TextureSlot::Extension {
+                    extension,
+                    property,
+                }
+            }
+            _ => return None,
+        })
+    }
+}
+
+/// An object of a document that can have extensions, identified by its kind and the indices that
+/// lead to it from the root, e.g. the mesh and primitive index of a primitive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum Object {
+    Root,
+    Asset,
+    Accessor(usize),
+    AccessorSparse(usize),
+    AccessorSparseIndices(usize),
+    AccessorSparseValues(usize),
+    Animation(usize),
+    AnimationChannel(usize, usize),
+    AnimationChannelTarget(usize, usize),
+    AnimationSampler(usize, usize),
+    Buffer(usize),
+    BufferView(usize),
+    Camera(usize),
+    CameraOrthographic(usize),
+    CameraPerspective(usize),
+    Image(usize),
+    Material(usize),
+    MaterialPbrMetallicRoughness(usize),
+    MaterialTexture(usize, TextureSlot),
+    Mesh(usize),
+    MeshPrimitive(usize, usize),
+    Node(usize),
+    Sampler(usize),
+    Scene(usize),
+    Skin(usize),
+    Texture(usize),
+}
+
+impl Object {
+    /// Parses a JSON pointer to an object, e.g. `/meshes/0/primitives/1`.
+    pub(crate) fn from_pointer(pointer: &str) -> Option<Self> {
+        if pointer.is_empty() {
+            return Some(Object::Root);
+        }
+        // No object is nested deeper than the slot of a material extension
+        let mut segments = [""; 5];
+        let mut count = 0;
+        for segment in pointer.strip_prefix('/')?.split('/') {
+            *segments.get_mut(count)? = segment;
+            count += 1;
+        }
+        let index = |segment: &str| segment.parse::<usize>().ok();
+        Some(match segments[..count] {
+            ["asset"] => Object::Asset,
+            ["accessors", i] => Object::Accessor(index(i)?),
+            ["accessors", i, "sparse"] => Object::AccessorSparse(index(i)?),
+            ["accessors", i, "sparse", "indices"] => Object::AccessorSparseIndices(index(i)?),
+            ["accessors", i, "sparse", "values"] => Object::AccessorSparseValues(index(i)?),
+            ["animations", i] => Object::Animation(index(i)?),
+            ["animations", i, "channels", j] => Object::AnimationChannel(index(i)?, index(j)?),
+            ["animations", i, "channels", j, "target"] => {
+                Object::AnimationChannelTarget(index(i)?, index(j)?)
+            }
+            ["animations", i, "samplers", j] => Object::AnimationSampler(index(i)?, index(j)?),
+            ["buffers", i] => Object::Buffer(index(i)?),
+            ["bufferViews", i] => Object::BufferView(index(i)?),
+            ["cameras", i] => Object::Camera(index(i)?),
+            ["cameras", i, "orthographic"] => Object::CameraOrthographic(index(i)?),
+            ["cameras", i, "perspective"] => Object::CameraPerspective(index(i)?),
+            ["images", i] => Object::Image(index(i)?),
+            ["materials", i] => Object::Material(index(i)?),
+            ["materials", i, "pbrMetallicRoughness"] => {
+                Object::MaterialPbrMetallicRoughness(index(i)?)
+            }
+            ["materials", i, ref slot @ ..] => {
+                Object::MaterialTexture(index(i)?, TextureSlot::from_segments(slot)?)
+            }
+            ["meshes", i] => Object::Mesh(index(i)?),
+            ["meshes", i, "primitives", j] => Object::MeshPrimitive(index(i)?, index(j)?),
+            ["nodes", i] => Object::Node(index(i)?),
+            ["samplers", i] => Object::Sampler(index(i)?),
+            ["scenes", i] => Object::Scene(index(i)?),
+            ["skins", i] => Object::Skin(index(i)?),
+            ["textures", i] => Object::Texture(index(i)?),
+            _ => return None,
+        })
+    }
+
+    /// The JSON pointer to the object, only formatted to report errors.
+    pub(crate) fn pointer(&self) -> String {
+        match *self {
+            Object::Root => String::new(),
+            Object::Asset => "/asset".to_string(),
+            Object::Accessor(i) => format!("/accessors/{i}"),
+            Object::AccessorSparse(i) => format!("/accessors/{i}/sparse"),
+            Object::AccessorSparseIndices(i) => format!("/accessors/{i}/sparse/indices"),
+            Object::AccessorSparseValues(i) => format!("/accessors/{i}/sparse/values"),
+            Object::Animation(i) => format!("/animations/{i}"),
+            Object::AnimationChannel(i, j) => format!("/animations/{i}/channels/{j}"),
+            Object::AnimationChannelTarget(i, j) => format!("/animations/{i}/channels/{j}/target"),
+            Object::AnimationSampler(i, j) => format!("/animations/{i}/samplers/{j}"),
+            Object::Buffer(i) => format!("/buffers/{i}"),
+            Object::BufferView(i) => format!("/bufferViews/{i}"),
+            Object::Camera(i) => format!("/cameras/{i}"),
+            Object::CameraOrthographic(i) => format!("/cameras/{i}/orthographic"),
+            Object::CameraPerspective(i) => format!("/cameras/{i}/perspective"),
+            Object::Image(i) => format!("/images/{i}"),
+            Object::Material(i) => format!("/materials/{i}"),
+            Object::MaterialPbrMetallicRoughness(i) => {
+                format!("/materials/{i}/pbrMetallicRoughness")
+            }
+            Object::MaterialTexture(i, slot) => match slot {
+                TextureSlot::BaseColor => {
+                    format!("/materials/{i}/pbrMetallicRoughness/baseColorTexture")
+                }
+                TextureSlot::MetallicRoughness => {
+                    format!("/materials/{i}/pbrMetallicRoughness/metallicRoughnessTexture")
+                }
+                TextureSlot::Normal => format!("/materials/{i}/normalTexture"),
+                TextureSlot::Occlusion => format!("/materials/{i}/occlusionTexture"),
+                TextureSlot::Emissive => format!("/materials/{i}/emissiveTexture"),
+                TextureSlot::Extension {
+                    extension,
+                    property,
+                } => format!("/materials/{i}/extensions/{extension}/{property}"),
+            },
+            Object::Mesh(i) => format!("/meshes/{i}"),
+            Object::MeshPrimitive(i, j) => format!("/meshes/{i}/primitives/{j}"),
+            Object::Node(i) => format!("/nodes/{i}"),
+            Object::Sampler(i) => format!("/samplers/{i}"),
+            Object::Scene(i) => format!("/scenes/{i}"),
+            Object::Skin(i) => format!("/skins/{i}"),
+            Object::Texture(i) => format!("/textures/{i}"),
+        }
+    }
+
+    /// The module of the object in the generated extension modules, e.g. `mesh_primitive`. The
+    /// normal and occlusion textures extend textureInfo, so its extensions, e.g.
+    /// KHR_texture_transform, apply to every texture slot.
+    fn module(&self) -> &'static str {
+        match self {
+            Object::Root => "gltf",
+            Object::Asset => "asset",
+            Object::Accessor(_) => "accessor",
+            Object::AccessorSparse(_) => "accessor_sparse",
+            Object::AccessorSparseIndices(_) => "accessor_sparse_indices",
+            Object::AccessorSparseValues(_) => "accessor_sparse_values",
+            Object::Animation(_) => "animation",
+            Object::AnimationChannel(..) => "animation_channel",
+            Object::AnimationChannelTarget(..) => "animation_channel_target",
+            Object::AnimationSampler(..) => "animation_sampler",
+            Object::Buffer(_) => "buffer",
+            Object::BufferView(_) => "buffer_view",
+            Object::Camera(_) => "camera",
+            Object::CameraOrthographic(_) => "camera_orthographic",
+            Object::CameraPerspective(_) => "camera_perspective",
+            Object::Image(_) => "image",
+            Object::Material(_) => "material",
+            Object::MaterialPbrMetallicRoughness(_) => "material_pbr_metallic_roughness",
+            Object::MaterialTexture(..) => "texture_info",
+            Object::Mesh(_) => "mesh",
+            Object::MeshPrimitive(..) => "mesh_primitive",
+            Object::Node(_) => "node",
+            Object::Sampler(_) => "sampler",
+            Object::Scene(_) => "scene",
+            Object::Skin(_) => "skin",
+            Object::Texture(_) => "texture",
+        }
+    }
+}
+
+/// Calls `visit` with the extensions of every object of a document that has any, in document
+/// order. This is the set of objects whose extensions are parsed and validated.
+pub(crate) fn visit_objects(
+    document: &InnerGltf,
+    visit: &mut impl FnMut(Object, &Map<String, Value>),
+) {
+    visit_object(visit, Object::Root, document);
+    visit_object(visit, Object::Asset, &document.asset);
+
+    for (index, accessor) in document.accessors.iter().enumerate() {
+        visit_object(visit, Object::Accessor(index), accessor);
+        if let Some(sparse) = &accessor.sparse {
+            visit_object(visit, Object::AccessorSparse(index), sparse);
+            visit_object(visit, Object::AccessorSparseIndices(index), &sparse.indices);
+            visit_object(visit, Object::AccessorSparseValues(index), &sparse.values);
+        }
+    }
+    for (index, animation) in document.animations.iter().enumerate() {
+        visit_object(visit, Object::Animation(index), animation);
+        for (channel_index, channel) in animation.channels.iter().enumerate() {
+            visit_object(
+                visit,
+                Object::AnimationChannel(index, channel_index),
+                channel,
+            );
+            let target = Object::AnimationChannelTarget(index, channel_index);
+            visit_object(visit, target, &channel.target);
+        }
+        for (sampler_index, sampler) in animation.samplers.iter().enumerate() {
+            visit_object(
+                visit,
+                Object::AnimationSampler(index, sampler_index),
+                sampler,
+            );
+        }
+    }
+    visit_collection(visit, Object::Buffer, &document.buffers);
+    visit_collection(visit, Object::BufferView, &document.buffer_views);
+    for (index, camera) in document.cameras.iter().enumerate() {
+        visit_object(visit, Object::Camera(index), camera);
+        if let Some(orthographic) = &camera.orthographic {
+            visit_object(visit, Object::CameraOrthographic(index), orthographic);
+        }
+        if let Some(perspective) = &camera.perspective {
+            visit_object(visit, Object::CameraPerspective(index), perspective);
+        }
+    }
+    visit_collection(visit, Object::Image, &document.images);
+    for (index, material) in document.materials.iter().enumerate() {
+        visit_object(visit, Object::Material(index), material);
+        let texture = |slot| Object::MaterialTexture(index, slot);
+        if let Some(pbr) = &material.pbr_metallic_roughness {
+            visit_object(visit, Object::MaterialPbrMetallicRoughness(index), pbr);
+            if let Some(base_color) = &pbr.base_color_texture {
+                visit_object(visit, texture(TextureSlot::BaseColor), base_color);
+            }
+            if let Some(metallic_roughness) = &pbr.metallic_roughness_texture {
+                let slot = TextureSlot::MetallicRoughness;
+                visit_object(visit, texture(slot), metallic_roughness);
+            }
+        }
+        if let Some(normal) = &material.normal_texture {
+            visit_object(visit, texture(TextureSlot::Normal), normal);
+        }
+        if let Some(occlusion) = &material.occlusion_texture {
+            visit_object(visit, texture(TextureSlot::Occlusion), occlusion);
+        }
+        if let Some(emissive) = &material.emissive_texture {
+            visit_object(visit, texture(TextureSlot::Emissive), emissive);
+        }
+        // The textures of material extensions are only parsed as JSON, as their typed
+        // structures are themselves extensions
+        for &(extension, property) in MATERIAL_EXTENSION_TEXTURES {
+            let texture_extensions = material
+                .extensions
+                .as_ref()
+                .and_then(|extensions| extensions.get(extension)?.get(property))
+                .and_then(|texture| texture.get("extensions")?.as_object());
+            if let Some(texture_extensions) = texture_extensions {
+                let slot = TextureSlot::Extension {
+                    extension,
+                    property,
+                };
+                visit(texture(slot), texture_extensions);
+            }
+        }
+    }
+    for (index, mesh) in document.meshes.iter().enumerate() {
+        visit_object(visit, Object::Mesh(index), mesh);
+        for (primitive_index, primitive) in mesh.primitives.iter().enumerate() {
+            visit_object(
+                visit,
+                Object::MeshPrimitive(index, primitive_index),
+                primitive,
+            );
+        }
+    }
+    visit_collection(visit, Object::Node, &document.nodes);
+    visit_collection(visit, Object::Sampler, &document.samplers);
+    visit_collection(visit, Object::Scene, &document.scenes);
+    visit_collection(visit, Object::Skin, &document.skins);
+    visit_collection(visit, Object::Texture, &document.textures);
+}
+
+fn visit_collection(
+    visit: &mut impl FnMut(Object, &Map<String, Value>),
+    object: fn(usize) -> Object,
+    values: &[impl GltfObject],
+) {
+    for (index, value) in values.iter().enumerate() {
+        visit_object(visit, object(index), value);
+    }
+}
+
+fn visit_object(
+    visit: &mut impl FnMut(Object, &Map<String, Value>),
+    object: Object,
+    value: &impl GltfObject,
+) {
+    if let Some(extensions) = value.extensions() {
+        visit(object, extensions);
+    }
+}
+
+/// The parsed extensions of every object of a document, by type and object.
+#[derive(Debug, Default)]
+pub(crate) struct Extensions {
+    parsed: HashMap<(TypeId, Object), Box<dyn Any + Send + Sync>>,
+    errors: Vec<ExtensionError>,
+}
+
+impl Extensions {
+    /// Parses the extensions of every object of a document that have a generated type.
+    pub(crate) fn parse(document: &InnerGltf) -> Self {
+        let mut extensions = Extensions::default();
+        visit_objects(document, &mut |object, values| {
+            extensions.extensions(object, values)
+        });
+        extensions
+    }
+
+    /// Returns the extension of a type on an object, if it has one that parsed.
+    pub(crate) fn get<T: GltfExtension + 'static>(&self, object: Object) -> Option<&T> {
+        self.parsed
+            .get(&(TypeId::of::<T>(), object))?
+            .downcast_ref()
+    }
+
+    pub(crate) fn errors(&self) -> &[ExtensionError] {
+        &self.errors
+    }
+
+    /// Parses the extensions of an object into the types generated for its kind of object.
+    fn extensions(&mut self, object: Object, extensions: &Map<String, Value>) {
+        for (name, extension) in extensions {
+            match generated::parse_extension(object.module(), name, extension) {
+                Some(Ok(parsed)) => {
+                    let type_id = (*parsed).type_id();
+                    self.parsed.insert((type_id, object), parsed);
+                }
+                Some(Err(source)) => self.errors.push(ExtensionError {
+                    pointer: format!("{}/extensions/{}", object.pointer(), escape(name)),
+                    source,
+                }),
+                None => {}
+            }
+        }
+    }
+}
@@ -1,29 +1,38 @@
 #![allow(clippy::all, unused_imports)]
-mod gltf {
-    mod extension {
+pub use material::Extension as MaterialExtension;
+pub mod material {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that defines the colour tint of the clearcoat.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "clearcoatIor")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The clearcoat layer's index of refraction.
             pub clearcoat_ior: Option<f64>,
             #[serde(rename = "clearcoatSpecularFactor")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The clearcoat layer's specular intensity.
             pub clearcoat_specular_factor: Option<f64>,
             #[serde(rename = "clearcoatSpecularTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The clearcoat layer's specular intensity texture. These values are sampled from the B channel.
             pub clearcoat_specular_texture: Option<crate::generated::gltf::TextureInfo>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -35,6 +44,21 @@ mod gltf {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "clearcoatSpecularTexture");
+                    if let Some(value) = &self.clearcoat_specular_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
     }
     pub use extension::Extension;
 }
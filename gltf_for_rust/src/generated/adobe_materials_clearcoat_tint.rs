@@ -1,25 +1,33 @@
 #![allow(clippy::all, unused_imports)]
-mod material {
-    mod extension {
+pub use material::Extension as MaterialExtension;
+pub mod material {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that defines the colour tint of the clearcoat.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "clearcoatTintFactor")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The colour of light allowed to be transmitted through the clearcoat layer of the material. A value of black means no light passes through. A value of white means all light passes through. These values are linear.
             pub clearcoat_tint_factor: Option<[f64; 3usize]>,
             #[serde(rename = "clearcoatTintTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The clearcoat layer tint texture.  The values are stored in sRGB.  Assume white colour if no texture is supplied.
             pub clearcoat_tint_texture: Option<crate::generated::gltf::TextureInfo>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -31,6 +39,21 @@ mod material {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "clearcoatTintTexture");
+                    if let Some(value) = &self.clearcoat_tint_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
     }
     pub use extension::Extension;
 }
@@ -1,28 +1,37 @@
 #![allow(clippy::all, unused_imports)]
-mod gltf {
-    mod extension {
+pub use material::Extension as MaterialExtension;
+pub mod material {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that defines properties to model physically plausible optical transparency.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The average index of refraction of the material, ignoring differences between frequencies of light. Default of 1.33 is for liquid water.
             pub ior: Option<f64>,
             #[serde(rename = "transmissionFactor")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The base percentage of non-specularly reflected light that is transmitted through the surface. i.e. of the light that penetrates a surface (isn't specularly reflected), what percentage is transmitted and not diffusely re-emitted from the surface?
             pub transmission_factor: Option<f64>,
             #[serde(rename = "transmissionTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The percentage of non-specularly reflected light that is transmitted through the surface. i.e. of the light that penetrates a surface (isn't specularly reflected), what percentage is transmitted and not diffusely re-emitted from the surface? This will be multiplied by the transmissionFactor.
             pub transmission_texture: Option<crate::generated::gltf::TextureInfo>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -34,6 +43,21 @@ mod gltf {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "transmissionTexture");
+                    if let Some(value) = &self.transmission_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
     }
     pub use extension::Extension;
 }
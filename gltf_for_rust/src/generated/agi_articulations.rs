@@ -1,20 +1,28 @@
 #![allow(clippy::all, unused_imports)]
-mod gltf {
-    mod extension {
+pub use gltf::Extension as RootExtension;
+pub use node::Extension as NodeExtension;
+pub mod gltf {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF Extension that defines metadata for applying external analysis or effects to a model.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Vec::is_empty")]
             ///An array of articulations.  An articulation indicates a named range of motion available to one or more nodes within the model.
             pub articulations: Vec<crate::generated::agi_articulations::Articulation>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -26,30 +34,54 @@ mod gltf {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "articulations");
+                    let value = &self.articulations;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
     }
     pub use extension::Extension;
 }
-mod node {
-    mod extension {
+pub mod node {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF Extension for an individual node in a glTF model, to associate it with the model's root AGI_articulations object.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "articulationName")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The name of an Articulation that applies to this node.  Articulations are defined in the glTF root extension.  A single articulation may apply to more than one node, and its stage values set the transform for all assigned nodes simultaneously.
             pub articulation_name: Option<String>,
             #[serde(rename = "isAttachPoint")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Set to true to indicate that this node's origin and orientation act as an attach point for external objects, analysis, or effects.
             pub is_attach_point: Option<bool>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -61,47 +93,80 @@ mod node {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
     }
     pub use extension::Extension;
 }
-mod articulation {
+pub mod articulation {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///A model articulation definition.
     pub struct Articulation {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         ///The name of this articulation.  The articulation name must be unique within this model.  Articulation names may not contain spaces.
         pub name: String,
         #[serde(rename = "pointingVector")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The local forward vector for the associated node, for the purpose of pointing at a target or other object.
         pub pointing_vector: Option<[f64; 3usize]>,
         ///An array of stages, each of which defines a degree of freedom of movement.
         pub stages: Vec<crate::generated::agi_articulations::ArticulationStage>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Articulation {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Articulation {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "stages");
+                let value = &self.stages;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+        }
+    }
 }
 pub use articulation::Articulation;
-mod articulation_stage {
+pub mod articulation_stage {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///One stage of a model articulation definition.
     pub struct ArticulationStage {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(rename = "initialValue")]
@@ -118,11 +183,22 @@ mod articulation_stage {
         #[serde(rename = "type")]
         ///The type of motion applied by this articulation stage.
         pub ty: serde_json::Value,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for ArticulationStage {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for ArticulationStage {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
 }
 pub use articulation_stage::ArticulationStage;
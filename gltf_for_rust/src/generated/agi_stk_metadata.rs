@@ -1,23 +1,31 @@
 #![allow(clippy::all, unused_imports)]
-mod gltf {
-    mod extension {
+pub use gltf::Extension as RootExtension;
+pub use node::Extension as NodeExtension;
+pub mod gltf {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF Extension that defines metadata for use with STK (Systems Tool Kit).
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "solarPanelGroups")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Vec::is_empty")]
             ///An array of solar panel groups.
             pub solar_panel_groups: Vec<
                 crate::generated::agi_stk_metadata::SolarPanelGroup,
             >,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -29,30 +37,54 @@ mod gltf {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "solarPanelGroups");
+                    let value = &self.solar_panel_groups;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
     }
     pub use extension::Extension;
 }
-mod node {
-    mod extension {
+pub mod node {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF Extension for an individual node in a glTF model, to associate it with the model's root AGI_stk_metadata object.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "noObscuration")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Set to true to indicate that this node's geometry does not obscure any sensors' view in the STK Sensor Obscuration tool.
             pub no_obscuration: Option<bool>,
             #[serde(rename = "solarPanelGroupName")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The name of a Solar Panel Group that includes this node.  Solar Panel Groups are defined in the glTF root extension.
             pub solar_panel_group_name: Option<String>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -64,30 +96,51 @@ mod node {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
     }
     pub use extension::Extension;
 }
-mod solar_panel_group {
+pub mod solar_panel_group {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///A solar panel group definition.
     pub struct SolarPanelGroup {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         ///The percentage, from 0.0 to 100.0, of how efficiently the solar cells convert solar to electrical energy.
         pub efficiency: f64,
         ///The name of this solar panel group.  The group name must be unique within this model, and may not contain spaces.
         pub name: String,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for SolarPanelGroup {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for SolarPanelGroup {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
 }
 pub use solar_panel_group::SolarPanelGroup;
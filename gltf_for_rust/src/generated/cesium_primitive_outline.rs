@@ -1,20 +1,27 @@
 #![allow(clippy::all, unused_imports)]
-mod primitive {
-    mod extension {
+pub use mesh_primitive::Extension as MeshPrimitiveExtension;
+pub mod mesh_primitive {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension for indicating that some edges of a primitive's triangles should be outlined.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The index of the accessor providing the list of highlighted lines at the edge of this primitive's triangles.
-            pub indices: Option<i64>,
+            pub indices: Option<crate::Index<crate::generated::gltf::Accessor>>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -26,6 +33,14 @@ mod primitive {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
     }
     pub use extension::Extension;
 }
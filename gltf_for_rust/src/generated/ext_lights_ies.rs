@@ -1,18 +1,25 @@
 #![allow(clippy::all, unused_imports)]
-mod gltf {
-    mod extension {
+pub use gltf::Extension as RootExtension;
+pub use node::Extension as NodeExtension;
+pub mod gltf {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that enables the use of IES light profiles.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
-            pub lights: Vec<Map<String, Value>>,
+            pub lights: Vec<crate::generated::ext_lights_ies::LightProfile>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -24,29 +31,61 @@ mod gltf {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "lights");
+                    let value = &self.lights;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
+        impl crate::Get<crate::generated::ext_lights_ies::LightProfile> for Extension {
+            fn get(
+                &self,
+                index: crate::Index<crate::generated::ext_lights_ies::LightProfile>,
+            ) -> Option<&crate::generated::ext_lights_ies::LightProfile> {
+                self.lights.get(index.value())
+            }
+        }
     }
     pub use extension::Extension;
 }
-mod node {
-    mod extension {
+pub mod node {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///RGB value for the light's color in linear space.
             pub color: Option<[f64; 3usize]>,
             ///The id of the light profile referenced by this node.
-            pub light: i64,
+            pub light: crate::Index<crate::generated::ext_lights_ies::LightProfile>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Non-negative factor to scale the light's intensity.
             pub multiplier: Option<f64>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -58,6 +97,88 @@ mod node {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
     }
     pub use extension::Extension;
 }
+pub mod light_profile {
+    use serde::{Serialize, Deserialize};
+    use serde_json::{Map, Value};
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    #[serde(from = "String", into = "String")]
+    pub enum MimeType {
+        ApplicationXIesLm63,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(String),
+    }
+    impl From<String> for MimeType {
+        fn from(value: String) -> Self {
+            match value.as_str() {
+                "application/x-ies-lm-63" => MimeType::ApplicationXIesLm63,
+                _ => MimeType::Other(value),
+            }
+        }
+    }
+    impl From<MimeType> for String {
+        fn from(value: MimeType) -> Self {
+            match value {
+                MimeType::ApplicationXIesLm63 => String::from("application/x-ies-lm-63"),
+                MimeType::Other(other) => other,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug)]
+    ///An IES light profile.
+    pub struct LightProfile {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///JSON object with extension-specific objects.
+        pub extensions: Option<Map<String, Value>>,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///Application-specific data.
+        pub extras: Option<serde_json::Value>,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
+        pub name: Option<String>,
+        #[serde(rename = "bufferView")]
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///The index of the bufferView that contains the IES light profile.
+        pub buffer_view: Option<crate::Index<crate::generated::gltf::BufferView>>,
+        #[serde(rename = "mimeType")]
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///The light profile's media type.
+        pub mime_type: Option<MimeType>,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///The uri of the IES light profile.
+        pub uri: Option<String>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
+    }
+    impl crate::GltfObject for LightProfile {
+        fn extensions(&self) -> &Option<Map<String, Value>> {
+            &self.extensions
+        }
+    }
+    impl crate::validation::Validate for LightProfile {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
+}
+pub use light_profile::LightProfile;
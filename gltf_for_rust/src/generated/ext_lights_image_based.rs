@@ -1,17 +1,24 @@
 #![allow(clippy::all, unused_imports)]
-mod gltf {
-    mod extension {
+pub use gltf::Extension as RootExtension;
+pub use scene::Extension as SceneExtension;
+pub mod gltf {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
-            pub lights: Vec<Map<String, Value>>,
+            pub lights: Vec<crate::generated::ext_lights_image_based::ImageBasedLight>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -23,23 +30,58 @@ mod gltf {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "lights");
+                    let value = &self.lights;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
+        impl crate::Get<crate::generated::ext_lights_image_based::ImageBasedLight>
+        for Extension {
+            fn get(
+                &self,
+                index: crate::Index<
+                    crate::generated::ext_lights_image_based::ImageBasedLight,
+                >,
+            ) -> Option<&crate::generated::ext_lights_image_based::ImageBasedLight> {
+                self.lights.get(index.value())
+            }
+        }
     }
     pub use extension::Extension;
 }
-mod scene {
-    mod extension {
+pub mod scene {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             ///The id of the light referenced by this scene.
-            pub light: i64,
+            pub light: crate::Index<
+                crate::generated::ext_lights_image_based::ImageBasedLight,
+            >,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -51,6 +93,124 @@ mod scene {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
     }
     pub use extension::Extension;
 }
+pub mod image_based_light {
+    use serde::{Serialize, Deserialize};
+    use serde_json::{Map, Value};
+    #[derive(Serialize, Deserialize, Debug)]
+    ///An image based environment light.
+    pub struct ImageBasedLight {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///JSON object with extension-specific objects.
+        pub extensions: Option<Map<String, Value>>,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///Application-specific data.
+        pub extras: Option<serde_json::Value>,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
+        pub name: Option<String>,
+        #[serde(default = "default_intensity")]
+        #[serde(skip_serializing_if = "is_default_intensity")]
+        ///Brightness multiplier for environment.
+        pub intensity: f64,
+        #[serde(rename = "irradianceCoefficients")]
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///Declares spherical harmonic coefficients for irradiance up to l=2.
+        pub irradiance_coefficients: Option<[[f64; 3usize]; 9usize]>,
+        #[serde(default = "default_rotation")]
+        #[serde(skip_serializing_if = "is_default_rotation")]
+        ///Quaternion that represents the rotation of the IBL environment.
+        pub rotation: [f64; 4usize],
+        #[serde(rename = "specularImageSize")]
+        ///The dimension (in pixels) of the first specular mip.
+        pub specular_image_size: i64,
+        #[serde(rename = "specularImages")]
+        ///Declares an array of the first N mips of the prefiltered cubemap.
+        pub specular_images: Vec<[crate::Index<crate::generated::gltf::Image>; 6usize]>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
+    }
+    impl crate::GltfObject for ImageBasedLight {
+        fn extensions(&self) -> &Option<Map<String, Value>> {
+            &self.extensions
+        }
+    }
+    impl crate::validation::Validate for ImageBasedLight {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "intensity");
+                let value = &self.intensity;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "rotation");
+                let value = &self.rotation;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        Some(-1.0),
+                        None,
+                        Some(1.0),
+                        errors,
+                    );
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "specularImageSize");
+                let value = &self.specular_image_size;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(1.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+        }
+    }
+    ///The default value of `intensity`.
+    pub fn default_intensity() -> f64 {
+        1.0
+    }
+    fn is_default_intensity(value: &f64) -> bool {
+        *value == default_intensity()
+    }
+    ///The default value of `rotation`.
+    pub fn default_rotation() -> [f64; 4usize] {
+        [0.0, 0.0, 0.0, 1.0]
+    }
+    fn is_default_rotation(value: &[f64; 4usize]) -> bool {
+        *value == default_rotation()
+    }
+}
+pub use image_based_light::ImageBasedLight;
@@ -1,27 +1,35 @@
 #![allow(clippy::all, unused_imports)]
-mod mesh {
-    mod extension {
+pub use mesh::Extension as MeshExtension;
+pub mod mesh {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension defines manifoldness for a mesh.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "manifoldPrimitive")]
             pub manifold_primitive: crate::generated::gltf::MeshPrimitive,
             #[serde(rename = "mergeIndices")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The index of the accessor that contains the vertex sparse indices for merging into a manifold.
-            pub merge_indices: Option<i64>,
+            pub merge_indices: Option<crate::Index<crate::generated::gltf::Accessor>>,
             #[serde(rename = "mergeValues")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The index of the accessor that contains the vertex sparse values for merging into a manifold.
-            pub merge_values: Option<i64>,
+            pub merge_values: Option<crate::Index<crate::generated::gltf::Accessor>>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -33,6 +41,20 @@ mod mesh {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "manifoldPrimitive");
+                    let value = &self.manifold_primitive;
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+        }
     }
     pub use extension::Extension;
 }
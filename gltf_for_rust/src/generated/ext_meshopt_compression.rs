@@ -1,20 +1,28 @@
 #![allow(clippy::all, unused_imports)]
-mod buffer {
-    mod extension {
+pub use buffer::Extension as BufferExtension;
+pub use buffer_view::Extension as BufferViewExtension;
+pub mod buffer {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///Compressed data for bufferView.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Set to true to indicate that the buffer is only referenced by bufferViews that have EXT_meshopt_compression extension and as such doesn't need to be loaded.
             pub fallback: Option<bool>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -26,14 +34,22 @@ mod buffer {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
     }
     pub use extension::Extension;
 }
-mod buffer_view {
-    mod extension {
+pub mod buffer_view {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
-        #[derive(Serialize, Deserialize, Debug)]
+        #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
         #[derive(Default)]
         pub enum Filter {
             #[serde(rename = "NONE")]
@@ -46,7 +62,7 @@ mod buffer_view {
             #[serde(rename = "EXPONENTIAL")]
             Exponential,
         }
-        #[derive(Serialize, Deserialize, Debug)]
+        #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
         pub enum Mode {
             #[serde(rename = "ATTRIBUTES")]
             Attributes,
@@ -59,18 +75,21 @@ mod buffer_view {
         ///Compressed data for bufferView.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             ///The index of the buffer with compressed data.
-            pub buffer: i64,
+            pub buffer: crate::Index<crate::generated::gltf::Buffer>,
             #[serde(rename = "byteLength")]
             ///The length of the compressed data in bytes.
             pub byte_length: i64,
             #[serde(rename = "byteOffset")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The offset into the buffer in bytes.
             pub byte_offset: Option<i64>,
             #[serde(rename = "byteStride")]
@@ -78,11 +97,15 @@ mod buffer_view {
             pub byte_stride: i64,
             ///The number of elements.
             pub count: i64,
-            #[serde(default)]
+            #[serde(default = "default_filter")]
+            #[serde(skip_serializing_if = "is_default_filter")]
             ///The compression filter.
-            pub filter: Option<Filter>,
+            pub filter: Filter,
             ///The compression mode.
             pub mode: Mode,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -94,6 +117,21 @@ mod buffer_view {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
+        ///The default value of `filter`.
+        pub fn default_filter() -> Filter {
+            Filter::default()
+        }
+        fn is_default_filter(value: &Filter) -> bool {
+            *value == default_filter()
+        }
     }
     pub use extension::Extension;
 }
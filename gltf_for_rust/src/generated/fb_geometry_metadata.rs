@@ -1,30 +1,39 @@
 #![allow(clippy::all, unused_imports)]
-mod scene {
-    mod extension {
+pub use scene::Extension as SceneExtension;
+pub mod scene {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "primitiveCount")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The number of distinct primitives recursively contained in this scene.
             pub primitive_count: Option<f64>,
             #[serde(rename = "sceneBounds")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The bounding box of this scene, in static geometry scene-space coordinates.
             pub scene_bounds: Option<
                 crate::generated::fb_geometry_metadata::SceneBounds,
             >,
             #[serde(rename = "vertexCount")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The number of distinct vertices recursively contained in this scene.
             pub vertex_count: Option<f64>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -36,29 +45,57 @@ mod scene {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "sceneBounds");
+                    if let Some(value) = &self.scene_bounds {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
     }
     pub use extension::Extension;
 }
-mod scene_bounds {
+pub mod scene_bounds {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     pub struct SceneBounds {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         ///The bounding box corner with the numerically highest scene-space coordinates
         pub max: [f64; 3usize],
         ///The bounding box corner with the numerically lowest scene-space coordinates
         pub min: [f64; 3usize],
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for SceneBounds {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for SceneBounds {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
 }
 pub use scene_bounds::SceneBounds;
@@ -1,116 +1,321 @@
 #![allow(clippy::all, unused_imports)]
-mod accessor {
+pub mod accessor {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(from = "i64", into = "i64")]
+    #[repr(u32)]
+    pub enum ComponentType {
+        Byte = 5120,
+        UnsignedByte = 5121,
+        Short = 5122,
+        UnsignedShort = 5123,
+        UnsignedInt = 5125,
+        Float = 5126,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(i64) = 5127,
+    }
+    impl From<i64> for ComponentType {
+        fn from(value: i64) -> Self {
+            match value {
+                5120 => ComponentType::Byte,
+                5121 => ComponentType::UnsignedByte,
+                5122 => ComponentType::Short,
+                5123 => ComponentType::UnsignedShort,
+                5125 => ComponentType::UnsignedInt,
+                5126 => ComponentType::Float,
+                other => ComponentType::Other(other),
+            }
+        }
+    }
+    impl From<ComponentType> for i64 {
+        fn from(value: ComponentType) -> Self {
+            match value {
+                ComponentType::Byte => 5120,
+                ComponentType::UnsignedByte => 5121,
+                ComponentType::Short => 5122,
+                ComponentType::UnsignedShort => 5123,
+                ComponentType::UnsignedInt => 5125,
+                ComponentType::Float => 5126,
+                ComponentType::Other(other) => other,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    #[serde(from = "String", into = "String")]
     pub enum Type {
-        #[serde(rename = "SCALAR")]
         Scalar,
-        #[serde(rename = "VEC2")]
         Vec2,
-        #[serde(rename = "VEC3")]
         Vec3,
-        #[serde(rename = "VEC4")]
         Vec4,
-        #[serde(rename = "MAT2")]
         Mat2,
-        #[serde(rename = "MAT3")]
         Mat3,
-        #[serde(rename = "MAT4")]
         Mat4,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(String),
+    }
+    impl From<String> for Type {
+        fn from(value: String) -> Self {
+            match value.as_str() {
+                "SCALAR" => Type::Scalar,
+                "VEC2" => Type::Vec2,
+                "VEC3" => Type::Vec3,
+                "VEC4" => Type::Vec4,
+                "MAT2" => Type::Mat2,
+                "MAT3" => Type::Mat3,
+                "MAT4" => Type::Mat4,
+                _ => Type::Other(value),
+            }
+        }
+    }
+    impl From<Type> for String {
+        fn from(value: Type) -> Self {
+            match value {
+                Type::Scalar => String::from("SCALAR"),
+                Type::Vec2 => String::from("VEC2"),
+                Type::Vec3 => String::from("VEC3"),
+                Type::Vec4 => String::from("VEC4"),
+                Type::Mat2 => String::from("MAT2"),
+                Type::Mat3 => String::from("MAT3"),
+                Type::Mat4 => String::from("MAT4"),
+                Type::Other(other) => other,
+            }
+        }
     }
     #[derive(Serialize, Deserialize, Debug)]
     ///A typed view into a buffer view that contains raw binary data.
     pub struct Accessor {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         #[serde(rename = "bufferView")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The index of the buffer view. When undefined, the accessor **MUST** be initialized with zeros; `sparse` property or extensions **MAY** override zeros with actual values.
-        pub buffer_view: Option<i64>,
+        pub buffer_view: Option<crate::Index<crate::generated::gltf::BufferView>>,
         #[serde(rename = "byteOffset")]
-        #[serde(default)]
+        #[serde(default = "default_byte_offset")]
+        #[serde(skip_serializing_if = "is_default_byte_offset")]
         ///The offset relative to the start of the buffer view in bytes.  This **MUST** be a multiple of the size of the component datatype. This property **MUST NOT** be defined when `bufferView` is undefined.
-        pub byte_offset: Option<i64>,
+        pub byte_offset: i64,
         #[serde(rename = "componentType")]
         ///The datatype of the accessor's components.  UNSIGNED_INT type **MUST NOT** be used for any accessor that is not referenced by `mesh.primitive.indices`.
-        pub component_type: i64,
+        pub component_type: ComponentType,
         ///The number of elements referenced by this accessor, not to be confused with the number of bytes or number of components.
         pub count: i64,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         /**Maximum value of each component in this accessor.  Array elements **MUST** be treated as having the same data type as accessor's `componentType`. Both `min` and `max` arrays have the same length.  The length is determined by the value of the `type` property; it can be 1, 2, 3, 4, 9, or 16.
 
 `normalized` property has no effect on array values: they always correspond to the actual values stored in the buffer. When the accessor is sparse, this property **MUST** contain maximum values of accessor data with sparse substitution applied.*/
         pub max: Vec<f64>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         /**Minimum value of each component in this accessor.  Array elements **MUST** be treated as having the same data type as accessor's `componentType`. Both `min` and `max` arrays have the same length.  The length is determined by the value of the `type` property; it can be 1, 2, 3, 4, 9, or 16.
 
 `normalized` property has no effect on array values: they always correspond to the actual values stored in the buffer. When the accessor is sparse, this property **MUST** contain minimum values of accessor data with sparse substitution applied.*/
         pub min: Vec<f64>,
-        #[serde(default)]
+        #[serde(default = "default_normalized")]
+        #[serde(skip_serializing_if = "is_default_normalized")]
         ///Specifies whether integer data values are normalized (`true`) to [0, 1] (for unsigned types) or to [-1, 1] (for signed types) when they are accessed. This property **MUST NOT** be set to `true` for accessors with `FLOAT` or `UNSIGNED_INT` component type.
-        pub normalized: Option<bool>,
+        pub normalized: bool,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Sparse storage of elements that deviate from their initialization value.
         pub sparse: Option<crate::generated::gltf::AccessorSparse>,
         #[serde(rename = "type")]
         ///Specifies if the accessor's elements are scalars, vectors, or matrices.
         pub ty: Type,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Accessor {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Accessor {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            if !is_default_byte_offset(&self.byte_offset) && !self.buffer_view.is_some()
+            {
+                errors
+                    .push(crate::validation::ValidationError {
+                        pointer: format!("{}/{}", pointer, "byteOffset"),
+                        kind: crate::validation::ValidationErrorKind::MissingDependency {
+                            property: String::from("byteOffset"),
+                            dependency: String::from("bufferView"),
+                        },
+                    });
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "byteOffset");
+                let value = &self.byte_offset;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "count");
+                let value = &self.count;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(1.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "sparse");
+                if let Some(value) = &self.sparse {
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+        }
+    }
+    ///The default value of `byteOffset`.
+    pub fn default_byte_offset() -> i64 {
+        0
+    }
+    fn is_default_byte_offset(value: &i64) -> bool {
+        *value == default_byte_offset()
+    }
+    ///The default value of `normalized`.
+    pub fn default_normalized() -> bool {
+        false
+    }
+    fn is_default_normalized(value: &bool) -> bool {
+        *value == default_normalized()
+    }
 }
 pub use accessor::Accessor;
-mod accessor_sparse_indices {
+pub mod accessor_sparse_indices {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(from = "i64", into = "i64")]
+    #[repr(u32)]
+    pub enum ComponentType {
+        UnsignedByte = 5121,
+        UnsignedShort = 5123,
+        UnsignedInt = 5125,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(i64) = 5126,
+    }
+    impl From<i64> for ComponentType {
+        fn from(value: i64) -> Self {
+            match value {
+                5121 => ComponentType::UnsignedByte,
+                5123 => ComponentType::UnsignedShort,
+                5125 => ComponentType::UnsignedInt,
+                other => ComponentType::Other(other),
+            }
+        }
+    }
+    impl From<ComponentType> for i64 {
+        fn from(value: ComponentType) -> Self {
+            match value {
+                ComponentType::UnsignedByte => 5121,
+                ComponentType::UnsignedShort => 5123,
+                ComponentType::UnsignedInt => 5125,
+                ComponentType::Other(other) => other,
+            }
+        }
+    }
     #[derive(Serialize, Deserialize, Debug)]
     ///An object pointing to a buffer view containing the indices of deviating accessor values. The number of indices is equal to `accessor.sparse.count`. Indices **MUST** strictly increase.
     pub struct AccessorSparseIndices {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(rename = "bufferView")]
         ///The index of the buffer view with sparse indices. The referenced buffer view **MUST NOT** have its `target` or `byteStride` properties defined. The buffer view and the optional `byteOffset` **MUST** be aligned to the `componentType` byte length.
-        pub buffer_view: i64,
+        pub buffer_view: crate::Index<crate::generated::gltf::BufferView>,
         #[serde(rename = "byteOffset")]
-        #[serde(default)]
+        #[serde(default = "default_byte_offset")]
+        #[serde(skip_serializing_if = "is_default_byte_offset")]
         ///The offset relative to the start of the buffer view in bytes.
-        pub byte_offset: Option<i64>,
+        pub byte_offset: i64,
         #[serde(rename = "componentType")]
         ///The indices data type.
-        pub component_type: i64,
+        pub component_type: ComponentType,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for AccessorSparseIndices {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for AccessorSparseIndices {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "byteOffset");
+                let value = &self.byte_offset;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+        }
+    }
+    ///The default value of `byteOffset`.
+    pub fn default_byte_offset() -> i64 {
+        0
+    }
+    fn is_default_byte_offset(value: &i64) -> bool {
+        *value == default_byte_offset()
+    }
 }
 pub use accessor_sparse_indices::AccessorSparseIndices;
-mod accessor_sparse {
+pub mod accessor_sparse {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///Sparse storage of accessor values that deviate from their initialization value.
     pub struct AccessorSparse {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         ///Number of deviating accessor values stored in the sparse array.
@@ -119,276 +324,637 @@ mod accessor_sparse {
         pub indices: crate::generated::gltf::AccessorSparseIndices,
         ///An object pointing to a buffer view containing the deviating accessor values.
         pub values: crate::generated::gltf::AccessorSparseValues,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for AccessorSparse {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for AccessorSparse {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "count");
+                let value = &self.count;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(1.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "indices");
+                let value = &self.indices;
+                crate::validation::Validate::validate_at(value, pointer, errors);
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "values");
+                let value = &self.values;
+                crate::validation::Validate::validate_at(value, pointer, errors);
+            }
+        }
+    }
 }
 pub use accessor_sparse::AccessorSparse;
-mod accessor_sparse_values {
+pub mod accessor_sparse_values {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///An object pointing to a buffer view containing the deviating accessor values. The number of elements is equal to `accessor.sparse.count` times number of components. The elements have the same component type as the base accessor. The elements are tightly packed. Data **MUST** be aligned following the same rules as the base accessor.
     pub struct AccessorSparseValues {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(rename = "bufferView")]
         ///The index of the bufferView with sparse values. The referenced buffer view **MUST NOT** have its `target` or `byteStride` properties defined.
-        pub buffer_view: i64,
+        pub buffer_view: crate::Index<crate::generated::gltf::BufferView>,
         #[serde(rename = "byteOffset")]
-        #[serde(default)]
+        #[serde(default = "default_byte_offset")]
+        #[serde(skip_serializing_if = "is_default_byte_offset")]
         ///The offset relative to the start of the bufferView in bytes.
-        pub byte_offset: Option<i64>,
+        pub byte_offset: i64,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for AccessorSparseValues {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for AccessorSparseValues {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "byteOffset");
+                let value = &self.byte_offset;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+        }
+    }
+    ///The default value of `byteOffset`.
+    pub fn default_byte_offset() -> i64 {
+        0
+    }
+    fn is_default_byte_offset(value: &i64) -> bool {
+        *value == default_byte_offset()
+    }
 }
 pub use accessor_sparse_values::AccessorSparseValues;
-mod animation_channel {
+pub mod animation_channel {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///An animation channel combines an animation sampler with a target property being animated.
     pub struct AnimationChannel {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         ///The index of a sampler in this animation used to compute the value for the target, e.g., a node's translation, rotation, or scale (TRS).
-        pub sampler: i64,
+        pub sampler: crate::Index<crate::generated::gltf::AnimationSampler>,
         ///The descriptor of the animated property.
         pub target: crate::generated::gltf::AnimationChannelTarget,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for AnimationChannel {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for AnimationChannel {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "target");
+                let value = &self.target;
+                crate::validation::Validate::validate_at(value, pointer, errors);
+            }
+        }
+    }
 }
 pub use animation_channel::AnimationChannel;
-mod animation_channel_target {
+pub mod animation_channel_target {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    #[serde(from = "String", into = "String")]
     pub enum Path {
-        #[serde(rename = "translation")]
         Translation,
-        #[serde(rename = "rotation")]
         Rotation,
-        #[serde(rename = "scale")]
         Scale,
-        #[serde(rename = "weights")]
         Weights,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(String),
+    }
+    impl From<String> for Path {
+        fn from(value: String) -> Self {
+            match value.as_str() {
+                "translation" => Path::Translation,
+                "rotation" => Path::Rotation,
+                "scale" => Path::Scale,
+                "weights" => Path::Weights,
+                _ => Path::Other(value),
+            }
+        }
+    }
+    impl From<Path> for String {
+        fn from(value: Path) -> Self {
+            match value {
+                Path::Translation => String::from("translation"),
+                Path::Rotation => String::from("rotation"),
+                Path::Scale => String::from("scale"),
+                Path::Weights => String::from("weights"),
+                Path::Other(other) => other,
+            }
+        }
     }
     #[derive(Serialize, Deserialize, Debug)]
     ///The descriptor of the animated property.
     pub struct AnimationChannelTarget {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The index of the node to animate. When undefined, the animated object **MAY** be defined by an extension.
-        pub node: Option<i64>,
+        pub node: Option<crate::Index<crate::generated::gltf::Node>>,
         ///The name of the node's TRS property to animate, or the `"weights"` of the Morph Targets it instantiates. For the `"translation"` property, the values that are provided by the sampler are the translation along the X, Y, and Z axes. For the `"rotation"` property, the values are a quaternion in the order (x, y, z, w), where w is the scalar. For the `"scale"` property, the values are the scaling factors along the X, Y, and Z axes.
         pub path: Path,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for AnimationChannelTarget {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for AnimationChannelTarget {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
 }
 pub use animation_channel_target::AnimationChannelTarget;
-mod animation_sampler {
+pub mod animation_sampler {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
     #[derive(Default)]
+    #[serde(from = "String", into = "String")]
     pub enum Interpolation {
-        #[serde(rename = "LINEAR")]
         #[default]
         Linear,
-        #[serde(rename = "STEP")]
         Step,
-        #[serde(rename = "CUBICSPLINE")]
         Cubicspline,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(String),
+    }
+    impl From<String> for Interpolation {
+        fn from(value: String) -> Self {
+            match value.as_str() {
+                "LINEAR" => Interpolation::Linear,
+                "STEP" => Interpolation::Step,
+                "CUBICSPLINE" => Interpolation::Cubicspline,
+                _ => Interpolation::Other(value),
+            }
+        }
+    }
+    impl From<Interpolation> for String {
+        fn from(value: Interpolation) -> Self {
+            match value {
+                Interpolation::Linear => String::from("LINEAR"),
+                Interpolation::Step => String::from("STEP"),
+                Interpolation::Cubicspline => String::from("CUBICSPLINE"),
+                Interpolation::Other(other) => other,
+            }
+        }
     }
     #[derive(Serialize, Deserialize, Debug)]
     ///An animation sampler combines timestamps with a sequence of output values and defines an interpolation algorithm.
     pub struct AnimationSampler {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         ///The index of an accessor containing keyframe timestamps. The accessor **MUST** be of scalar type with floating-point components. The values represent time in seconds with `time[0] >= 0.0`, and strictly increasing values, i.e., `time[n + 1] > time[n]`.
-        pub input: i64,
-        #[serde(default)]
+        pub input: crate::Index<crate::generated::gltf::Accessor>,
+        #[serde(default = "default_interpolation")]
+        #[serde(skip_serializing_if = "is_default_interpolation")]
         ///Interpolation algorithm.
-        pub interpolation: Option<Interpolation>,
+        pub interpolation: Interpolation,
         ///The index of an accessor, containing keyframe output values.
-        pub output: i64,
+        pub output: crate::Index<crate::generated::gltf::Accessor>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for AnimationSampler {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for AnimationSampler {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
+    ///The default value of `interpolation`.
+    pub fn default_interpolation() -> Interpolation {
+        Interpolation::default()
+    }
+    fn is_default_interpolation(value: &Interpolation) -> bool {
+        *value == default_interpolation()
+    }
 }
 pub use animation_sampler::AnimationSampler;
-mod animation {
+pub mod animation {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///A keyframe animation.
     pub struct Animation {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         ///An array of animation channels. An animation channel combines an animation sampler with a target property being animated. Different channels of the same animation **MUST NOT** have the same targets.
         pub channels: Vec<crate::generated::gltf::AnimationChannel>,
         ///An array of animation samplers. An animation sampler combines timestamps with a sequence of output values and defines an interpolation algorithm.
         pub samplers: Vec<crate::generated::gltf::AnimationSampler>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Animation {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Animation {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "channels");
+                let value = &self.channels;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "samplers");
+                let value = &self.samplers;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+        }
+    }
+    impl crate::Get<crate::generated::gltf::AnimationSampler> for Animation {
+        fn get(
+            &self,
+            index: crate::Index<crate::generated::gltf::AnimationSampler>,
+        ) -> Option<&crate::generated::gltf::AnimationSampler> {
+            self.samplers.get(index.value())
+        }
+    }
 }
 pub use animation::Animation;
-mod asset {
+pub mod asset {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///Metadata about the glTF asset.
     pub struct Asset {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///A copyright message suitable for display to credit the content creator.
         pub copyright: Option<String>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Tool that generated this glTF model.  Useful for debugging.
         pub generator: Option<String>,
         #[serde(rename = "minVersion")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The minimum glTF version in the form of `<major>.<minor>` that this asset targets. This property **MUST NOT** be greater than the asset version.
         pub min_version: Option<String>,
         ///The glTF version in the form of `<major>.<minor>` that this asset targets.
         pub version: String,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Asset {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Asset {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "minVersion");
+                if let Some(value) = &self.min_version {
+                    crate::validation::check_string(
+                        pointer,
+                        value,
+                        None,
+                        Some("^[0-9]+\\.[0-9]+$"),
+                        errors,
+                    );
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "version");
+                let value = &self.version;
+                crate::validation::check_string(
+                    pointer,
+                    value,
+                    None,
+                    Some("^[0-9]+\\.[0-9]+$"),
+                    errors,
+                );
+            }
+        }
+    }
 }
 pub use asset::Asset;
-mod buffer {
+pub mod buffer {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///A buffer points to binary geometry, animation, or skins.
     pub struct Buffer {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         #[serde(rename = "byteLength")]
         ///The length of the buffer in bytes.
         pub byte_length: i64,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The URI (or IRI) of the buffer.  Relative paths are relative to the current glTF asset.  Instead of referencing an external file, this field **MAY** contain a `data:`-URI.
         pub uri: Option<String>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Buffer {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Buffer {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "byteLength");
+                let value = &self.byte_length;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(1.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+        }
+    }
 }
 pub use buffer::Buffer;
-mod buffer_view {
+pub mod buffer_view {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(from = "i64", into = "i64")]
+    #[repr(u32)]
+    pub enum Target {
+        ArrayBuffer = 34962,
+        ElementArrayBuffer = 34963,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(i64) = 34964,
+    }
+    impl From<i64> for Target {
+        fn from(value: i64) -> Self {
+            match value {
+                34962 => Target::ArrayBuffer,
+                34963 => Target::ElementArrayBuffer,
+                other => Target::Other(other),
+            }
+        }
+    }
+    impl From<Target> for i64 {
+        fn from(value: Target) -> Self {
+            match value {
+                Target::ArrayBuffer => 34962,
+                Target::ElementArrayBuffer => 34963,
+                Target::Other(other) => other,
+            }
+        }
+    }
     #[derive(Serialize, Deserialize, Debug)]
     ///A view into a buffer generally representing a subset of the buffer.
     pub struct BufferView {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         ///The index of the buffer.
-        pub buffer: i64,
+        pub buffer: crate::Index<crate::generated::gltf::Buffer>,
         #[serde(rename = "byteLength")]
         ///The length of the bufferView in bytes.
         pub byte_length: i64,
         #[serde(rename = "byteOffset")]
-        #[serde(default)]
+        #[serde(default = "default_byte_offset")]
+        #[serde(skip_serializing_if = "is_default_byte_offset")]
         ///The offset into the buffer in bytes.
-        pub byte_offset: Option<i64>,
+        pub byte_offset: i64,
         #[serde(rename = "byteStride")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The stride, in bytes, between vertex attributes.  When this is not defined, data is tightly packed. When two or more accessors use the same buffer view, this field **MUST** be defined.
         pub byte_stride: Option<i64>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The hint representing the intended GPU buffer type to use with this buffer view.
-        pub target: Option<i64>,
+        pub target: Option<Target>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for BufferView {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for BufferView {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "byteLength");
+                let value = &self.byte_length;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(1.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "byteOffset");
+                let value = &self.byte_offset;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "byteStride");
+                if let Some(value) = &self.byte_stride {
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        Some(4.0),
+                        None,
+                        Some(252.0),
+                        errors,
+                    );
+                }
+            }
+        }
+    }
+    ///The default value of `byteOffset`.
+    pub fn default_byte_offset() -> i64 {
+        0
+    }
+    fn is_default_byte_offset(value: &i64) -> bool {
+        *value == default_byte_offset()
+    }
 }
 pub use buffer_view::BufferView;
-mod camera_orthographic {
+pub mod camera_orthographic {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///An orthographic camera containing properties to create an orthographic projection matrix.
     pub struct CameraOrthographic {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         ///The floating-point horizontal magnification of the view. This value **MUST NOT** be equal to zero. This value **SHOULD NOT** be negative.
@@ -399,625 +965,1848 @@ mod camera_orthographic {
         pub zfar: f64,
         ///The floating-point distance to the near clipping plane.
         pub znear: f64,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for CameraOrthographic {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for CameraOrthographic {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "zfar");
+                let value = &self.zfar;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    None,
+                    Some(0.0),
+                    None,
+                    errors,
+                );
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "znear");
+                let value = &self.znear;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+        }
+    }
 }
 pub use camera_orthographic::CameraOrthographic;
-mod camera_perspective {
+pub mod camera_perspective {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///A perspective camera containing properties to create a perspective projection matrix.
     pub struct CameraPerspective {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(rename = "aspectRatio")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The floating-point aspect ratio of the field of view. When undefined, the aspect ratio of the rendering viewport **MUST** be used.
         pub aspect_ratio: Option<f64>,
         ///The floating-point vertical field of view in radians. This value **SHOULD** be less than π.
         pub yfov: f64,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The floating-point distance to the far clipping plane. When defined, `zfar` **MUST** be greater than `znear`. If `zfar` is undefined, client implementations **SHOULD** use infinite projection matrix.
         pub zfar: Option<f64>,
         ///The floating-point distance to the near clipping plane.
         pub znear: f64,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for CameraPerspective {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for CameraPerspective {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "aspectRatio");
+                if let Some(value) = &self.aspect_ratio {
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        None,
+                        Some(0.0),
+                        None,
+                        errors,
+                    );
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "yfov");
+                let value = &self.yfov;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    None,
+                    Some(0.0),
+                    None,
+                    errors,
+                );
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "zfar");
+                if let Some(value) = &self.zfar {
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        None,
+                        Some(0.0),
+                        None,
+                        errors,
+                    );
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "znear");
+                let value = &self.znear;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    None,
+                    Some(0.0),
+                    None,
+                    errors,
+                );
+            }
+        }
+    }
 }
 pub use camera_perspective::CameraPerspective;
-mod camera {
+pub mod camera {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    #[serde(from = "String", into = "String")]
     pub enum Type {
-        #[serde(rename = "perspective")]
         Perspective,
-        #[serde(rename = "orthographic")]
         Orthographic,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(String),
+    }
+    impl From<String> for Type {
+        fn from(value: String) -> Self {
+            match value.as_str() {
+                "perspective" => Type::Perspective,
+                "orthographic" => Type::Orthographic,
+                _ => Type::Other(value),
+            }
+        }
+    }
+    impl From<Type> for String {
+        fn from(value: Type) -> Self {
+            match value {
+                Type::Perspective => String::from("perspective"),
+                Type::Orthographic => String::from("orthographic"),
+                Type::Other(other) => other,
+            }
+        }
     }
     #[derive(Serialize, Deserialize, Debug)]
     ///A camera's projection.  A node **MAY** reference a camera to apply a transform to place the camera in the scene.
     pub struct Camera {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///An orthographic camera containing properties to create an orthographic projection matrix. This property **MUST NOT** be defined when `perspective` is defined.
         pub orthographic: Option<crate::generated::gltf::CameraOrthographic>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///A perspective camera containing properties to create a perspective projection matrix. This property **MUST NOT** be defined when `orthographic` is defined.
         pub perspective: Option<crate::generated::gltf::CameraPerspective>,
         #[serde(rename = "type")]
         ///Specifies if the camera uses a perspective or orthographic projection.  Based on this, either the camera's `perspective` or `orthographic` property **MUST** be defined.
         pub ty: Type,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Camera {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Camera {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            if self.perspective.is_some() && self.orthographic.is_some() {
+                errors
+                    .push(crate::validation::ValidationError {
+                        pointer: pointer.to_string(),
+                        kind: crate::validation::ValidationErrorKind::ForbiddenCombination,
+                    });
+            }
+            let matches = [
+                self.perspective.is_some() && self.ty == Type::Perspective,
+                self.orthographic.is_some() && self.ty == Type::Orthographic,
+            ]
+                .into_iter()
+                .filter(|matches| *matches)
+                .count();
+            if matches != 1 {
+                errors
+                    .push(crate::validation::ValidationError {
+                        pointer: pointer.to_string(),
+                        kind: crate::validation::ValidationErrorKind::OneOfMismatch(
+                            matches,
+                        ),
+                    });
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "orthographic");
+                if let Some(value) = &self.orthographic {
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "perspective");
+                if let Some(value) = &self.perspective {
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+        }
+    }
 }
 pub use camera::Camera;
-mod gltf {
+pub mod gltf {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///The root object for a glTF asset.
     pub struct Gltf {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of accessors.  An accessor is a typed view into a bufferView.
         pub accessors: Vec<crate::generated::gltf::Accessor>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of keyframe animations.
         pub animations: Vec<crate::generated::gltf::Animation>,
         ///Metadata about the glTF asset.
         pub asset: crate::generated::gltf::Asset,
         #[serde(rename = "bufferViews")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of bufferViews.  A bufferView is a view into a buffer generally representing a subset of the buffer.
         pub buffer_views: Vec<crate::generated::gltf::BufferView>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of buffers.  A buffer points to binary geometry, animation, or skins.
         pub buffers: Vec<crate::generated::gltf::Buffer>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of cameras.  A camera defines a projection matrix.
         pub cameras: Vec<crate::generated::gltf::Camera>,
         #[serde(rename = "extensionsRequired")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///Names of glTF extensions required to properly load this asset.
         pub extensions_required: Vec<String>,
         #[serde(rename = "extensionsUsed")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///Names of glTF extensions used in this asset.
         pub extensions_used: Vec<String>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of images.  An image defines data used to create a texture.
         pub images: Vec<crate::generated::gltf::Image>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of materials.  A material defines the appearance of a primitive.
         pub materials: Vec<crate::generated::gltf::Material>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of meshes.  A mesh is a set of primitives to be rendered.
         pub meshes: Vec<crate::generated::gltf::Mesh>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of nodes.
         pub nodes: Vec<crate::generated::gltf::Node>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of samplers.  A sampler contains properties for texture filtering and wrapping modes.
         pub samplers: Vec<crate::generated::gltf::Sampler>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The index of the default scene.  This property **MUST NOT** be defined, when `scenes` is undefined.
-        pub scene: Option<i64>,
+        pub scene: Option<crate::Index<crate::generated::gltf::Scene>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of scenes.
         pub scenes: Vec<crate::generated::gltf::Scene>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of skins.  A skin is defined by joints and matrices.
         pub skins: Vec<crate::generated::gltf::Skin>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of textures.
         pub textures: Vec<crate::generated::gltf::Texture>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Gltf {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Gltf {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            if self.scene.is_some() && !!self.scenes.is_empty() {
+                errors
+                    .push(crate::validation::ValidationError {
+                        pointer: format!("{}/{}", pointer, "scene"),
+                        kind: crate::validation::ValidationErrorKind::MissingDependency {
+                            property: String::from("scene"),
+                            dependency: String::from("scenes"),
+                        },
+                    });
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "accessors");
+                let value = &self.accessors;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "animations");
+                let value = &self.animations;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "asset");
+                let value = &self.asset;
+                crate::validation::Validate::validate_at(value, pointer, errors);
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "bufferViews");
+                let value = &self.buffer_views;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "buffers");
+                let value = &self.buffers;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "cameras");
+                let value = &self.cameras;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "images");
+                let value = &self.images;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "materials");
+                let value = &self.materials;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "meshes");
+                let value = &self.meshes;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "nodes");
+                let value = &self.nodes;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "samplers");
+                let value = &self.samplers;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "scenes");
+                let value = &self.scenes;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "skins");
+                let value = &self.skins;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "textures");
+                let value = &self.textures;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+        }
+    }
+    impl crate::Get<crate::generated::gltf::Accessor> for Gltf {
+        fn get(
+            &self,
+            index: crate::Index<crate::generated::gltf::Accessor>,
+        ) -> Option<&crate::generated::gltf::Accessor> {
+            self.accessors.get(index.value())
+        }
+    }
+    impl crate::Get<crate::generated::gltf::BufferView> for Gltf {
+        fn get(
+            &self,
+            index: crate::Index<crate::generated::gltf::BufferView>,
+        ) -> Option<&crate::generated::gltf::BufferView> {
+            self.buffer_views.get(index.value())
+        }
+    }
+    impl crate::Get<crate::generated::gltf::Buffer> for Gltf {
+        fn get(
+            &self,
+            index: crate::Index<crate::generated::gltf::Buffer>,
+        ) -> Option<&crate::generated::gltf::Buffer> {
+            self.buffers.get(index.value())
+        }
+    }
+    impl crate::Get<crate::generated::gltf::Camera> for Gltf {
+        fn get(
+            &self,
+            index: crate::Index<crate::generated::gltf::Camera>,
+        ) -> Option<&crate::generated::gltf::Camera> {
+            self.cameras.get(index.value())
+        }
+    }
+    impl crate::Get<crate::generated::gltf::Image> for Gltf {
+        fn get(
+            &self,
+            index: crate::Index<crate::generated::gltf::Image>,
+        ) -> Option<&crate::generated::gltf::Image> {
+            self.images.get(index.value())
+        }
+    }
+    impl crate::Get<crate::generated::gltf::Material> for Gltf {
+        fn get(
+            &self,
+            index: crate::Index<crate::generated::gltf::Material>,
+        ) -> Option<&crate::generated::gltf::Material> {
+            self.materials.get(index.value())
+        }
+    }
+    impl crate::Get<crate::generated::gltf::Mesh> for Gltf {
+        fn get(
+            &self,
+            index: crate::Index<crate::generated::gltf::Mesh>,
+        ) -> Option<&crate::generated::gltf::Mesh> {
+            self.meshes.get(index.value())
+        }
+    }
+    impl crate::Get<crate::generated::gltf::Node> for Gltf {
+        fn get(
+            &self,
+            index: crate::Index<crate::generated::gltf::Node>,
+        ) -> Option<&crate::generated::gltf::Node> {
+            self.nodes.get(index.value())
+        }
+    }
+    impl crate::Get<crate::generated::gltf::Sampler> for Gltf {
+        fn get(
+            &self,
+            index: crate::Index<crate::generated::gltf::Sampler>,
+        ) -> Option<&crate::generated::gltf::Sampler> {
+            self.samplers.get(index.value())
+        }
+    }
+    impl crate::Get<crate::generated::gltf::Scene> for Gltf {
+        fn get(
+            &self,
+            index: crate::Index<crate::generated::gltf::Scene>,
+        ) -> Option<&crate::generated::gltf::Scene> {
+            self.scenes.get(index.value())
+        }
+    }
+    impl crate::Get<crate::generated::gltf::Skin> for Gltf {
+        fn get(
+            &self,
+            index: crate::Index<crate::generated::gltf::Skin>,
+        ) -> Option<&crate::generated::gltf::Skin> {
+            self.skins.get(index.value())
+        }
+    }
+    impl crate::Get<crate::generated::gltf::Texture> for Gltf {
+        fn get(
+            &self,
+            index: crate::Index<crate::generated::gltf::Texture>,
+        ) -> Option<&crate::generated::gltf::Texture> {
+            self.textures.get(index.value())
+        }
+    }
 }
 pub use gltf::Gltf;
-mod image {
+pub mod image {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    #[serde(from = "String", into = "String")]
     pub enum MimeType {
-        #[serde(rename = "image/jpeg")]
         ImageJpeg,
-        #[serde(rename = "image/png")]
         ImagePng,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(String),
+    }
+    impl From<String> for MimeType {
+        fn from(value: String) -> Self {
+            match value.as_str() {
+                "image/jpeg" => MimeType::ImageJpeg,
+                "image/png" => MimeType::ImagePng,
+                _ => MimeType::Other(value),
+            }
+        }
+    }
+    impl From<MimeType> for String {
+        fn from(value: MimeType) -> Self {
+            match value {
+                MimeType::ImageJpeg => String::from("image/jpeg"),
+                MimeType::ImagePng => String::from("image/png"),
+                MimeType::Other(other) => other,
+            }
+        }
     }
     #[derive(Serialize, Deserialize, Debug)]
     ///Image data used to create a texture. Image **MAY** be referenced by an URI (or IRI) or a buffer view index.
     pub struct Image {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         #[serde(rename = "bufferView")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The index of the bufferView that contains the image. This field **MUST NOT** be defined when `uri` is defined.
-        pub buffer_view: Option<i64>,
+        pub buffer_view: Option<crate::Index<crate::generated::gltf::BufferView>>,
         #[serde(rename = "mimeType")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The image's media type. This field **MUST** be defined when `bufferView` is defined.
         pub mime_type: Option<MimeType>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The URI (or IRI) of the image.  Relative paths are relative to the current glTF asset.  Instead of referencing an external file, this field **MAY** contain a `data:`-URI. This field **MUST NOT** be defined when `bufferView` is defined.
         pub uri: Option<String>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Image {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Image {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            if self.buffer_view.is_some() && !self.mime_type.is_some() {
+                errors
+                    .push(crate::validation::ValidationError {
+                        pointer: format!("{}/{}", pointer, "bufferView"),
+                        kind: crate::validation::ValidationErrorKind::MissingDependency {
+                            property: String::from("bufferView"),
+                            dependency: String::from("mimeType"),
+                        },
+                    });
+            }
+            let matches = [self.uri.is_some(), self.buffer_view.is_some()]
+                .into_iter()
+                .filter(|matches| *matches)
+                .count();
+            if matches != 1 {
+                errors
+                    .push(crate::validation::ValidationError {
+                        pointer: pointer.to_string(),
+                        kind: crate::validation::ValidationErrorKind::OneOfMismatch(
+                            matches,
+                        ),
+                    });
+            }
+        }
+    }
 }
 pub use image::Image;
-mod material_normal_texture_info {
+pub mod material_normal_texture_info {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     pub struct MaterialNormalTextureInfo {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         ///The index of the texture.
-        pub index: i64,
+        pub index: crate::Index<crate::generated::gltf::Texture>,
         #[serde(rename = "texCoord")]
-        #[serde(default)]
+        #[serde(default = "default_tex_coord")]
+        #[serde(skip_serializing_if = "is_default_tex_coord")]
         ///This integer value is used to construct a string in the format `TEXCOORD_<set index>` which is a reference to a key in `mesh.primitives.attributes` (e.g. a value of `0` corresponds to `TEXCOORD_0`). A mesh primitive **MUST** have the corresponding texture coordinate attributes for the material to be applicable to it.
-        pub tex_coord: Option<i64>,
-        #[serde(default)]
+        pub tex_coord: i64,
+        #[serde(default = "default_scale")]
+        #[serde(skip_serializing_if = "is_default_scale")]
         ///The scalar parameter applied to each normal vector of the texture. This value scales the normal vector in X and Y directions using the formula: `scaledNormal =  normalize((<sampled normal texture value> * 2.0 - 1.0) * vec3(<normal scale>, <normal scale>, 1.0))`.
-        pub scale: Option<f64>,
+        pub scale: f64,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for MaterialNormalTextureInfo {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for MaterialNormalTextureInfo {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "texCoord");
+                let value = &self.tex_coord;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+        }
+    }
+    ///The default value of `texCoord`.
+    pub fn default_tex_coord() -> i64 {
+        0
+    }
+    fn is_default_tex_coord(value: &i64) -> bool {
+        *value == default_tex_coord()
+    }
+    ///The default value of `scale`.
+    pub fn default_scale() -> f64 {
+        1.0
+    }
+    fn is_default_scale(value: &f64) -> bool {
+        *value == default_scale()
+    }
 }
 pub use material_normal_texture_info::MaterialNormalTextureInfo;
-mod material_occlusion_texture_info {
+pub mod material_occlusion_texture_info {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     pub struct MaterialOcclusionTextureInfo {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         ///The index of the texture.
-        pub index: i64,
+        pub index: crate::Index<crate::generated::gltf::Texture>,
         #[serde(rename = "texCoord")]
-        #[serde(default)]
+        #[serde(default = "default_tex_coord")]
+        #[serde(skip_serializing_if = "is_default_tex_coord")]
         ///This integer value is used to construct a string in the format `TEXCOORD_<set index>` which is a reference to a key in `mesh.primitives.attributes` (e.g. a value of `0` corresponds to `TEXCOORD_0`). A mesh primitive **MUST** have the corresponding texture coordinate attributes for the material to be applicable to it.
-        pub tex_coord: Option<i64>,
-        #[serde(default)]
+        pub tex_coord: i64,
+        #[serde(default = "default_strength")]
+        #[serde(skip_serializing_if = "is_default_strength")]
         ///A scalar parameter controlling the amount of occlusion applied. A value of `0.0` means no occlusion. A value of `1.0` means full occlusion. This value affects the final occlusion value as: `1.0 + strength * (<sampled occlusion texture value> - 1.0)`.
-        pub strength: Option<f64>,
+        pub strength: f64,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for MaterialOcclusionTextureInfo {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for MaterialOcclusionTextureInfo {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "texCoord");
+                let value = &self.tex_coord;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "strength");
+                let value = &self.strength;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    Some(1.0),
+                    errors,
+                );
+            }
+        }
+    }
+    ///The default value of `texCoord`.
+    pub fn default_tex_coord() -> i64 {
+        0
+    }
+    fn is_default_tex_coord(value: &i64) -> bool {
+        *value == default_tex_coord()
+    }
+    ///The default value of `strength`.
+    pub fn default_strength() -> f64 {
+        1.0
+    }
+    fn is_default_strength(value: &f64) -> bool {
+        *value == default_strength()
+    }
 }
 pub use material_occlusion_texture_info::MaterialOcclusionTextureInfo;
-mod material_pbr_metallic_roughness {
+pub mod material_pbr_metallic_roughness {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///A set of parameter values that are used to define the metallic-roughness material model from Physically-Based Rendering (PBR) methodology.
     pub struct MaterialPbrMetallicRoughness {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(rename = "baseColorFactor")]
-        #[serde(default)]
+        #[serde(default = "default_base_color_factor")]
+        #[serde(skip_serializing_if = "is_default_base_color_factor")]
         ///The factors for the base color of the material. This value defines linear multipliers for the sampled texels of the base color texture.
-        pub base_color_factor: Option<[f64; 4usize]>,
+        pub base_color_factor: [f64; 4usize],
         #[serde(rename = "baseColorTexture")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The base color texture. The first three components (RGB) **MUST** be encoded with the sRGB transfer function. They specify the base color of the material. If the fourth component (A) is present, it represents the linear alpha coverage of the material. Otherwise, the alpha coverage is equal to `1.0`. The `material.alphaMode` property specifies how alpha is interpreted. The stored texels **MUST NOT** be premultiplied. When undefined, the texture **MUST** be sampled as having `1.0` in all components.
         pub base_color_texture: Option<crate::generated::gltf::TextureInfo>,
         #[serde(rename = "metallicFactor")]
-        #[serde(default)]
+        #[serde(default = "default_metallic_factor")]
+        #[serde(skip_serializing_if = "is_default_metallic_factor")]
         ///The factor for the metalness of the material. This value defines a linear multiplier for the sampled metalness values of the metallic-roughness texture.
-        pub metallic_factor: Option<f64>,
+        pub metallic_factor: f64,
         #[serde(rename = "metallicRoughnessTexture")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The metallic-roughness texture. The metalness values are sampled from the B channel. The roughness values are sampled from the G channel. These values **MUST** be encoded with a linear transfer function. If other channels are present (R or A), they **MUST** be ignored for metallic-roughness calculations. When undefined, the texture **MUST** be sampled as having `1.0` in G and B components.
         pub metallic_roughness_texture: Option<crate::generated::gltf::TextureInfo>,
         #[serde(rename = "roughnessFactor")]
-        #[serde(default)]
+        #[serde(default = "default_roughness_factor")]
+        #[serde(skip_serializing_if = "is_default_roughness_factor")]
         ///The factor for the roughness of the material. This value defines a linear multiplier for the sampled roughness values of the metallic-roughness texture.
-        pub roughness_factor: Option<f64>,
+        pub roughness_factor: f64,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for MaterialPbrMetallicRoughness {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for MaterialPbrMetallicRoughness {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "baseColorFactor");
+                let value = &self.base_color_factor;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        Some(0.0),
+                        None,
+                        Some(1.0),
+                        errors,
+                    );
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "baseColorTexture");
+                if let Some(value) = &self.base_color_texture {
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "metallicFactor");
+                let value = &self.metallic_factor;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    Some(1.0),
+                    errors,
+                );
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "metallicRoughnessTexture");
+                if let Some(value) = &self.metallic_roughness_texture {
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "roughnessFactor");
+                let value = &self.roughness_factor;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    Some(1.0),
+                    errors,
+                );
+            }
+        }
+    }
+    ///The default value of `baseColorFactor`.
+    pub fn default_base_color_factor() -> [f64; 4usize] {
+        [1.0, 1.0, 1.0, 1.0]
+    }
+    fn is_default_base_color_factor(value: &[f64; 4usize]) -> bool {
+        *value == default_base_color_factor()
+    }
+    ///The default value of `metallicFactor`.
+    pub fn default_metallic_factor() -> f64 {
+        1.0
+    }
+    fn is_default_metallic_factor(value: &f64) -> bool {
+        *value == default_metallic_factor()
+    }
+    ///The default value of `roughnessFactor`.
+    pub fn default_roughness_factor() -> f64 {
+        1.0
+    }
+    fn is_default_roughness_factor(value: &f64) -> bool {
+        *value == default_roughness_factor()
+    }
 }
 pub use material_pbr_metallic_roughness::MaterialPbrMetallicRoughness;
-mod material {
+pub mod material {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
     #[derive(Default)]
+    #[serde(from = "String", into = "String")]
     pub enum AlphaMode {
-        #[serde(rename = "OPAQUE")]
         #[default]
         Opaque,
-        #[serde(rename = "MASK")]
         Mask,
-        #[serde(rename = "BLEND")]
         Blend,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(String),
+    }
+    impl From<String> for AlphaMode {
+        fn from(value: String) -> Self {
+            match value.as_str() {
+                "OPAQUE" => AlphaMode::Opaque,
+                "MASK" => AlphaMode::Mask,
+                "BLEND" => AlphaMode::Blend,
+                _ => AlphaMode::Other(value),
+            }
+        }
+    }
+    impl From<AlphaMode> for String {
+        fn from(value: AlphaMode) -> Self {
+            match value {
+                AlphaMode::Opaque => String::from("OPAQUE"),
+                AlphaMode::Mask => String::from("MASK"),
+                AlphaMode::Blend => String::from("BLEND"),
+                AlphaMode::Other(other) => other,
+            }
+        }
     }
     #[derive(Serialize, Deserialize, Debug)]
     ///The material appearance of a primitive.
     pub struct Material {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         #[serde(rename = "alphaCutoff")]
-        #[serde(default)]
+        #[serde(default = "default_alpha_cutoff")]
+        #[serde(skip_serializing_if = "is_default_alpha_cutoff")]
         ///Specifies the cutoff threshold when in `MASK` alpha mode. If the alpha value is greater than or equal to this value then it is rendered as fully opaque, otherwise, it is rendered as fully transparent. A value greater than `1.0` will render the entire material as fully transparent. This value **MUST** be ignored for other alpha modes. When `alphaMode` is not defined, this value **MUST NOT** be defined.
-        pub alpha_cutoff: Option<f64>,
+        pub alpha_cutoff: f64,
         #[serde(rename = "alphaMode")]
-        #[serde(default)]
+        #[serde(default = "default_alpha_mode")]
+        #[serde(skip_serializing_if = "is_default_alpha_mode")]
         ///The material's alpha rendering mode enumeration specifying the interpretation of the alpha value of the base color.
-        pub alpha_mode: Option<AlphaMode>,
+        pub alpha_mode: AlphaMode,
         #[serde(rename = "doubleSided")]
-        #[serde(default)]
+        #[serde(default = "default_double_sided")]
+        #[serde(skip_serializing_if = "is_default_double_sided")]
         ///Specifies whether the material is double sided. When this value is false, back-face culling is enabled. When this value is true, back-face culling is disabled and double-sided lighting is enabled. The back-face **MUST** have its normals reversed before the lighting equation is evaluated.
-        pub double_sided: Option<bool>,
+        pub double_sided: bool,
         #[serde(rename = "emissiveFactor")]
-        #[serde(default)]
+        #[serde(default = "default_emissive_factor")]
+        #[serde(skip_serializing_if = "is_default_emissive_factor")]
         ///The factors for the emissive color of the material. This value defines linear multipliers for the sampled texels of the emissive texture.
-        pub emissive_factor: Option<[f64; 3usize]>,
+        pub emissive_factor: [f64; 3usize],
         #[serde(rename = "emissiveTexture")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The emissive texture. It controls the color and intensity of the light being emitted by the material. This texture contains RGB components encoded with the sRGB transfer function. If a fourth component (A) is present, it **MUST** be ignored. When undefined, the texture **MUST** be sampled as having `1.0` in RGB components.
         pub emissive_texture: Option<crate::generated::gltf::TextureInfo>,
         #[serde(rename = "normalTexture")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The tangent space normal texture. The texture encodes RGB components with linear transfer function. Each texel represents the XYZ components of a normal vector in tangent space. The normal vectors use the convention +X is right and +Y is up. +Z points toward the viewer. If a fourth component (A) is present, it **MUST** be ignored. When undefined, the material does not have a tangent space normal texture.
         pub normal_texture: Option<crate::generated::gltf::MaterialNormalTextureInfo>,
         #[serde(rename = "occlusionTexture")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The occlusion texture. The occlusion values are linearly sampled from the R channel. Higher values indicate areas that receive full indirect lighting and lower values indicate no indirect lighting. If other channels are present (GBA), they **MUST** be ignored for occlusion calculations. When undefined, the material does not have an occlusion texture.
         pub occlusion_texture: Option<
             crate::generated::gltf::MaterialOcclusionTextureInfo,
         >,
         #[serde(rename = "pbrMetallicRoughness")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///A set of parameter values that are used to define the metallic-roughness material model from Physically Based Rendering (PBR) methodology. When undefined, all the default values of `pbrMetallicRoughness` **MUST** apply.
         pub pbr_metallic_roughness: Option<
             crate::generated::gltf::MaterialPbrMetallicRoughness,
         >,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Material {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Material {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "alphaCutoff");
+                let value = &self.alpha_cutoff;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "emissiveFactor");
+                let value = &self.emissive_factor;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        Some(0.0),
+                        None,
+                        Some(1.0),
+                        errors,
+                    );
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "emissiveTexture");
+                if let Some(value) = &self.emissive_texture {
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "normalTexture");
+                if let Some(value) = &self.normal_texture {
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "occlusionTexture");
+                if let Some(value) = &self.occlusion_texture {
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "pbrMetallicRoughness");
+                if let Some(value) = &self.pbr_metallic_roughness {
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+        }
+    }
+    ///The default value of `alphaCutoff`.
+    pub fn default_alpha_cutoff() -> f64 {
+        0.5
+    }
+    fn is_default_alpha_cutoff(value: &f64) -> bool {
+        *value == default_alpha_cutoff()
+    }
+    ///The default value of `alphaMode`.
+    pub fn default_alpha_mode() -> AlphaMode {
+        AlphaMode::default()
+    }
+    fn is_default_alpha_mode(value: &AlphaMode) -> bool {
+        *value == default_alpha_mode()
+    }
+    ///The default value of `doubleSided`.
+    pub fn default_double_sided() -> bool {
+        false
+    }
+    fn is_default_double_sided(value: &bool) -> bool {
+        *value == default_double_sided()
+    }
+    ///The default value of `emissiveFactor`.
+    pub fn default_emissive_factor() -> [f64; 3usize] {
+        [0.0, 0.0, 0.0]
+    }
+    fn is_default_emissive_factor(value: &[f64; 3usize]) -> bool {
+        *value == default_emissive_factor()
+    }
 }
 pub use material::Material;
-mod mesh_primitive {
+pub mod mesh_primitive {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Default)]
+    #[serde(from = "i64", into = "i64")]
+    #[repr(u32)]
+    pub enum Mode {
+        Points = 0,
+        Lines = 1,
+        LineLoop = 2,
+        LineStrip = 3,
+        #[default]
+        Triangles = 4,
+        TriangleStrip = 5,
+        TriangleFan = 6,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(i64) = 7,
+    }
+    impl From<i64> for Mode {
+        fn from(value: i64) -> Self {
+            match value {
+                0 => Mode::Points,
+                1 => Mode::Lines,
+                2 => Mode::LineLoop,
+                3 => Mode::LineStrip,
+                4 => Mode::Triangles,
+                5 => Mode::TriangleStrip,
+                6 => Mode::TriangleFan,
+                other => Mode::Other(other),
+            }
+        }
+    }
+    impl From<Mode> for i64 {
+        fn from(value: Mode) -> Self {
+            match value {
+                Mode::Points => 0,
+                Mode::Lines => 1,
+                Mode::LineLoop => 2,
+                Mode::LineStrip => 3,
+                Mode::Triangles => 4,
+                Mode::TriangleStrip => 5,
+                Mode::TriangleFan => 6,
+                Mode::Other(other) => other,
+            }
+        }
+    }
     #[derive(Serialize, Deserialize, Debug)]
     ///Geometry to be rendered with the given material.
     pub struct MeshPrimitive {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         ///A plain JSON object, where each key corresponds to a mesh attribute semantic and each value is the index of the accessor containing attribute's data.
         pub attributes: Map<String, Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The index of the accessor that contains the vertex indices.  When this is undefined, the primitive defines non-indexed geometry.  When defined, the accessor **MUST** have `SCALAR` type and an unsigned integer component type.
-        pub indices: Option<i64>,
+        pub indices: Option<crate::Index<crate::generated::gltf::Accessor>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The index of the material to apply to this primitive when rendering.
-        pub material: Option<i64>,
-        #[serde(default)]
+        pub material: Option<crate::Index<crate::generated::gltf::Material>>,
+        #[serde(default = "default_mode")]
+        #[serde(skip_serializing_if = "is_default_mode")]
         ///The topology type of primitives to render.
-        pub mode: Option<i64>,
+        pub mode: Mode,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///An array of morph targets.
         pub targets: Vec<Map<String, Value>>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for MeshPrimitive {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for MeshPrimitive {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
+    ///The default value of `mode`.
+    pub fn default_mode() -> Mode {
+        Mode::default()
+    }
+    fn is_default_mode(value: &Mode) -> bool {
+        *value == default_mode()
+    }
 }
 pub use mesh_primitive::MeshPrimitive;
-mod mesh {
+pub mod mesh {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///A set of primitives to be rendered.  Its global transform is defined by a node that references it.
     pub struct Mesh {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         ///An array of primitives, each defining geometry to be rendered.
         pub primitives: Vec<crate::generated::gltf::MeshPrimitive>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///Array of weights to be applied to the morph targets. The number of array elements **MUST** match the number of morph targets.
         pub weights: Vec<f64>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Mesh {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Mesh {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "primitives");
+                let value = &self.primitives;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+        }
+    }
 }
 pub use mesh::Mesh;
-mod node {
+pub mod node {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///A node in the node hierarchy.  When the node contains `skin`, all `mesh.primitives` **MUST** contain `JOINTS_0` and `WEIGHTS_0` attributes.  A node **MAY** have either a `matrix` or any combination of `translation`/`rotation`/`scale` (TRS) properties. TRS properties are converted to matrices and postmultiplied in the `T * R * S` order to compose the transformation matrix; first the scale is applied to the vertices, then the rotation, and then the translation. If none are provided, the transform is the identity. When a node is targeted for animation (referenced by an animation.channel.target), `matrix` **MUST NOT** be present.
     pub struct Node {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The index of the camera referenced by this node.
-        pub camera: Option<i64>,
+        pub camera: Option<crate::Index<crate::generated::gltf::Camera>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///The indices of this node's children.
-        pub children: Vec<i64>,
-        #[serde(default)]
+        pub children: Vec<crate::Index<crate::generated::gltf::Node>>,
+        #[serde(default = "default_matrix")]
+        #[serde(skip_serializing_if = "is_default_matrix")]
         ///A floating-point 4x4 transformation matrix stored in column-major order.
-        pub matrix: Option<[f64; 16usize]>,
+        pub matrix: [f64; 16usize],
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The index of the mesh in this node.
-        pub mesh: Option<i64>,
-        #[serde(default)]
+        pub mesh: Option<crate::Index<crate::generated::gltf::Mesh>>,
+        #[serde(default = "default_rotation")]
+        #[serde(skip_serializing_if = "is_default_rotation")]
         ///The node's unit quaternion rotation in the order (x, y, z, w), where w is the scalar.
-        pub rotation: Option<[f64; 4usize]>,
-        #[serde(default)]
+        pub rotation: [f64; 4usize],
+        #[serde(default = "default_scale")]
+        #[serde(skip_serializing_if = "is_default_scale")]
         ///The node's non-uniform scale, given as the scaling factors along the x, y, and z axes.
-        pub scale: Option<[f64; 3usize]>,
+        pub scale: [f64; 3usize],
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The index of the skin referenced by this node. When a skin is referenced by a node within a scene, all joints used by the skin **MUST** belong to the same scene. When defined, `mesh` **MUST** also be defined.
-        pub skin: Option<i64>,
-        #[serde(default)]
+        pub skin: Option<crate::Index<crate::generated::gltf::Skin>>,
+        #[serde(default = "default_translation")]
+        #[serde(skip_serializing_if = "is_default_translation")]
         ///The node's translation along the x, y, and z axes.
-        pub translation: Option<[f64; 3usize]>,
+        pub translation: [f64; 3usize],
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///The weights of the instantiated morph target. The number of array elements **MUST** match the number of morph targets of the referenced mesh. When defined, `mesh` **MUST** also be defined.
         pub weights: Vec<f64>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Node {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Node {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            if self.skin.is_some() && !self.mesh.is_some() {
+                errors
+                    .push(crate::validation::ValidationError {
+                        pointer: format!("{}/{}", pointer, "skin"),
+                        kind: crate::validation::ValidationErrorKind::MissingDependency {
+                            property: String::from("skin"),
+                            dependency: String::from("mesh"),
+                        },
+                    });
+            }
+            if !self.weights.is_empty() && !self.mesh.is_some() {
+                errors
+                    .push(crate::validation::ValidationError {
+                        pointer: format!("{}/{}", pointer, "weights"),
+                        kind: crate::validation::ValidationErrorKind::MissingDependency {
+                            property: String::from("weights"),
+                            dependency: String::from("mesh"),
+                        },
+                    });
+            }
+            if (!is_default_matrix(&self.matrix)
+                && !is_default_translation(&self.translation))
+                || (!is_default_matrix(&self.matrix)
+                    && !is_default_rotation(&self.rotation))
+                || (!is_default_matrix(&self.matrix) && !is_default_scale(&self.scale))
+            {
+                errors
+                    .push(crate::validation::ValidationError {
+                        pointer: pointer.to_string(),
+                        kind: crate::validation::ValidationErrorKind::ForbiddenCombination,
+                    });
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "rotation");
+                let value = &self.rotation;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        Some(-1.0),
+                        None,
+                        Some(1.0),
+                        errors,
+                    );
+                }
+            }
+        }
+    }
+    ///The default value of `matrix`.
+    pub fn default_matrix() -> [f64; 16usize] {
+        [1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0]
+    }
+    fn is_default_matrix(value: &[f64; 16usize]) -> bool {
+        *value == default_matrix()
+    }
+    ///The default value of `rotation`.
+    pub fn default_rotation() -> [f64; 4usize] {
+        [0.0, 0.0, 0.0, 1.0]
+    }
+    fn is_default_rotation(value: &[f64; 4usize]) -> bool {
+        *value == default_rotation()
+    }
+    ///The default value of `scale`.
+    pub fn default_scale() -> [f64; 3usize] {
+        [1.0, 1.0, 1.0]
+    }
+    fn is_default_scale(value: &[f64; 3usize]) -> bool {
+        *value == default_scale()
+    }
+    ///The default value of `translation`.
+    pub fn default_translation() -> [f64; 3usize] {
+        [0.0, 0.0, 0.0]
+    }
+    fn is_default_translation(value: &[f64; 3usize]) -> bool {
+        *value == default_translation()
+    }
 }
 pub use node::Node;
-mod sampler {
+pub mod sampler {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(from = "i64", into = "i64")]
+    #[repr(u32)]
+    pub enum MagFilter {
+        Nearest = 9728,
+        Linear = 9729,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(i64) = 9730,
+    }
+    impl From<i64> for MagFilter {
+        fn from(value: i64) -> Self {
+            match value {
+                9728 => MagFilter::Nearest,
+                9729 => MagFilter::Linear,
+                other => MagFilter::Other(other),
+            }
+        }
+    }
+    impl From<MagFilter> for i64 {
+        fn from(value: MagFilter) -> Self {
+            match value {
+                MagFilter::Nearest => 9728,
+                MagFilter::Linear => 9729,
+                MagFilter::Other(other) => other,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(from = "i64", into = "i64")]
+    #[repr(u32)]
+    pub enum MinFilter {
+        Nearest = 9728,
+        Linear = 9729,
+        NearestMipmapNearest = 9984,
+        LinearMipmapNearest = 9985,
+        NearestMipmapLinear = 9986,
+        LinearMipmapLinear = 9987,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(i64) = 9988,
+    }
+    impl From<i64> for MinFilter {
+        fn from(value: i64) -> Self {
+            match value {
+                9728 => MinFilter::Nearest,
+                9729 => MinFilter::Linear,
+                9984 => MinFilter::NearestMipmapNearest,
+                9985 => MinFilter::LinearMipmapNearest,
+                9986 => MinFilter::NearestMipmapLinear,
+                9987 => MinFilter::LinearMipmapLinear,
+                other => MinFilter::Other(other),
+            }
+        }
+    }
+    impl From<MinFilter> for i64 {
+        fn from(value: MinFilter) -> Self {
+            match value {
+                MinFilter::Nearest => 9728,
+                MinFilter::Linear => 9729,
+                MinFilter::NearestMipmapNearest => 9984,
+                MinFilter::LinearMipmapNearest => 9985,
+                MinFilter::NearestMipmapLinear => 9986,
+                MinFilter::LinearMipmapLinear => 9987,
+                MinFilter::Other(other) => other,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Default)]
+    #[serde(from = "i64", into = "i64")]
+    #[repr(u32)]
+    pub enum WrapS {
+        ClampToEdge = 33071,
+        MirroredRepeat = 33648,
+        #[default]
+        Repeat = 10497,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(i64) = 33649,
+    }
+    impl From<i64> for WrapS {
+        fn from(value: i64) -> Self {
+            match value {
+                33071 => WrapS::ClampToEdge,
+                33648 => WrapS::MirroredRepeat,
+                10497 => WrapS::Repeat,
+                other => WrapS::Other(other),
+            }
+        }
+    }
+    impl From<WrapS> for i64 {
+        fn from(value: WrapS) -> Self {
+            match value {
+                WrapS::ClampToEdge => 33071,
+                WrapS::MirroredRepeat => 33648,
+                WrapS::Repeat => 10497,
+                WrapS::Other(other) => other,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Default)]
+    #[serde(from = "i64", into = "i64")]
+    #[repr(u32)]
+    pub enum WrapT {
+        ClampToEdge = 33071,
+        MirroredRepeat = 33648,
+        #[default]
+        Repeat = 10497,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(i64) = 33649,
+    }
+    impl From<i64> for WrapT {
+        fn from(value: i64) -> Self {
+            match value {
+                33071 => WrapT::ClampToEdge,
+                33648 => WrapT::MirroredRepeat,
+                10497 => WrapT::Repeat,
+                other => WrapT::Other(other),
+            }
+        }
+    }
+    impl From<WrapT> for i64 {
+        fn from(value: WrapT) -> Self {
+            match value {
+                WrapT::ClampToEdge => 33071,
+                WrapT::MirroredRepeat => 33648,
+                WrapT::Repeat => 10497,
+                WrapT::Other(other) => other,
+            }
+        }
+    }
     #[derive(Serialize, Deserialize, Debug)]
     ///Texture sampler properties for filtering and wrapping modes.
     pub struct Sampler {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         #[serde(rename = "magFilter")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Magnification filter.
-        pub mag_filter: Option<i64>,
+        pub mag_filter: Option<MagFilter>,
         #[serde(rename = "minFilter")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Minification filter.
-        pub min_filter: Option<i64>,
+        pub min_filter: Option<MinFilter>,
         #[serde(rename = "wrapS")]
-        #[serde(default)]
+        #[serde(default = "default_wrap_s")]
+        #[serde(skip_serializing_if = "is_default_wrap_s")]
         ///S (U) wrapping mode.  All valid values correspond to WebGL enums.
-        pub wrap_s: Option<i64>,
+        pub wrap_s: WrapS,
         #[serde(rename = "wrapT")]
-        #[serde(default)]
+        #[serde(default = "default_wrap_t")]
+        #[serde(skip_serializing_if = "is_default_wrap_t")]
         ///T (V) wrapping mode.
-        pub wrap_t: Option<i64>,
+        pub wrap_t: WrapT,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Sampler {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Sampler {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
+    ///The default value of `wrapS`.
+    pub fn default_wrap_s() -> WrapS {
+        WrapS::default()
+    }
+    fn is_default_wrap_s(value: &WrapS) -> bool {
+        *value == default_wrap_s()
+    }
+    ///The default value of `wrapT`.
+    pub fn default_wrap_t() -> WrapT {
+        WrapT::default()
+    }
+    fn is_default_wrap_t(value: &WrapT) -> bool {
+        *value == default_wrap_t()
+    }
 }
 pub use sampler::Sampler;
-mod scene {
+pub mod scene {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///The root nodes of a scene.
     pub struct Scene {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///The indices of each root node.
-        pub nodes: Vec<i64>,
+        pub nodes: Vec<crate::Index<crate::generated::gltf::Node>>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Scene {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Scene {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
 }
 pub use scene::Scene;
-mod skin {
+pub mod skin {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///Joints and matrices defining a skin.
     pub struct Skin {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         #[serde(rename = "inverseBindMatrices")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The index of the accessor containing the floating-point 4x4 inverse-bind matrices. Its `accessor.count` property **MUST** be greater than or equal to the number of elements of the `joints` array. When undefined, each matrix is a 4x4 identity matrix.
-        pub inverse_bind_matrices: Option<i64>,
+        pub inverse_bind_matrices: Option<
+            crate::Index<crate::generated::gltf::Accessor>,
+        >,
         ///Indices of skeleton nodes, used as joints in this skin.
-        pub joints: Vec<i64>,
+        pub joints: Vec<crate::Index<crate::generated::gltf::Node>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The index of the node used as a skeleton root. The node **MUST** be the closest common root of the joints hierarchy or a direct or indirect parent node of the closest common root.
-        pub skeleton: Option<i64>,
+        pub skeleton: Option<crate::Index<crate::generated::gltf::Node>>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Skin {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Skin {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
 }
 pub use skin::Skin;
-mod texture {
+pub mod texture {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///A texture and its sampler.
     pub struct Texture {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The index of the sampler used by this texture. When undefined, a sampler with repeat wrapping and auto filtering **SHOULD** be used.
-        pub sampler: Option<i64>,
+        pub sampler: Option<crate::Index<crate::generated::gltf::Sampler>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The index of the image used by this texture. When undefined, an extension or other mechanism **SHOULD** supply an alternate texture source, otherwise behavior is undefined.
-        pub source: Option<i64>,
+        pub source: Option<crate::Index<crate::generated::gltf::Image>>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Texture {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Texture {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
 }
 pub use texture::Texture;
-mod texture_info {
+pub mod texture_info {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///Reference to a texture.
     pub struct TextureInfo {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         ///The index of the texture.
-        pub index: i64,
+        pub index: crate::Index<crate::generated::gltf::Texture>,
         #[serde(rename = "texCoord")]
-        #[serde(default)]
+        #[serde(default = "default_tex_coord")]
+        #[serde(skip_serializing_if = "is_default_tex_coord")]
         ///This integer value is used to construct a string in the format `TEXCOORD_<set index>` which is a reference to a key in `mesh.primitives.attributes` (e.g. a value of `0` corresponds to `TEXCOORD_0`). A mesh primitive **MUST** have the corresponding texture coordinate attributes for the material to be applicable to it.
-        pub tex_coord: Option<i64>,
+        pub tex_coord: i64,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for TextureInfo {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for TextureInfo {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "texCoord");
+                let value = &self.tex_coord;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+        }
+    }
+    ///The default value of `texCoord`.
+    pub fn default_tex_coord() -> i64 {
+        0
+    }
+    fn is_default_tex_coord(value: &i64) -> bool {
+        *value == default_tex_coord()
+    }
 }
 pub use texture_info::TextureInfo;
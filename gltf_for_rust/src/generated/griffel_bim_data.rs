@@ -1,9 +1,11 @@
 #![allow(clippy::all, unused_imports)]
-mod gltf {
-    mod extension {
+pub use gltf::Extension as RootExtension;
+pub use node::Extension as NodeExtension;
+pub mod gltf {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
-        mod property {
+        pub mod property {
             use serde::{Serialize, Deserialize};
             use serde_json::{Map, Value};
             #[derive(Serialize, Deserialize, Debug)]
@@ -13,20 +15,43 @@ mod gltf {
                 pub name: i64,
                 ///Index of a property value in the root level collection.
                 pub value: i64,
+                #[serde(flatten)]
+                ///Properties that are not part of the schema, kept so they survive a round trip.
+                pub unknown: Map<String, Value>,
+            }
+            impl crate::validation::Validate for Property {
+                #[allow(unused_variables)]
+                fn validate_at(
+                    &self,
+                    pointer: &str,
+                    errors: &mut Vec<crate::validation::ValidationError>,
+                ) {}
             }
         }
         pub use property::Property;
-        mod ty {
+        pub mod ty {
             use serde::{Serialize, Deserialize};
             use serde_json::{Map, Value};
             #[derive(Serialize, Deserialize, Debug)]
             ///Set of properties which are common for many nodes.
             pub struct Type {
                 #[serde(default)]
+                #[serde(skip_serializing_if = "Option::is_none")]
                 ///Name of the type.
                 pub name: Option<String>,
                 ///Collection of indices which point to corresponding properties of the type.
                 pub properties: Vec<i64>,
+                #[serde(flatten)]
+                ///Properties that are not part of the schema, kept so they survive a round trip.
+                pub unknown: Map<String, Value>,
+            }
+            impl crate::validation::Validate for Type {
+                #[allow(unused_variables)]
+                fn validate_at(
+                    &self,
+                    pointer: &str,
+                    errors: &mut Vec<crate::validation::ValidationError>,
+                ) {}
             }
         }
         pub use ty::Type;
@@ -42,41 +67,78 @@ mod gltf {
             ///Collection of unique property values.
             pub property_values: Vec<String>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Vec::is_empty")]
             ///Collection of types - common sets of properties for many nodes.
             pub types: Vec<Type>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
                 "GRIFFEL_bim_data"
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "properties");
+                    let value = &self.properties;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "types");
+                    let value = &self.types;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
     }
     pub use extension::Extension;
 }
-mod node {
-    mod extension {
+pub mod node {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///References type and instance properties of the node and/or buffer where those properties can be found by node ID.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "bufferView")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Index of the buffer view which points to the buffer with the data for this node.
             pub buffer_view: Option<i64>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Vec::is_empty")]
             ///Collection of indices which point to corresponding instance properties of the node. (Instance properties are unique to the node. They override the same type properties.)
             pub properties: Vec<i64>,
             #[serde(rename = "type")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Index of a type in the root level collection. (Type is a set of properties which are common for many nodes.)
             pub ty: Option<i64>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -88,6 +150,14 @@ mod node {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
     }
     pub use extension::Extension;
 }
@@ -1,21 +1,27 @@
 #![allow(clippy::all, unused_imports)]
-mod mesh_primitive {
-    mod extension {
+pub use mesh_primitive::Extension as MeshPrimitiveExtension;
+pub mod mesh_primitive {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             ///A dictionary object, where each key corresponds to an attribute and its unique attribute id stored in the compressed geometry.
             pub attributes: Map<String, Value>,
             #[serde(rename = "bufferView")]
             ///The index of the bufferView.
-            pub buffer_view: i64,
+            pub buffer_view: crate::Index<crate::generated::gltf::BufferView>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -27,6 +33,14 @@ mod mesh_primitive {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
     }
     pub use extension::Extension;
 }
@@ -1,17 +1,24 @@
 #![allow(clippy::all, unused_imports)]
-mod gltf {
-    mod extension {
+pub use gltf::Extension as RootExtension;
+pub use node::Extension as NodeExtension;
+pub mod gltf {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
-            pub lights: Vec<Map<String, Value>>,
+            pub lights: Vec<crate::generated::khr_lights_punctual::Light>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -23,23 +30,53 @@ mod gltf {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "lights");
+                    let value = &self.lights;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
+        impl crate::Get<crate::generated::khr_lights_punctual::Light> for Extension {
+            fn get(
+                &self,
+                index: crate::Index<crate::generated::khr_lights_punctual::Light>,
+            ) -> Option<&crate::generated::khr_lights_punctual::Light> {
+                self.lights.get(index.value())
+            }
+        }
     }
     pub use extension::Extension;
 }
-mod node {
-    mod extension {
+pub mod node {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             ///The id of the light referenced by this node.
-            pub light: i64,
+            pub light: crate::Index<crate::generated::khr_lights_punctual::Light>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -51,6 +88,241 @@ mod node {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
     }
     pub use extension::Extension;
 }
+pub mod light {
+    use serde::{Serialize, Deserialize};
+    use serde_json::{Map, Value};
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    #[serde(from = "String", into = "String")]
+    pub enum Type {
+        Directional,
+        Point,
+        Spot,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(String),
+    }
+    impl From<String> for Type {
+        fn from(value: String) -> Self {
+            match value.as_str() {
+                "directional" => Type::Directional,
+                "point" => Type::Point,
+                "spot" => Type::Spot,
+                _ => Type::Other(value),
+            }
+        }
+    }
+    impl From<Type> for String {
+        fn from(value: Type) -> Self {
+            match value {
+                Type::Directional => String::from("directional"),
+                Type::Point => String::from("point"),
+                Type::Spot => String::from("spot"),
+                Type::Other(other) => other,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug)]
+    ///A directional, point, or spot light.
+    pub struct Light {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///JSON object with extension-specific objects.
+        pub extensions: Option<Map<String, Value>>,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///Application-specific data.
+        pub extras: Option<serde_json::Value>,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
+        pub name: Option<String>,
+        #[serde(default = "default_color")]
+        #[serde(skip_serializing_if = "is_default_color")]
+        ///Color of the light source.
+        pub color: [f64; 3usize],
+        #[serde(default = "default_intensity")]
+        #[serde(skip_serializing_if = "is_default_intensity")]
+        ///Intensity of the light source.
+        pub intensity: f64,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///A distance cutoff at which the light's intensity may be considered to have reached zero.
+        pub range: Option<f64>,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub spot: Option<crate::generated::khr_lights_punctual::LightSpot>,
+        #[serde(rename = "type")]
+        ///Specifies the light type.
+        pub ty: Type,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
+    }
+    impl crate::GltfObject for Light {
+        fn extensions(&self) -> &Option<Map<String, Value>> {
+            &self.extensions
+        }
+    }
+    impl crate::validation::Validate for Light {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "color");
+                let value = &self.color;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        Some(0.0),
+                        None,
+                        Some(1.0),
+                        errors,
+                    );
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "intensity");
+                let value = &self.intensity;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "range");
+                if let Some(value) = &self.range {
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        None,
+                        Some(0.0),
+                        None,
+                        errors,
+                    );
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "spot");
+                if let Some(value) = &self.spot {
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+        }
+    }
+    ///The default value of `color`.
+    pub fn default_color() -> [f64; 3usize] {
+        [1.0, 1.0, 1.0]
+    }
+    fn is_default_color(value: &[f64; 3usize]) -> bool {
+        *value == default_color()
+    }
+    ///The default value of `intensity`.
+    pub fn default_intensity() -> f64 {
+        1.0
+    }
+    fn is_default_intensity(value: &f64) -> bool {
+        *value == default_intensity()
+    }
+}
+pub use light::Light;
+pub mod light_spot {
+    use serde::{Serialize, Deserialize};
+    use serde_json::{Map, Value};
+    #[derive(Serialize, Deserialize, Debug)]
+    ///When a light's type is spot, this property on the light is required.
+    pub struct LightSpot {
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///JSON object with extension-specific objects.
+        pub extensions: Option<Map<String, Value>>,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ///Application-specific data.
+        pub extras: Option<serde_json::Value>,
+        #[serde(rename = "innerConeAngle")]
+        #[serde(default = "default_inner_cone_angle")]
+        #[serde(skip_serializing_if = "is_default_inner_cone_angle")]
+        ///Angle in radians from centre of spotlight where falloff begins.
+        pub inner_cone_angle: f64,
+        #[serde(rename = "outerConeAngle")]
+        #[serde(default = "default_outer_cone_angle")]
+        #[serde(skip_serializing_if = "is_default_outer_cone_angle")]
+        ///Angle in radians from centre of spotlight where falloff ends.
+        pub outer_cone_angle: f64,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
+    }
+    impl crate::GltfObject for LightSpot {
+        fn extensions(&self) -> &Option<Map<String, Value>> {
+            &self.extensions
+        }
+    }
+    impl crate::validation::Validate for LightSpot {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "innerConeAngle");
+                let value = &self.inner_cone_angle;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    Some(0.0),
+                    None,
+                    None,
+                    errors,
+                );
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "outerConeAngle");
+                let value = &self.outer_cone_angle;
+                crate::validation::check_number(
+                    pointer,
+                    *value as f64,
+                    None,
+                    Some(0.0),
+                    Some(1.5707963267948966),
+                    errors,
+                );
+            }
+        }
+    }
+    ///The default value of `innerConeAngle`.
+    pub fn default_inner_cone_angle() -> f64 {
+        0.0
+    }
+    fn is_default_inner_cone_angle(value: &f64) -> bool {
+        *value == default_inner_cone_angle()
+    }
+    ///The default value of `outerConeAngle`.
+    pub fn default_outer_cone_angle() -> f64 {
+        0.7853981633974483
+    }
+    fn is_default_outer_cone_angle(value: &f64) -> bool {
+        *value == default_outer_cone_angle()
+    }
+}
+pub use light_spot::LightSpot;
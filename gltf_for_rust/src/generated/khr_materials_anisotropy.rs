@@ -1,29 +1,38 @@
 #![allow(clippy::all, unused_imports)]
-mod material {
-    mod extension {
+pub use material::Extension as MaterialExtension;
+pub mod material {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that defines anisotropy.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "anisotropyRotation")]
-            #[serde(default)]
+            #[serde(default = "default_anisotropy_rotation")]
+            #[serde(skip_serializing_if = "is_default_anisotropy_rotation")]
             ///The rotation of the anisotropy in tangent, bitangent space, measured in radians counter-clockwise from the tangent. When anisotropyTexture is present, anisotropyRotation provides additional rotation to the vectors in the texture.
-            pub anisotropy_rotation: Option<f64>,
+            pub anisotropy_rotation: f64,
             #[serde(rename = "anisotropyStrength")]
-            #[serde(default)]
+            #[serde(default = "default_anisotropy_strength")]
+            #[serde(skip_serializing_if = "is_default_anisotropy_strength")]
             ///The anisotropy strength. When anisotropyTexture is present, this value is multiplied by the blue channel.
-            pub anisotropy_strength: Option<f64>,
+            pub anisotropy_strength: f64,
             #[serde(rename = "anisotropyTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The anisotropy texture. Red and green channels represent the anisotropy direction in [-1, 1] tangent, bitangent space, to be rotated by anisotropyRotation. The blue channel contains strength as [0, 1] to be multiplied by anisotropyStrength.
             pub anisotropy_texture: Option<crate::generated::gltf::TextureInfo>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -35,6 +44,35 @@ mod material {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "anisotropyTexture");
+                    if let Some(value) = &self.anisotropy_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
+        ///The default value of `anisotropyRotation`.
+        pub fn default_anisotropy_rotation() -> f64 {
+            0.0
+        }
+        fn is_default_anisotropy_rotation(value: &f64) -> bool {
+            *value == default_anisotropy_rotation()
+        }
+        ///The default value of `anisotropyStrength`.
+        pub fn default_anisotropy_strength() -> f64 {
+            0.0
+        }
+        fn is_default_anisotropy_strength(value: &f64) -> bool {
+            *value == default_anisotropy_strength()
+        }
     }
     pub use extension::Extension;
 }
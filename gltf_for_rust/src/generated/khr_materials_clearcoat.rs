@@ -1,39 +1,50 @@
 #![allow(clippy::all, unused_imports)]
-mod material {
-    mod extension {
+pub use material::Extension as MaterialExtension;
+pub mod material {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that defines the clearcoat material layer.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "clearcoatFactor")]
-            #[serde(default)]
+            #[serde(default = "default_clearcoat_factor")]
+            #[serde(skip_serializing_if = "is_default_clearcoat_factor")]
             ///The clearcoat layer intensity (aka opacity) of the material. A value of 0.0 means the material has no clearcoat layer enabled.
-            pub clearcoat_factor: Option<f64>,
+            pub clearcoat_factor: f64,
             #[serde(rename = "clearcoatNormalTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///A tangent space normal map for the clearcoat layer.  If desired, this may be a reference to the same normal map used by the base material.  If not supplied, no normal mapping is applied to the clear coat layer.
             pub clearcoat_normal_texture: Option<
                 crate::generated::gltf::MaterialNormalTextureInfo,
             >,
             #[serde(rename = "clearcoatRoughnessFactor")]
-            #[serde(default)]
+            #[serde(default = "default_clearcoat_roughness_factor")]
+            #[serde(skip_serializing_if = "is_default_clearcoat_roughness_factor")]
             ///The clearcoat layer roughness of the material.
-            pub clearcoat_roughness_factor: Option<f64>,
+            pub clearcoat_roughness_factor: f64,
             #[serde(rename = "clearcoatRoughnessTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The clearcoat layer roughness texture. These values are sampled from the G channel.  The values are linear.  Use value 1.0 if no texture is supplied.
             pub clearcoat_roughness_texture: Option<crate::generated::gltf::TextureInfo>,
             #[serde(rename = "clearcoatTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The clearcoat layer intensity texture. These values are sampled from the R channel.  The values are linear.  Use value 1.0 if no texture is supplied.
             pub clearcoat_texture: Option<crate::generated::gltf::TextureInfo>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -45,6 +56,73 @@ mod material {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "clearcoatFactor");
+                    let value = &self.clearcoat_factor;
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        Some(0.0),
+                        None,
+                        Some(1.0),
+                        errors,
+                    );
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "clearcoatNormalTexture");
+                    if let Some(value) = &self.clearcoat_normal_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "clearcoatRoughnessFactor");
+                    let value = &self.clearcoat_roughness_factor;
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        Some(0.0),
+                        None,
+                        Some(1.0),
+                        errors,
+                    );
+                }
+                {
+                    let pointer = &format!(
+                        "{}/{}", pointer, "clearcoatRoughnessTexture"
+                    );
+                    if let Some(value) = &self.clearcoat_roughness_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "clearcoatTexture");
+                    if let Some(value) = &self.clearcoat_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
+        ///The default value of `clearcoatFactor`.
+        pub fn default_clearcoat_factor() -> f64 {
+            0.0
+        }
+        fn is_default_clearcoat_factor(value: &f64) -> bool {
+            *value == default_clearcoat_factor()
+        }
+        ///The default value of `clearcoatRoughnessFactor`.
+        pub fn default_clearcoat_roughness_factor() -> f64 {
+            0.0
+        }
+        fn is_default_clearcoat_roughness_factor(value: &f64) -> bool {
+            *value == default_clearcoat_roughness_factor()
+        }
     }
     pub use extension::Extension;
 }
@@ -1,20 +1,27 @@
 #![allow(clippy::all, unused_imports)]
-mod material {
-    mod extension {
+pub use material::Extension as MaterialExtension;
+pub mod material {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that defines the strength of dispersion.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
-            #[serde(default)]
+            #[serde(default = "default_dispersion")]
+            #[serde(skip_serializing_if = "is_default_dispersion")]
             ///This parameter defines dispersion in terms of the 20/Abbe number formulation.
-            pub dispersion: Option<f64>,
+            pub dispersion: f64,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -26,6 +33,21 @@ mod material {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
+        ///The default value of `dispersion`.
+        pub fn default_dispersion() -> f64 {
+            0.0
+        }
+        fn is_default_dispersion(value: &f64) -> bool {
+            *value == default_dispersion()
+        }
     }
     pub use extension::Extension;
 }
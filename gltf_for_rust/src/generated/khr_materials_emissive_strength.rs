@@ -1,21 +1,28 @@
 #![allow(clippy::all, unused_imports)]
-mod material {
-    mod extension {
+pub use material::Extension as MaterialExtension;
+pub mod material {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that adjusts the strength of emissive material properties.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "emissiveStrength")]
-            #[serde(default)]
+            #[serde(default = "default_emissive_strength")]
+            #[serde(skip_serializing_if = "is_default_emissive_strength")]
             ///The strength adjustment to be multiplied with the material's emissive value.
-            pub emissive_strength: Option<f64>,
+            pub emissive_strength: f64,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -27,6 +34,21 @@ mod material {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
+        ///The default value of `emissiveStrength`.
+        pub fn default_emissive_strength() -> f64 {
+            1.0
+        }
+        fn is_default_emissive_strength(value: &f64) -> bool {
+            *value == default_emissive_strength()
+        }
     }
     pub use extension::Extension;
 }
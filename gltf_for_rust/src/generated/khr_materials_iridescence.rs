@@ -1,43 +1,55 @@
 #![allow(clippy::all, unused_imports)]
-mod material {
-    mod extension {
+pub use material::Extension as MaterialExtension;
+pub mod material {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that defines an iridescence effect.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "iridescenceFactor")]
-            #[serde(default)]
+            #[serde(default = "default_iridescence_factor")]
+            #[serde(skip_serializing_if = "is_default_iridescence_factor")]
             ///The iridescence intensity factor.
-            pub iridescence_factor: Option<f64>,
+            pub iridescence_factor: f64,
             #[serde(rename = "iridescenceIor")]
-            #[serde(default)]
+            #[serde(default = "default_iridescence_ior")]
+            #[serde(skip_serializing_if = "is_default_iridescence_ior")]
             ///The index of refraction of the dielectric thin-film layer.
-            pub iridescence_ior: Option<f64>,
+            pub iridescence_ior: f64,
             #[serde(rename = "iridescenceTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The iridescence intensity texture. The values are sampled from the R channel. These values are linear. If a texture is not given, a value of `1.0` **MUST** be assumed. If other channels are present (GBA), they are ignored for iridescence intensity calculations.
             pub iridescence_texture: Option<crate::generated::gltf::TextureInfo>,
             #[serde(rename = "iridescenceThicknessMaximum")]
-            #[serde(default)]
+            #[serde(default = "default_iridescence_thickness_maximum")]
+            #[serde(skip_serializing_if = "is_default_iridescence_thickness_maximum")]
             ///The maximum thickness of the thin-film layer given in nanometers. The value **MUST** be greater than or equal to the value of `iridescenceThicknessMinimum`.
-            pub iridescence_thickness_maximum: Option<f64>,
+            pub iridescence_thickness_maximum: f64,
             #[serde(rename = "iridescenceThicknessMinimum")]
-            #[serde(default)]
+            #[serde(default = "default_iridescence_thickness_minimum")]
+            #[serde(skip_serializing_if = "is_default_iridescence_thickness_minimum")]
             ///The minimum thickness of the thin-film layer given in nanometers. The value **MUST** be less than or equal to the value of `iridescenceThicknessMaximum`.
-            pub iridescence_thickness_minimum: Option<f64>,
+            pub iridescence_thickness_minimum: f64,
             #[serde(rename = "iridescenceThicknessTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The thickness texture of the thin-film layer to linearly interpolate between the minimum and maximum thickness given by the corresponding properties, where a sampled value of `0.0` represents the minimum thickness and a sampled value of `1.0` represents the maximum thickness. The values are sampled from the G channel. These values are linear. If a texture is not given, the maximum thickness **MUST** be assumed. If other channels are present (RBA), they are ignored for thickness calculations.
             pub iridescence_thickness_texture: Option<
                 crate::generated::gltf::TextureInfo,
             >,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -49,6 +61,57 @@ mod material {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "iridescenceTexture");
+                    if let Some(value) = &self.iridescence_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+                {
+                    let pointer = &format!(
+                        "{}/{}", pointer, "iridescenceThicknessTexture"
+                    );
+                    if let Some(value) = &self.iridescence_thickness_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
+        ///The default value of `iridescenceFactor`.
+        pub fn default_iridescence_factor() -> f64 {
+            0.0
+        }
+        fn is_default_iridescence_factor(value: &f64) -> bool {
+            *value == default_iridescence_factor()
+        }
+        ///The default value of `iridescenceIor`.
+        pub fn default_iridescence_ior() -> f64 {
+            1.3
+        }
+        fn is_default_iridescence_ior(value: &f64) -> bool {
+            *value == default_iridescence_ior()
+        }
+        ///The default value of `iridescenceThicknessMaximum`.
+        pub fn default_iridescence_thickness_maximum() -> f64 {
+            400.0
+        }
+        fn is_default_iridescence_thickness_maximum(value: &f64) -> bool {
+            *value == default_iridescence_thickness_maximum()
+        }
+        ///The default value of `iridescenceThicknessMinimum`.
+        pub fn default_iridescence_thickness_minimum() -> f64 {
+            100.0
+        }
+        fn is_default_iridescence_thickness_minimum(value: &f64) -> bool {
+            *value == default_iridescence_thickness_minimum()
+        }
     }
     pub use extension::Extension;
 }
@@ -1,33 +1,43 @@
 #![allow(clippy::all, unused_imports)]
-mod material {
-    mod extension {
+pub use material::Extension as MaterialExtension;
+pub mod material {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that defines the sheen material model.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "sheenColorFactor")]
-            #[serde(default)]
+            #[serde(default = "default_sheen_color_factor")]
+            #[serde(skip_serializing_if = "is_default_sheen_color_factor")]
             ///Color of the sheen layer (in linear space).
-            pub sheen_color_factor: Option<[f64; 3usize]>,
+            pub sheen_color_factor: [f64; 3usize],
             #[serde(rename = "sheenColorTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The sheen color (RGB) texture. Stored in channel RGB, the sheen color is in sRGB transfer function.
             pub sheen_color_texture: Option<crate::generated::gltf::TextureInfo>,
             #[serde(rename = "sheenRoughnessFactor")]
-            #[serde(default)]
+            #[serde(default = "default_sheen_roughness_factor")]
+            #[serde(skip_serializing_if = "is_default_sheen_roughness_factor")]
             ///The sheen layer roughness of the material.
-            pub sheen_roughness_factor: Option<f64>,
+            pub sheen_roughness_factor: f64,
             #[serde(rename = "sheenRoughnessTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///The sheen roughness (Alpha) texture. Stored in alpha channel, the roughness value is in linear space.
             pub sheen_roughness_texture: Option<crate::generated::gltf::TextureInfo>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -39,6 +49,68 @@ mod material {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "sheenColorFactor");
+                    let value = &self.sheen_color_factor;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::check_number(
+                            pointer,
+                            *value as f64,
+                            Some(0.0),
+                            None,
+                            Some(1.0),
+                            errors,
+                        );
+                    }
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "sheenColorTexture");
+                    if let Some(value) = &self.sheen_color_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "sheenRoughnessFactor");
+                    let value = &self.sheen_roughness_factor;
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        Some(0.0),
+                        None,
+                        Some(1.0),
+                        errors,
+                    );
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "sheenRoughnessTexture");
+                    if let Some(value) = &self.sheen_roughness_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
+        ///The default value of `sheenColorFactor`.
+        pub fn default_sheen_color_factor() -> [f64; 3usize] {
+            [0.0, 0.0, 0.0]
+        }
+        fn is_default_sheen_color_factor(value: &[f64; 3usize]) -> bool {
+            *value == default_sheen_color_factor()
+        }
+        ///The default value of `sheenRoughnessFactor`.
+        pub fn default_sheen_roughness_factor() -> f64 {
+            0.0
+        }
+        fn is_default_sheen_roughness_factor(value: &f64) -> bool {
+            *value == default_sheen_roughness_factor()
+        }
     }
     pub use extension::Extension;
 }
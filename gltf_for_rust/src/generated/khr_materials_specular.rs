@@ -1,33 +1,43 @@
 #![allow(clippy::all, unused_imports)]
-mod material {
-    mod extension {
+pub use material::Extension as MaterialExtension;
+pub mod material {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that defines the strength of the specular reflection.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "specularColorFactor")]
-            #[serde(default)]
+            #[serde(default = "default_specular_color_factor")]
+            #[serde(skip_serializing_if = "is_default_specular_color_factor")]
             ///This is an additional RGB color parameter that tints the specular reflection of non-metallic surfaces. At grazing angles, the reflection still blends to white, and the parameter has not effect on metals. The value is linear.
-            pub specular_color_factor: Option<[f64; 3usize]>,
+            pub specular_color_factor: [f64; 3usize],
             #[serde(rename = "specularColorTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///A texture that defines the specular color in the RGB channels (encoded in sRGB). This will be multiplied by specularColorFactor.
             pub specular_color_texture: Option<crate::generated::gltf::TextureInfo>,
             #[serde(rename = "specularFactor")]
-            #[serde(default)]
+            #[serde(default = "default_specular_factor")]
+            #[serde(skip_serializing_if = "is_default_specular_factor")]
             ///This parameter scales the amount of specular reflection on non-metallic surfaces. It has no effect on metals.
-            pub specular_factor: Option<f64>,
+            pub specular_factor: f64,
             #[serde(rename = "specularTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///A texture that defines the specular factor in the alpha channel. This will be multiplied by specularFactor.
             pub specular_texture: Option<crate::generated::gltf::TextureInfo>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -39,6 +49,68 @@ mod material {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "specularColorFactor");
+                    let value = &self.specular_color_factor;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::check_number(
+                            pointer,
+                            *value as f64,
+                            Some(0.0),
+                            None,
+                            None,
+                            errors,
+                        );
+                    }
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "specularColorTexture");
+                    if let Some(value) = &self.specular_color_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "specularFactor");
+                    let value = &self.specular_factor;
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        Some(0.0),
+                        None,
+                        Some(1.0),
+                        errors,
+                    );
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "specularTexture");
+                    if let Some(value) = &self.specular_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
+        ///The default value of `specularColorFactor`.
+        pub fn default_specular_color_factor() -> [f64; 3usize] {
+            [1.0, 1.0, 1.0]
+        }
+        fn is_default_specular_color_factor(value: &[f64; 3usize]) -> bool {
+            *value == default_specular_color_factor()
+        }
+        ///The default value of `specularFactor`.
+        pub fn default_specular_factor() -> f64 {
+            1.0
+        }
+        fn is_default_specular_factor(value: &f64) -> bool {
+            *value == default_specular_factor()
+        }
     }
     pub use extension::Extension;
 }
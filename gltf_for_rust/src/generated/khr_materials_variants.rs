@@ -1,40 +1,60 @@
 #![allow(clippy::all, unused_imports)]
-mod gltf {
-    mod extension {
+pub use gltf::Extension as RootExtension;
+pub use mesh_primitive::Extension as MeshPrimitiveExtension;
+pub mod gltf {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
-        mod variant {
+        pub mod variant {
             use serde::{Serialize, Deserialize};
             use serde_json::{Map, Value};
             #[derive(Serialize, Deserialize, Debug)]
             ///An object defining a valid material variant
             pub struct Variant {
                 #[serde(default)]
+                #[serde(skip_serializing_if = "Option::is_none")]
                 ///JSON object with extension-specific objects.
                 pub extensions: Option<Map<String, Value>>,
                 #[serde(default)]
+                #[serde(skip_serializing_if = "Option::is_none")]
                 ///Application-specific data.
                 pub extras: Option<serde_json::Value>,
                 ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
                 pub name: String,
+                #[serde(flatten)]
+                ///Properties that are not part of the schema, kept so they survive a round trip.
+                pub unknown: Map<String, Value>,
             }
             impl crate::GltfObject for Variant {
                 fn extensions(&self) -> &Option<Map<String, Value>> {
                     &self.extensions
                 }
             }
+            impl crate::validation::Validate for Variant {
+                #[allow(unused_variables)]
+                fn validate_at(
+                    &self,
+                    pointer: &str,
+                    errors: &mut Vec<crate::validation::ValidationError>,
+                ) {}
+            }
         }
         pub use variant::Variant;
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that defines a material variations for mesh primitives
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             pub variants: Vec<Variant>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -46,49 +66,85 @@ mod gltf {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "variants");
+                    let value = &self.variants;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
     }
     pub use extension::Extension;
 }
-mod mesh_primitive {
-    mod extension {
+pub mod mesh_primitive {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
-        mod mapping {
+        pub mod mapping {
             use serde::{Serialize, Deserialize};
             use serde_json::{Map, Value};
             #[derive(Serialize, Deserialize, Debug)]
             pub struct Mapping {
                 #[serde(default)]
+                #[serde(skip_serializing_if = "Option::is_none")]
                 ///JSON object with extension-specific objects.
                 pub extensions: Option<Map<String, Value>>,
                 #[serde(default)]
+                #[serde(skip_serializing_if = "Option::is_none")]
                 ///Application-specific data.
                 pub extras: Option<serde_json::Value>,
                 ///A reference to the material associated with the given array of variants.
-                pub material: i64,
+                pub material: crate::Index<crate::generated::gltf::Material>,
                 #[serde(default)]
+                #[serde(skip_serializing_if = "Option::is_none")]
                 ///The optional user-defined name of this variant material mapping.  This is not necessarily unique.
                 pub name: Option<String>,
                 ///An array of index values that reference variants defined in the glTF root's extension object.
                 pub variants: Vec<i64>,
+                #[serde(flatten)]
+                ///Properties that are not part of the schema, kept so they survive a round trip.
+                pub unknown: Map<String, Value>,
             }
             impl crate::GltfObject for Mapping {
                 fn extensions(&self) -> &Option<Map<String, Value>> {
                     &self.extensions
                 }
             }
+            impl crate::validation::Validate for Mapping {
+                #[allow(unused_variables)]
+                fn validate_at(
+                    &self,
+                    pointer: &str,
+                    errors: &mut Vec<crate::validation::ValidationError>,
+                ) {}
+            }
         }
         pub use mapping::Mapping;
         #[derive(Serialize, Deserialize, Debug)]
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             ///An array of object values that associate an indexed material to a set of variants.
             pub mappings: Vec<Mapping>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -100,6 +156,23 @@ mod mesh_primitive {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "mappings");
+                    let value = &self.mappings;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
     }
     pub use extension::Extension;
 }
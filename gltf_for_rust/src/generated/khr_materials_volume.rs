@@ -1,33 +1,43 @@
 #![allow(clippy::all, unused_imports)]
-mod material {
-    mod extension {
+pub use material::Extension as MaterialExtension;
+pub mod material {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that defines the parameters for the volume of a material.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "attenuationColor")]
-            #[serde(default)]
+            #[serde(default = "default_attenuation_color")]
+            #[serde(skip_serializing_if = "is_default_attenuation_color")]
             ///Color that white light turns into due to absorption when reaching the attenuation distance.
-            pub attenuation_color: Option<[f64; 3usize]>,
+            pub attenuation_color: [f64; 3usize],
             #[serde(rename = "attenuationDistance")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Density of the medium given as the average distance that light travels in the medium before interacting with a particle. The value is given in world space. When undefined, the value is assumed to be infinite.
             pub attenuation_distance: Option<f64>,
             #[serde(rename = "thicknessFactor")]
-            #[serde(default)]
+            #[serde(default = "default_thickness_factor")]
+            #[serde(skip_serializing_if = "is_default_thickness_factor")]
             ///The thickness of the volume beneath the surface. The value is given in the coordinate space of the mesh. A value greater than 0 turns the mesh into a volume with a homogeneous medium, enabling refraction, absorption and subsurface scattering. The actual value may be ignored by renderers that are able to derive the thickness from the mesh (ray tracer).
-            pub thickness_factor: Option<f64>,
+            pub thickness_factor: f64,
             #[serde(rename = "thicknessTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///A texture that defines the thickness of the volume, stored in the G channel. Will be multiplied by thicknessFactor.
             pub thickness_texture: Option<crate::generated::gltf::TextureInfo>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -39,6 +49,75 @@ mod material {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "attenuationColor");
+                    let value = &self.attenuation_color;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::check_number(
+                            pointer,
+                            *value as f64,
+                            Some(0.0),
+                            None,
+                            Some(1.0),
+                            errors,
+                        );
+                    }
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "attenuationDistance");
+                    if let Some(value) = &self.attenuation_distance {
+                        crate::validation::check_number(
+                            pointer,
+                            *value as f64,
+                            None,
+                            Some(0.0),
+                            None,
+                            errors,
+                        );
+                    }
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "thicknessFactor");
+                    let value = &self.thickness_factor;
+                    crate::validation::check_number(
+                        pointer,
+                        *value as f64,
+                        Some(0.0),
+                        None,
+                        None,
+                        errors,
+                    );
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "thicknessTexture");
+                    if let Some(value) = &self.thickness_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
+        ///The default value of `attenuationColor`.
+        pub fn default_attenuation_color() -> [f64; 3usize] {
+            [1.0, 1.0, 1.0]
+        }
+        fn is_default_attenuation_color(value: &[f64; 3usize]) -> bool {
+            *value == default_attenuation_color()
+        }
+        ///The default value of `thicknessFactor`.
+        pub fn default_thickness_factor() -> f64 {
+            0.0
+        }
+        fn is_default_thickness_factor(value: &f64) -> bool {
+            *value == default_thickness_factor()
+        }
     }
     pub use extension::Extension;
 }
@@ -1,30 +1,40 @@
 #![allow(clippy::all, unused_imports)]
-mod texture_info {
-    mod extension {
+pub use texture_info::Extension as TextureInfoExtension;
+pub mod texture_info {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that enables shifting and scaling UV coordinates on a per-texture basis
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
-            #[serde(default)]
+            #[serde(default = "default_offset")]
+            #[serde(skip_serializing_if = "is_default_offset")]
             ///The offset of the UV coordinate origin as a factor of the texture dimensions.
-            pub offset: Option<[f64; 2usize]>,
-            #[serde(default)]
+            pub offset: [f64; 2usize],
+            #[serde(default = "default_rotation")]
+            #[serde(skip_serializing_if = "is_default_rotation")]
             ///Rotate the UVs by this many radians counter-clockwise around the origin.
-            pub rotation: Option<f64>,
-            #[serde(default)]
+            pub rotation: f64,
+            #[serde(default = "default_scale")]
+            #[serde(skip_serializing_if = "is_default_scale")]
             ///The scale factor applied to the components of the UV coordinates.
-            pub scale: Option<[f64; 2usize]>,
+            pub scale: [f64; 2usize],
             #[serde(rename = "texCoord")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Overrides the textureInfo texCoord value if supplied, and if this extension is supported.
             pub tex_coord: Option<i64>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -36,6 +46,35 @@ mod texture_info {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
+        ///The default value of `offset`.
+        pub fn default_offset() -> [f64; 2usize] {
+            [0.0, 0.0]
+        }
+        fn is_default_offset(value: &[f64; 2usize]) -> bool {
+            *value == default_offset()
+        }
+        ///The default value of `rotation`.
+        pub fn default_rotation() -> f64 {
+            0.0
+        }
+        fn is_default_rotation(value: &f64) -> bool {
+            *value == default_rotation()
+        }
+        ///The default value of `scale`.
+        pub fn default_scale() -> [f64; 2usize] {
+            [1.0, 1.0]
+        }
+        fn is_default_scale(value: &[f64; 2usize]) -> bool {
+            *value == default_scale()
+        }
     }
     pub use extension::Extension;
 }
@@ -1,9 +1,10 @@
 #![allow(clippy::all, unused_imports)]
-mod gltf {
-    mod extension {
+pub use gltf::Extension as RootExtension;
+pub mod gltf {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
-        mod packet {
+        pub mod packet {
             use serde::{Serialize, Deserialize};
             use serde_json::{Map, Value};
             #[derive(Serialize, Deserialize, Debug)]
@@ -12,6 +13,17 @@ mod gltf {
                 #[serde(rename = "@context")]
                 ///Dictionary mapping XMP namespace names to the URI where they are defined
                 pub context: Map<String, Value>,
+                #[serde(flatten)]
+                ///Properties that are not part of the schema, kept so they survive a round trip.
+                pub unknown: Map<String, Value>,
+            }
+            impl crate::validation::Validate for Packet {
+                #[allow(unused_variables)]
+                fn validate_at(
+                    &self,
+                    pointer: &str,
+                    errors: &mut Vec<crate::validation::ValidationError>,
+                ) {}
             }
         }
         pub use packet::Packet;
@@ -19,12 +31,17 @@ mod gltf {
         ///Metadata about the glTF asset.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             pub packets: Vec<Packet>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -36,6 +53,23 @@ mod gltf {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "packets");
+                    let value = &self.packets;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
     }
     pub use extension::Extension;
 }
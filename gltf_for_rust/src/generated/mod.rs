@@ -26,9 +26,9 @@ pub mod agi_stk_metadata;
 pub mod cesium_primitive_outline;
 pub mod ext_lights_ies;
 pub mod ext_lights_image_based;
-pub mod ext_meshopt_compression;
 pub mod ext_mesh_gpu_instancing;
 pub mod ext_mesh_manifold;
+pub mod ext_meshopt_compression;
 pub mod ext_texture_webp;
 pub mod fb_geometry_metadata;
 pub mod griffel_bim_data;
@@ -46,3 +46,211 @@ pub mod msft_packing_normal_roughness_metallic;
 pub mod msft_packing_occlusion_roughness_metallic;
 pub mod msft_texture_dds;
 pub mod nv_materials_mdl;
+/// Groups the modules generated for each extension schema.
+pub mod extensions {
+    pub use super::khr_animation_pointer;
+    pub use super::khr_draco_mesh_compression;
+    pub use super::khr_lights_punctual;
+    pub use super::khr_materials_anisotropy;
+    pub use super::khr_materials_clearcoat;
+    pub use super::khr_materials_dispersion;
+    pub use super::khr_materials_emissive_strength;
+    pub use super::khr_materials_ior;
+    pub use super::khr_materials_iridescence;
+    pub use super::khr_materials_sheen;
+    pub use super::khr_materials_specular;
+    pub use super::khr_materials_transmission;
+    pub use super::khr_materials_unlit;
+    pub use super::khr_materials_variants;
+    pub use super::khr_materials_volume;
+    pub use super::khr_mesh_quantization;
+    pub use super::khr_texture_basisu;
+    pub use super::khr_texture_transform;
+    pub use super::khr_xmp_json_ld;
+    pub use super::adobe_materials_clearcoat_specular;
+    pub use super::adobe_materials_clearcoat_tint;
+    pub use super::adobe_materials_thin_transparency;
+    pub use super::agi_articulations;
+    pub use super::agi_stk_metadata;
+    pub use super::cesium_primitive_outline;
+    pub use super::ext_lights_ies;
+    pub use super::ext_lights_image_based;
+    pub use super::ext_mesh_gpu_instancing;
+    pub use super::ext_mesh_manifold;
+    pub use super::ext_meshopt_compression;
+    pub use super::ext_texture_webp;
+    pub use super::fb_geometry_metadata;
+    pub use super::griffel_bim_data;
+    pub use super::mpeg_accessor_timed;
+    pub use super::mpeg_animation_timing;
+    pub use super::mpeg_audio_spatial;
+    pub use super::mpeg_buffer_circular;
+    pub use super::mpeg_media;
+    pub use super::mpeg_mesh_linking;
+    pub use super::mpeg_scene_dynamic;
+    pub use super::mpeg_texture_video;
+    pub use super::mpeg_viewport_recommended;
+    pub use super::msft_lod;
+    pub use super::msft_packing_normal_roughness_metallic;
+    pub use super::msft_packing_occlusion_roughness_metallic;
+    pub use super::msft_texture_dds;
+    pub use super::nv_materials_mdl;
+}
+/// Parses an extension of an object into the type generated for it. The object is named by
+/// its module in the extension modules, e.g. `node` or `mesh_primitive`. Returns None if no
+/// type is generated for the extension on that object.
+pub(crate) fn parse_extension(
+    object: &str,
+    extension: &str,
+    value: &serde_json::Value,
+) -> Option<Result<Box<dyn std::any::Any + Send + Sync>, serde_json::Error>> {
+    fn parse<T: crate::GltfExtension + Send + Sync + 'static>(
+        value: &serde_json::Value,
+    ) -> Result<Box<dyn std::any::Any + Send + Sync>, serde_json::Error> {
+        T::deserialize(value).map(|extension| Box::new(extension) as _)
+    }
+    Some(
+        match (object, extension) {
+            ("animation_channel_target", "KHR_animation_pointer") => {
+                parse::<
+                    khr_animation_pointer::animation_channel_target::Extension,
+                >(value)
+            }
+            ("mesh_primitive", "KHR_draco_mesh_compression") => {
+                parse::<khr_draco_mesh_compression::mesh_primitive::Extension>(value)
+            }
+            ("gltf", "KHR_lights_punctual") => {
+                parse::<khr_lights_punctual::gltf::Extension>(value)
+            }
+            ("node", "KHR_lights_punctual") => {
+                parse::<khr_lights_punctual::node::Extension>(value)
+            }
+            ("material", "KHR_materials_anisotropy") => {
+                parse::<khr_materials_anisotropy::material::Extension>(value)
+            }
+            ("material", "KHR_materials_clearcoat") => {
+                parse::<khr_materials_clearcoat::material::Extension>(value)
+            }
+            ("material", "KHR_materials_dispersion") => {
+                parse::<khr_materials_dispersion::material::Extension>(value)
+            }
+            ("material", "KHR_materials_emissive_strength") => {
+                parse::<khr_materials_emissive_strength::material::Extension>(value)
+            }
+            ("material", "KHR_materials_ior") => {
+                parse::<khr_materials_ior::material::Extension>(value)
+            }
+            ("material", "KHR_materials_iridescence") => {
+                parse::<khr_materials_iridescence::material::Extension>(value)
+            }
+            ("material", "KHR_materials_sheen") => {
+                parse::<khr_materials_sheen::material::Extension>(value)
+            }
+            ("material", "KHR_materials_specular") => {
+                parse::<khr_materials_specular::material::Extension>(value)
+            }
+            ("material", "KHR_materials_transmission") => {
+                parse::<khr_materials_transmission::material::Extension>(value)
+            }
+            ("material", "KHR_materials_unlit") => {
+                parse::<khr_materials_unlit::material::Extension>(value)
+            }
+            ("gltf", "KHR_materials_variants") => {
+                parse::<khr_materials_variants::gltf::Extension>(value)
+            }
+            ("mesh_primitive", "KHR_materials_variants") => {
+                parse::<khr_materials_variants::mesh_primitive::Extension>(value)
+            }
+            ("material", "KHR_materials_volume") => {
+                parse::<khr_materials_volume::material::Extension>(value)
+            }
+            ("texture", "KHR_texture_basisu") => {
+                parse::<khr_texture_basisu::texture::Extension>(value)
+            }
+            ("texture_info", "KHR_texture_transform") => {
+                parse::<khr_texture_transform::texture_info::Extension>(value)
+            }
+            ("gltf", "KHR_xmp_json_ld") => {
+                parse::<khr_xmp_json_ld::gltf::Extension>(value)
+            }
+            ("material", "ADOBE_materials_clearcoat_specular") => {
+                parse::<adobe_materials_clearcoat_specular::material::Extension>(value)
+            }
+            ("material", "ADOBE_materials_clearcoat_tint") => {
+                parse::<adobe_materials_clearcoat_tint::material::Extension>(value)
+            }
+            ("material", "ADOBE_materials_thin_transparency") => {
+                parse::<adobe_materials_thin_transparency::material::Extension>(value)
+            }
+            ("gltf", "AGI_articulations") => {
+                parse::<agi_articulations::gltf::Extension>(value)
+            }
+            ("node", "AGI_articulations") => {
+                parse::<agi_articulations::node::Extension>(value)
+            }
+            ("gltf", "AGI_stk_metadata") => {
+                parse::<agi_stk_metadata::gltf::Extension>(value)
+            }
+            ("node", "AGI_stk_metadata") => {
+                parse::<agi_stk_metadata::node::Extension>(value)
+            }
+            ("mesh_primitive", "CESIUM_primitive_outline") => {
+                parse::<cesium_primitive_outline::mesh_primitive::Extension>(value)
+            }
+            ("gltf", "EXT_lights_ies") => parse::<ext_lights_ies::gltf::Extension>(value),
+            ("node", "EXT_lights_ies") => parse::<ext_lights_ies::node::Extension>(value),
+            ("gltf", "EXT_lights_image_based") => {
+                parse::<ext_lights_image_based::gltf::Extension>(value)
+            }
+            ("scene", "EXT_lights_image_based") => {
+                parse::<ext_lights_image_based::scene::Extension>(value)
+            }
+            ("node", "EXT_mesh_gpu_instancing") => {
+                parse::<ext_mesh_gpu_instancing::node::Extension>(value)
+            }
+            ("mesh", "EXT_mesh_manifold") => {
+                parse::<ext_mesh_manifold::mesh::Extension>(value)
+            }
+            ("buffer", "EXT_meshopt_compression") => {
+                parse::<ext_meshopt_compression::buffer::Extension>(value)
+            }
+            ("buffer_view", "EXT_meshopt_compression") => {
+                parse::<ext_meshopt_compression::buffer_view::Extension>(value)
+            }
+            ("texture", "EXT_texture_webp") => {
+                parse::<ext_texture_webp::texture::Extension>(value)
+            }
+            ("scene", "FB_geometry_metadata") => {
+                parse::<fb_geometry_metadata::scene::Extension>(value)
+            }
+            ("gltf", "GRIFFEL_bim_data") => {
+                parse::<griffel_bim_data::gltf::Extension>(value)
+            }
+            ("node", "GRIFFEL_bim_data") => {
+                parse::<griffel_bim_data::node::Extension>(value)
+            }
+            ("material", "MSFT_lod") => parse::<msft_lod::material::Extension>(value),
+            ("node", "MSFT_lod") => parse::<msft_lod::node::Extension>(value),
+            ("material", "MSFT_packing_normalRoughnessMetallic") => {
+                parse::<
+                    msft_packing_normal_roughness_metallic::material::Extension,
+                >(value)
+            }
+            ("material", "MSFT_packing_occlusionRoughnessMetallic") => {
+                parse::<
+                    msft_packing_occlusion_roughness_metallic::material::Extension,
+                >(value)
+            }
+            ("texture", "MSFT_texture_dds") => {
+                parse::<msft_texture_dds::texture::Extension>(value)
+            }
+            ("gltf", "NV_materials_mdl") => {
+                parse::<nv_materials_mdl::gltf::Extension>(value)
+            }
+            ("material", "NV_materials_mdl") => {
+                parse::<nv_materials_mdl::material::Extension>(value)
+            }
+            _ => return None,
+        },
+    )
+}
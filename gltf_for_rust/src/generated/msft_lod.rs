@@ -1,20 +1,28 @@
 #![allow(clippy::all, unused_imports)]
-mod gltf {
-    mod extension {
+pub use material::Extension as MaterialExtension;
+pub use node::Extension as NodeExtension;
+pub mod material {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension for specifying levels of detail (LOD).
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Vec::is_empty")]
             ///Array containing the indices of progressively lower LOD nodes.
-            pub ids: Vec<i64>,
+            pub ids: Vec<crate::Index<crate::generated::gltf::Material>>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -26,6 +34,58 @@ mod gltf {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
+    }
+    pub use extension::Extension;
+}
+pub mod node {
+    pub mod extension {
+        use serde::{Serialize, Deserialize};
+        use serde_json::{Map, Value};
+        #[derive(Serialize, Deserialize, Debug)]
+        ///glTF extension for specifying levels of detail (LOD).
+        pub struct Extension {
+            #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            ///JSON object with extension-specific objects.
+            pub extensions: Option<Map<String, Value>>,
+            #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            ///Application-specific data.
+            pub extras: Option<serde_json::Value>,
+            #[serde(default)]
+            #[serde(skip_serializing_if = "Vec::is_empty")]
+            ///Array containing the indices of progressively lower LOD nodes.
+            pub ids: Vec<crate::Index<crate::generated::gltf::Node>>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
+        }
+        impl crate::GltfExtension for Extension {
+            fn extension_name() -> &'static str {
+                "MSFT_lod"
+            }
+        }
+        impl crate::GltfObject for Extension {
+            fn extensions(&self) -> &Option<Map<String, Value>> {
+                &self.extensions
+            }
+        }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
     }
     pub use extension::Extension;
 }
@@ -1,17 +1,30 @@
 #![allow(clippy::all, unused_imports)]
-pub mod gltf {
-    mod extension {
-        use serde::{Deserialize, Serialize};
+pub use material::Extension as MaterialExtension;
+pub mod material {
+    pub mod extension {
+        use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
-        mod normal_roughness_metallic_texture {
-            use serde::{Deserialize, Serialize};
+        pub mod normal_roughness_metallic_texture {
+            use serde::{Serialize, Deserialize};
             use serde_json::{Map, Value};
             #[derive(Serialize, Deserialize, Debug)]
             ///A texture with the packing Normal (RG), Roughness (B), Metallic (A).
             pub struct NormalRoughnessMetallicTexture {
                 #[serde(default)]
+                #[serde(skip_serializing_if = "Option::is_none")]
                 ///The index of the texture.
                 pub index: Option<i64>,
+                #[serde(flatten)]
+                ///Properties that are not part of the schema, kept so they survive a round trip.
+                pub unknown: Map<String, Value>,
+            }
+            impl crate::validation::Validate for NormalRoughnessMetallicTexture {
+                #[allow(unused_variables)]
+                fn validate_at(
+                    &self,
+                    pointer: &str,
+                    errors: &mut Vec<crate::validation::ValidationError>,
+                ) {}
             }
         }
         pub use normal_roughness_metallic_texture::NormalRoughnessMetallicTexture;
@@ -19,26 +32,51 @@ pub mod gltf {
         ///glTF extension the specifies a packing of normal, roughness and metallic in a single texture.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "normalRoughnessMetallicTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///A texture with the packing Normal (RG), Roughness (B), Metallic (A).
-            pub normal_roughness_metallic_texture: Option<NormalRoughnessMetallicTexture>,
+            pub normal_roughness_metallic_texture: Option<
+                NormalRoughnessMetallicTexture,
+            >,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
+        }
+        impl crate::GltfExtension for Extension {
+            fn extension_name() -> &'static str {
+                "MSFT_packing_normalRoughnessMetallic"
+            }
         }
         impl crate::GltfObject for Extension {
             fn extensions(&self) -> &Option<Map<String, Value>> {
                 &self.extensions
             }
         }
-    }
-    pub use extension::Extension;
-    impl crate::GltfExtension for Extension {
-        fn extension_name() -> &'static str {
-            "MSFT_packing_normalRoughnessMetallic"
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!(
+                        "{}/{}", pointer, "normalRoughnessMetallicTexture"
+                    );
+                    if let Some(value) = &self.normal_roughness_metallic_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
         }
     }
+    pub use extension::Extension;
 }
@@ -1,41 +1,78 @@
 #![allow(clippy::all, unused_imports)]
-pub mod gltf {
-    mod extension {
-        use serde::{Deserialize, Serialize};
+pub use material::Extension as MaterialExtension;
+pub mod material {
+    pub mod extension {
+        use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
-        mod normal_texture {
-            use serde::{Deserialize, Serialize};
+        pub mod normal_texture {
+            use serde::{Serialize, Deserialize};
             use serde_json::{Map, Value};
             #[derive(Serialize, Deserialize, Debug)]
             ///A texture which contains two channel (RG) normal map.
             pub struct NormalTexture {
                 #[serde(default)]
+                #[serde(skip_serializing_if = "Option::is_none")]
                 ///The index of the texture.
                 pub index: Option<i64>,
+                #[serde(flatten)]
+                ///Properties that are not part of the schema, kept so they survive a round trip.
+                pub unknown: Map<String, Value>,
+            }
+            impl crate::validation::Validate for NormalTexture {
+                #[allow(unused_variables)]
+                fn validate_at(
+                    &self,
+                    pointer: &str,
+                    errors: &mut Vec<crate::validation::ValidationError>,
+                ) {}
             }
         }
         pub use normal_texture::NormalTexture;
-        mod occlusion_roughness_metallic_texture {
-            use serde::{Deserialize, Serialize};
+        pub mod occlusion_roughness_metallic_texture {
+            use serde::{Serialize, Deserialize};
             use serde_json::{Map, Value};
             #[derive(Serialize, Deserialize, Debug)]
             ///A texture with packing Occlusion (R), Roughness (G), Metallic (B).
             pub struct OcclusionRoughnessMetallicTexture {
                 #[serde(default)]
+                #[serde(skip_serializing_if = "Option::is_none")]
                 ///The index of the texture.
                 pub index: Option<i64>,
+                #[serde(flatten)]
+                ///Properties that are not part of the schema, kept so they survive a round trip.
+                pub unknown: Map<String, Value>,
+            }
+            impl crate::validation::Validate for OcclusionRoughnessMetallicTexture {
+                #[allow(unused_variables)]
+                fn validate_at(
+                    &self,
+                    pointer: &str,
+                    errors: &mut Vec<crate::validation::ValidationError>,
+                ) {}
             }
         }
         pub use occlusion_roughness_metallic_texture::OcclusionRoughnessMetallicTexture;
-        mod roughness_metallic_occlusion_texture {
-            use serde::{Deserialize, Serialize};
+        pub mod roughness_metallic_occlusion_texture {
+            use serde::{Serialize, Deserialize};
             use serde_json::{Map, Value};
             #[derive(Serialize, Deserialize, Debug)]
             ///A texture with packing Roughness (R), Metallic (G), Occlusion (B).
             pub struct RoughnessMetallicOcclusionTexture {
                 #[serde(default)]
+                #[serde(skip_serializing_if = "Option::is_none")]
                 ///The index of the texture.
                 pub index: Option<i64>,
+                #[serde(flatten)]
+                ///Properties that are not part of the schema, kept so they survive a round trip.
+                pub unknown: Map<String, Value>,
+            }
+            impl crate::validation::Validate for RoughnessMetallicOcclusionTexture {
+                #[allow(unused_variables)]
+                fn validate_at(
+                    &self,
+                    pointer: &str,
+                    errors: &mut Vec<crate::validation::ValidationError>,
+                ) {}
             }
         }
         pub use roughness_metallic_occlusion_texture::RoughnessMetallicOcclusionTexture;
@@ -43,34 +80,77 @@ pub mod gltf {
         ///glTF extension the specifies a packing of occlusion, roughness and metallic in a single texture and a two channel normal map.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "normalTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///A texture which contains two channel (RG) normal map.
             pub normal_texture: Option<NormalTexture>,
             #[serde(rename = "occlusionRoughnessMetallicTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///A texture with packing Occlusion (R), Roughness (G), Metallic (B).
-            pub occlusion_roughness_metallic_texture: Option<OcclusionRoughnessMetallicTexture>,
+            pub occlusion_roughness_metallic_texture: Option<
+                OcclusionRoughnessMetallicTexture,
+            >,
             #[serde(rename = "roughnessMetallicOcclusionTexture")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///A texture with packing Roughness (R), Metallic (G), Occlusion (B).
-            pub roughness_metallic_occlusion_texture: Option<RoughnessMetallicOcclusionTexture>,
+            pub roughness_metallic_occlusion_texture: Option<
+                RoughnessMetallicOcclusionTexture,
+            >,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
+        }
+        impl crate::GltfExtension for Extension {
+            fn extension_name() -> &'static str {
+                "MSFT_packing_occlusionRoughnessMetallic"
+            }
         }
         impl crate::GltfObject for Extension {
             fn extensions(&self) -> &Option<Map<String, Value>> {
                 &self.extensions
             }
         }
-    }
-    pub use extension::Extension;
-    impl crate::GltfExtension for Extension {
-        fn extension_name() -> &'static str {
-            "MSFT_packing_occlusionRoughnessMetallic"
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "normalTexture");
+                    if let Some(value) = &self.normal_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+                {
+                    let pointer = &format!(
+                        "{}/{}", pointer, "occlusionRoughnessMetallicTexture"
+                    );
+                    if let Some(value) = &self.occlusion_roughness_metallic_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+                {
+                    let pointer = &format!(
+                        "{}/{}", pointer, "roughnessMetallicOcclusionTexture"
+                    );
+                    if let Some(value) = &self.roughness_metallic_occlusion_texture {
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
         }
     }
+    pub use extension::Extension;
 }
@@ -1,30 +1,40 @@
 #![allow(clippy::all, unused_imports)]
-mod gltf {
-    mod extension {
+pub use gltf::Extension as RootExtension;
+pub use material::Extension as MaterialExtension;
+pub mod gltf {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that enables using MDL materials.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "bsdfMeasurements")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Vec::is_empty")]
             ///The list of all BSDF measurements.
             pub bsdf_measurements: Vec<
                 crate::generated::nv_materials_mdl::BsdfMeasurement,
             >,
             #[serde(rename = "functionCalls")]
             #[serde(default)]
+            #[serde(skip_serializing_if = "Vec::is_empty")]
             ///The list of all function calls.
             pub function_calls: Vec<crate::generated::nv_materials_mdl::FunctionCall>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Vec::is_empty")]
             ///The list of all MDL modules.
             pub modules: Vec<crate::generated::nv_materials_mdl::Module>,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -36,25 +46,63 @@ mod gltf {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {
+                {
+                    let pointer = &format!("{}/{}", pointer, "bsdfMeasurements");
+                    let value = &self.bsdf_measurements;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "functionCalls");
+                    let value = &self.function_calls;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+                {
+                    let pointer = &format!("{}/{}", pointer, "modules");
+                    let value = &self.modules;
+                    for (index, value) in value.iter().enumerate() {
+                        let pointer = &format!("{}/{}", pointer, index);
+                        crate::validation::Validate::validate_at(value, pointer, errors);
+                    }
+                }
+            }
+        }
     }
     pub use extension::Extension;
 }
-mod material {
-    mod extension {
+pub mod material {
+    pub mod extension {
         use serde::{Serialize, Deserialize};
         use serde_json::{Map, Value};
         #[derive(Serialize, Deserialize, Debug)]
         ///glTF extension that enables using MDL materials.
         pub struct Extension {
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///JSON object with extension-specific objects.
             pub extensions: Option<Map<String, Value>>,
             #[serde(default)]
+            #[serde(skip_serializing_if = "Option::is_none")]
             ///Application-specific data.
             pub extras: Option<serde_json::Value>,
             #[serde(rename = "functionCall")]
             ///The index of the MDL function call. The reference function call **MUST** represent the entry point to a function call graph and have the return type `material`.
             pub function_call: i64,
+            #[serde(flatten)]
+            ///Properties that are not part of the schema, kept so they survive a round trip.
+            pub unknown: Map<String, Value>,
         }
         impl crate::GltfExtension for Extension {
             fn extension_name() -> &'static str {
@@ -66,121 +114,220 @@ mod material {
                 &self.extensions
             }
         }
+        impl crate::validation::Validate for Extension {
+            #[allow(unused_variables)]
+            fn validate_at(
+                &self,
+                pointer: &str,
+                errors: &mut Vec<crate::validation::ValidationError>,
+            ) {}
+        }
     }
     pub use extension::Extension;
 }
-mod bsdf_measurement {
+pub mod bsdf_measurement {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    #[serde(from = "String", into = "String")]
     pub enum MimeType {
-        #[serde(rename = "application/vnd.mdl-mbsdf")]
         ApplicationVndMdlMbsdf,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(String),
+    }
+    impl From<String> for MimeType {
+        fn from(value: String) -> Self {
+            match value.as_str() {
+                "application/vnd.mdl-mbsdf" => MimeType::ApplicationVndMdlMbsdf,
+                _ => MimeType::Other(value),
+            }
+        }
+    }
+    impl From<MimeType> for String {
+        fn from(value: MimeType) -> Self {
+            match value {
+                MimeType::ApplicationVndMdlMbsdf => {
+                    String::from("application/vnd.mdl-mbsdf")
+                }
+                MimeType::Other(other) => other,
+            }
+        }
     }
     #[derive(Serialize, Deserialize, Debug)]
     ///A BSDF measurement (MBSDF) as defined in the MDL Language Specification.
     pub struct BsdfMeasurement {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         #[serde(rename = "bufferView")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The ID of the bufferView containing the MBSDF.  This field **MUST NOT** be defined if `uri` is defined.
         pub buffer_view: Option<i64>,
         #[serde(rename = "mimeType")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The BSDF measurement's media type.  This field **MUST** be defined when `bufferView` is defined.
         pub mime_type: Option<MimeType>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The URI (or IRI) of the MBSDF.  Relative paths are relative to the current glTF asset.  Instead of referencing an external file, this field **MAY** contain a `data:`-URI. This field **MUST NOT** be defined when `bufferView` is defined.
         pub uri: Option<String>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for BsdfMeasurement {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for BsdfMeasurement {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
 }
 pub use bsdf_measurement::BsdfMeasurement;
-mod function_call_argument {
+pub mod function_call_argument {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///Named function call argument. Can be another function call or a constant value.
     pub struct FunctionCallArgument {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(rename = "functionCall")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The ID of a function call.  This field **MUST NOT** be defined if `value` is defined.
         pub function_call: Option<i64>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The name of the named argument.
         pub name: Option<String>,
         #[serde(rename = "type")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The type of the value argument.  This field **MUST** be defined if `value` is defined and **MUST NOT** be defined if `functionCall` is defined.
         pub ty: Option<crate::generated::nv_materials_mdl::FunctionCallType>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The literal value of the value argument.  This field **MUST NOT** be defined if `functionCall` is defined.
         pub value: Option<serde_json::Value>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for FunctionCallArgument {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for FunctionCallArgument {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "type");
+                if let Some(value) = &self.ty {
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+        }
+    }
 }
 pub use function_call_argument::FunctionCallArgument;
-mod function_call {
+pub mod function_call {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
     #[derive(Serialize, Deserialize, Debug)]
     ///Function call with its list of arguments. Can represent the entry point into a function call graph or be a node in such a graph.
     pub struct FunctionCall {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         ///A list of named value and/or function call arguments.  Multiple arguments with the same name **MUST NOT** exist.
         pub arguments: Vec<crate::generated::nv_materials_mdl::FunctionCallArgument>,
         #[serde(rename = "functionName")]
         ///The unqualified name of the function.
         pub function_name: String,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The ID of the containing module.  This field **MUST NOT** be defined if a built-in function is specified.
         pub module: Option<i64>,
         #[serde(rename = "type")]
         ///The return type of the function.
         pub ty: crate::generated::nv_materials_mdl::FunctionCallType,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for FunctionCall {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for FunctionCall {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {
+            {
+                let pointer = &format!("{}/{}", pointer, "arguments");
+                let value = &self.arguments;
+                for (index, value) in value.iter().enumerate() {
+                    let pointer = &format!("{}/{}", pointer, index);
+                    crate::validation::Validate::validate_at(value, pointer, errors);
+                }
+            }
+            {
+                let pointer = &format!("{}/{}", pointer, "type");
+                let value = &self.ty;
+                crate::validation::Validate::validate_at(value, pointer, errors);
+            }
+        }
+    }
 }
 pub use function_call::FunctionCall;
-mod function_call_type {
+pub mod function_call_type {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
     pub enum Modifier {
         #[serde(rename = "varying")]
         Varying,
@@ -191,72 +338,124 @@ mod function_call_type {
     ///MDL type describing either a built-in or user-defined type, or an array of a built-in or user-defined type.
     pub struct FunctionCallType {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(rename = "arraySize")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The array size. If this field is defined the type is considered to be a array.
         pub array_size: Option<i64>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The name of the type modifier.
         pub modifier: Option<Modifier>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The ID of the containing module.  This field **MUST NOT** be defined if a built-in type is specified.
         pub module: Option<i64>,
         #[serde(rename = "typeName")]
         ///The unqualified name of the type.
         pub type_name: String,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for FunctionCallType {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for FunctionCallType {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
 }
 pub use function_call_type::FunctionCallType;
-mod module {
+pub mod module {
     use serde::{Serialize, Deserialize};
     use serde_json::{Map, Value};
-    #[derive(Serialize, Deserialize, Debug)]
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    #[serde(from = "String", into = "String")]
     pub enum MimeType {
-        #[serde(rename = "application/vnd.mdl")]
         ApplicationVndMdl,
+        ///A value that is not defined by the specification, e.g. one introduced by an extension.
+        Other(String),
+    }
+    impl From<String> for MimeType {
+        fn from(value: String) -> Self {
+            match value.as_str() {
+                "application/vnd.mdl" => MimeType::ApplicationVndMdl,
+                _ => MimeType::Other(value),
+            }
+        }
+    }
+    impl From<MimeType> for String {
+        fn from(value: MimeType) -> Self {
+            match value {
+                MimeType::ApplicationVndMdl => String::from("application/vnd.mdl"),
+                MimeType::Other(other) => other,
+            }
+        }
     }
     #[derive(Serialize, Deserialize, Debug)]
     ///An MDL module.
     pub struct Module {
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///JSON object with extension-specific objects.
         pub extensions: Option<Map<String, Value>>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Application-specific data.
         pub extras: Option<serde_json::Value>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The user-defined name of this object.  This is not necessarily unique, e.g., an accessor and a buffer could have the same name, or two accessors could even have the same name.
         pub name: Option<String>,
         #[serde(rename = "bufferView")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The ID of the bufferView containing the MDL module.  This field **MUST NOT** be defined if `uri` is defined.
         pub buffer_view: Option<i64>,
         #[serde(rename = "mimeType")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The MDL module's media type.  This field **MUST** be defined when `bufferView` is defined.
         pub mime_type: Option<MimeType>,
         #[serde(rename = "modulePath")]
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///Relative path of the module.  This field **MUST** be defined if `bufferView` is defined or `uri` is defined and contains a data-URI, otherwise this field **MUST NOT** be defined.
         pub module_path: Option<String>,
         #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
         ///The URI (or IRI) of the MDL module.  Relative paths are relative to the current glTF asset.  Instead of referencing an external file, this field **MAY** contain a `data:`-URI. This field **MUST NOT** be defined when `bufferView` is defined.
         pub uri: Option<String>,
+        #[serde(flatten)]
+        ///Properties that are not part of the schema, kept so they survive a round trip.
+        pub unknown: Map<String, Value>,
     }
     impl crate::GltfObject for Module {
         fn extensions(&self) -> &Option<Map<String, Value>> {
             &self.extensions
         }
     }
+    impl crate::validation::Validate for Module {
+        #[allow(unused_variables)]
+        fn validate_at(
+            &self,
+            pointer: &str,
+            errors: &mut Vec<crate::validation::ValidationError>,
+        ) {}
+    }
 }
 pub use module::Module;
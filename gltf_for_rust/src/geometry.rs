@@ -0,0 +1,622 @@
+//! Generation of the normals and tangents that a primitive omits, as the specification requires
+//! of clients: flat normals when `NORMAL` is missing, and tangents derived from the texture
+//! coordinates when `TANGENT` is missing but the material uses a normal texture.
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::accessor::{self, AccessorError, ComponentType, Element};
+use crate::import::{Accessor, Gltf, Primitive};
+use crate::semantic::Semantic;
+use crate::topology::{Topology, TopologyError};
+use crate::Index;
+
+use crate::generated::gltf::accessor::Type;
+use crate::generated::gltf::buffer_view::Target;
+use crate::generated::gltf::mesh_primitive::Mode;
+use crate::generated::gltf::Accessor as InnerAccessor;
+use crate::generated::gltf::BufferView as InnerBufferView;
+
+#[derive(Error, Debug)]
+pub enum GeometryError {
+    #[error("Attribute {0} is missing")]
+    MissingAttribute(Semantic),
+    #[error("Attribute {semantic} can not be read: {source}")]
+    Attribute {
+        semantic: Semantic,
+        source: AccessorError,
+    },
+    #[error("Attribute {semantic} of morph target {target} can not be read: {source}")]
+    TargetAttribute {
+        target: usize,
+        semantic: Semantic,
+        source: AccessorError,
+    },
+    #[error(
+        "Attribute {semantic} has {actual} elements but the primitive has {expected} vertices"
+    )]
+    CountMismatch {
+        semantic: Semantic,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Attribute {0} is not a scalar or vector and can not be copied")]
+    UnsupportedAttribute(Semantic),
+    #[error("Topology can not be read: {0}")]
+    Topology(TopologyError),
+    #[error("Primitive does not consist of triangles")]
+    NotTriangles,
+    #[error("Vertex {vertex} is out of range for a primitive of {vertices} vertices")]
+    VertexOutOfRange { vertex: u32, vertices: usize },
+    #[error("Primitive {primitive} of mesh {mesh} does not exist")]
+    MissingPrimitive { mesh: usize, primitive: usize },
+}
+
+/// Vertices generated for the triangles of a primitive. A vertex of the primitive is split when
+/// the triangles sharing it need different normals or tangents, so every generated vertex refers
+/// to the vertex its other attributes are copied from.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Generated {
+    /// The index of the mesh whose primitive the vertices were generated for.
+    pub mesh: usize,
+    /// The index of the primitive within its mesh.
+    pub primitive: usize,
+    /// The vertex of the primitive that each generated vertex is copied from.
+    pub sources: Vec<u32>,
+    /// The generated vertices of each triangle, in the winding order of the primitive.
+    pub triangles: Vec<[u32; 3]>,
+    /// The normal of each vertex, either read from the primitive or flat.
+    pub normals: Vec<[f32; 3]>,
+    /// The tangent of each vertex with its handedness in w, empty if none were generated.
+    pub tangents: Vec<[f32; 4]>,
+}
+
+/// Generates the normals and tangents of a primitive. Normals are read from the primitive, or
+/// computed flat if it has none. Tangents are generated with the texture coordinates of the normal
+/// texture of its material, if it has one and the primitive has no tangents. Tangents of a
+/// primitive without normals are ignored, as the specification requires.
+pub fn generate(primitive: &Primitive) -> Result<Generated, GeometryError> {
+    let triangles = match Topology::read(primitive).map_err(GeometryError::Topology)? {
+        Topology::Triangles(triangles) => triangles,
+        Topology::Points(_) | Topology::Lines(_) => return Err(GeometryError::NotTriangles),
+    };
+    let positions = primitive
+        .positions()
+        .ok_or(GeometryError::MissingAttribute(Semantic::Position))?;
+    let vertices = positions.count();
+    let positions: Vec<[f32; 3]> = read(positions, Semantic::Position, vertices)?;
+    if let Some(&vertex) = triangles
+        .iter()
+        .flatten()
+        .find(|&&v| v as usize >= vertices)
+    {
+        return Err(GeometryError::VertexOutOfRange { vertex, vertices });
+    }
+
+    let normals: Vec<[f32; 3]> = match primitive.normals() {
+        Some(normals) => {
+            let normals: Vec<[f32; 3]> = read(normals, Semantic::Normal, vertices)?;
+            let corners = triangles.iter().flatten();
+            corners.map(|&vertex| normals[vertex as usize]).collect()
+        }
+        None => flat_normals(&positions, &triangles)
+            .into_iter()
+            .flat_map(|normal| [normal; 3])
+            .collect(),
+    };
+
+    let normal_texture = primitive
+        .material()
+        .and_then(|material| material.normal_texture());
+    let provided_tangents = primitive.normals().and(primitive.tangents());
+    let tangents = match (normal_texture, provided_tangents) {
+        (Some(texture), None) => {
+            let set = texture.tex_coord() as u32;
+            let tex_coords = primitive
+                .tex_coords(set)
+                .ok_or(GeometryError::MissingAttribute(Semantic::TexCoord(set)))?;
+            let tex_coords = read(tex_coords, Semantic::TexCoord(set), vertices)?;
+            tangents(&positions, &tex_coords, &normals, &triangles)
+        }
+        _ => Vec::new(),
+    };
+    Ok(Generated {
+        mesh: primitive.mesh().index(),
+        primitive: primitive.index(),
+        ..weld(&triangles, normals, tangents)
+    })
+}
+
+/// Computes the normal of each triangle from its winding order. Triangles without area, whose
+/// normal is undefined, face +Z.
+pub fn flat_normals(positions: &[[f32; 3]], triangles: &[[u32; 3]]) -> Vec<[f32; 3]> {
+    triangles
+        .iter()
+        .map(|triangle| {
+            let [a, b, c] = triangle.map(|vertex| positions[vertex as usize]);
+            let normal = normalize(cross(sub(b, a), sub(c, a)));
+            match normal == [0.0; 3] {
+                true => [0.0, 0.0, 1.0],
+                false => normal,
+            }
+        })
+        .collect()
+}
+
+/// Generates tangents from the texture coordinates of the triangles. Normals and tangents are
+/// indexed by corner, i.e. corner `i` of triangle `t` is `3 * t + i`.
+///
+/// Corners with equal position, normal and texture coordinates share a tangent, which averages
+/// the tangents of their triangles weighted by the angle at the corner. Triangles with mirrored
+/// texture coordinates are averaged separately and get a handedness of -1. Triangles whose texture
+/// coordinates have no area contribute nothing, and corners without any contribution get an
+/// arbitrary tangent perpendicular to their normal.
+///
+/// This is a simplification of MikkTSpace, the algorithm most tools baking normal maps use, and
+/// does not reproduce its output exactly.
+pub fn tangents(
+    positions: &[[f32; 3]],
+    tex_coords: &[[f32; 2]],
+    normals: &[[f32; 3]],
+    triangles: &[[u32; 3]],
+) -> Vec<[f32; 4]> {
+    let mut groups: HashMap<_, usize> = HashMap::new();
+    let mut sums: Vec<[f32; 3]> = Vec::new();
+    let mut corners = Vec::with_capacity(triangles.len() * 3);
+    for (face, triangle) in triangles.iter().enumerate() {
+        let p = triangle.map(|vertex| positions[vertex as usize]);
+        let t = triangle.map(|vertex| tex_coords[vertex as usize]);
+        let (d1, d2) = (sub(p[1], p[0]), sub(p[2], p[0]));
+        let (s1, t1) = (t[1][0] - t[0][0], t[1][1] - t[0][1]);
+        let (s2, t2) = (t[2][0] - t[0][0], t[2][1] - t[0][1]);
+        // Twice the signed area of the triangle in texture space
+        let area = s1 * t2 - t1 * s2;
+        let preserving = area >= 0.0;
+        let tangent = normalize(sub(scale(d1, t2), scale(d2, t1)));
+        let tangent = match preserving {
+            true => tangent,
+            false => scale(tangent, -1.0),
+        };
+
+        for corner in 0..3 {
+            let normal = normals[face * 3 + corner];
+            let vertex = triangle[corner] as usize;
+            let key = (
+                positions[vertex].map(f32::to_bits),
+                normal.map(f32::to_bits),
+                tex_coords[vertex].map(f32::to_bits),
+                preserving,
+            );
+            let group = *groups.entry(key).or_insert_with(|| {
+                sums.push([0.0; 3]);
+                sums.len() - 1
+            });
+            corners.push((group, preserving, normal));
+            if area == 0.0 {
+                continue;
+            }
+
+            let project = |v: [f32; 3]| normalize(sub(v, scale(normal, dot(normal, v))));
+            let previous = project(sub(p[(corner + 2) % 3], p[corner]));
+            let next = project(sub(p[(corner + 1) % 3], p[corner]));
+            let angle = dot(previous, next).clamp(-1.0, 1.0).acos();
+            let sum = &mut sums[group];
+            *sum = add(*sum, scale(project(tangent), angle));
+        }
+    }
+
+    corners
+        .into_iter()
+        .map(|(group, preserving, normal)| {
+            let tangent = match normalize(sums[group]) {
+                [0.0, 0.0, 0.0] => perpendicular(normal),
+                tangent => tangent,
+            };
+            let handedness = if preserving { 1.0 } else { -1.0 };
+            [tangent[0], tangent[1], tangent[2], handedness]
+        })
+        .collect()
+}
+
+/// Merges the corners of the triangles that share a vertex of the primitive and have the same
+/// normal and tangent into a single vertex.
+fn weld(triangles: &[[u32; 3]], normals: Vec<[f32; 3]>, tangents: Vec<[f32; 4]>) -> Generated {
+    let mut generated = Generated::default();
+    let mut vertices = HashMap::new();
+    for (face, triangle) in triangles.iter().enumerate() {
+        generated.triangles.push(std::array::from_fn(|corner| {
+            let index = face * 3 + corner;
+            let tangent = tangents.get(index).copied();
+            let key = (
+                triangle[corner],
+                normals[index].map(f32::to_bits),
+                tangent.map(|tangent| tangent.map(f32::to_bits)),
+            );
+            *vertices.entry(key).or_insert_with(|| {
+                generated.sources.push(triangle[corner]);
+                generated.normals.push(normals[index]);
+                generated.tangents.extend(tangent);
+                generated.sources.len() as u32 - 1
+            })
+        }));
+    }
+    generated
+}
+
+impl Generated {
+    /// Replaces the primitive the vertices were generated for with them. Its attributes and morph
+    /// targets are copied for every generated vertex into a new buffer, which is embedded when the
+    /// document is exported, and the primitive becomes an indexed list of triangles. The accessors
+    /// previously used by the primitive are left in the document.
+    pub fn write(&self, gltf: &mut Gltf) -> Result<(), GeometryError> {
+        let (mesh, primitive) = (self.mesh, self.primitive);
+        let missing = GeometryError::MissingPrimitive { mesh, primitive };
+        let mut writer = Writer::default();
+        let (attributes, targets, indices) = {
+            let primitive = gltf
+                .mesh(mesh)
+                .and_then(|mesh| mesh.primitives().nth(primitive))
+                .ok_or(missing)?;
+            let vertices = primitive
+                .positions()
+                .ok_or(GeometryError::MissingAttribute(Semantic::Position))?
+                .count();
+            if let Some(&vertex) = self.sources.iter().find(|&&v| v as usize >= vertices) {
+                return Err(GeometryError::VertexOutOfRange { vertex, vertices });
+            }
+            // Provided tangents are replaced, and ignored without provided normals
+            let keep_tangents = self.tangents.is_empty() && primitive.normals().is_some();
+
+            let mut attributes = Vec::new();
+            for (semantic, accessor) in primitive.attributes() {
+                match semantic {
+                    Semantic::Normal => continue,
+                    Semantic::Tangent if !keep_tangents => continue,
+                    _ => {}
+                }
+                let written = writer
+                    .gather(&accessor, &semantic, &self.sources, vertices)
+                    .map_err(|error| error.into_attribute_error(semantic.clone()))?;
+                attributes.push((semantic, written));
+            }
+            let normals = self.normals.as_flattened().iter().map(|&v| v as f64);
+            let written = writer.push(
+                Type::Vec3,
+                ComponentType::Float,
+                false,
+                normals.collect(),
+                Some(Target::ArrayBuffer),
+                false,
+            );
+            attributes.push((Semantic::Normal, written));
+            if !self.tangents.is_empty() {
+                let tangents = self.tangents.as_flattened().iter().map(|&v| v as f64);
+                let written = writer.push(
+                    Type::Vec4,
+                    ComponentType::Float,
+                    false,
+                    tangents.collect(),
+                    Some(Target::ArrayBuffer),
+                    false,
+                );
+                attributes.push((Semantic::Tangent, written));
+            }
+
+            let mut targets = Vec::new();
+            for target in primitive.targets() {
+                let mut attributes = Vec::new();
+                for (semantic, accessor) in target.attributes() {
+                    let written = writer
+                        .gather(&accessor, &semantic, &self.sources, vertices)
+                        .map_err(|error| {
+                            error.into_target_error(target.index(), semantic.clone())
+                        })?;
+                    attributes.push((semantic, written));
+                }
+                targets.push(attributes);
+            }
+
+            let component_type = match self.sources.len() <= u16::MAX as usize {
+                true => ComponentType::UnsignedShort,
+                false => ComponentType::UnsignedInt,
+            };
+            let indices = self.triangles.as_flattened().iter().map(|&v| v as f64);
+            let indices = writer.push(
+                Type::Scalar,
+                component_type,
+                false,
+                indices.collect(),
+                Some(Target::ElementArrayBuffer),
+                false,
+            );
+            (attributes, targets, indices)
+        };
+
+        let buffer = gltf.push_buffer(std::mem::take(&mut writer.data));
+        let document = gltf.document_mut();
+        let first_accessor = document.accessors.len();
+        for written in writer.accessors {
+            document.buffer_views.push(InnerBufferView {
+                extensions: None,
+                extras: None,
+                name: None,
+                buffer: Index::new(buffer as u32),
+                byte_length: written.byte_length as i64,
+                byte_offset: written.byte_offset as i64,
+                byte_stride: written.byte_stride.map(|stride| stride as i64),
+                target: written.target,
+                unknown: Default::default(),
+            });
+            document.accessors.push(InnerAccessor {
+                extensions: None,
+                extras: None,
+                name: None,
+                buffer_view: Some(Index::new(document.buffer_views.len() as u32 - 1)),
+                byte_offset: 0,
+                component_type: written.component_type,
+                count: written.count as i64,
+                max: written.max,
+                min: written.min,
+                normalized: written.normalized,
+                sparse: None,
+                ty: written.ty,
+                unknown: Default::default(),
+            });
+        }
+
+        let to_map = |attributes: Vec<(Semantic, usize)>| {
+            attributes
+                .into_iter()
+                .map(|(semantic, written)| {
+                    (semantic.to_string(), (first_accessor + written).into())
+                })
+                .collect()
+        };
+        let inner = &mut document.meshes[mesh].primitives[primitive];
+        inner.attributes = to_map(attributes);
+        inner.targets = targets.into_iter().map(to_map).collect();
+        inner.indices = Some(Index::new((first_accessor + indices) as u32));
+        inner.mode = Mode::Triangles;
+        Ok(())
+    }
+}
+
+/// An error gathering an attribute, before it is known whether the attribute belongs to a target.
+enum GatherError {
+    Accessor(AccessorError),
+    Unsupported,
+    CountMismatch { expected: usize, actual: usize },
+}
+
+impl GatherError {
+    fn into_attribute_error(self, semantic: Semantic) -> GeometryError {
+        match self {
+            GatherError::Accessor(source) => GeometryError::Attribute { semantic, source },
+            GatherError::Unsupported => GeometryError::UnsupportedAttribute(semantic),
+            GatherError::CountMismatch { expected, actual } => GeometryError::CountMismatch {
+                semantic,
+                expected,
+                actual,
+            },
+        }
+    }
+
+    fn into_target_error(self, target: usize, semantic: Semantic) -> GeometryError {
+        match self {
+            GatherError::Accessor(source) => GeometryError::TargetAttribute {
+                target,
+                semantic,
+                source,
+            },
+            error => error.into_attribute_error(semantic),
+        }
+    }
+}
+
+/// An accessor written into the new buffer, in a buffer view of its own.
+struct Written {
+    byte_offset: usize,
+    byte_length: usize,
+    byte_stride: Option<usize>,
+    target: Option<Target>,
+    ty: Type,
+    component_type: ComponentType,
+    normalized: bool,
+    count: usize,
+    min: Vec<f64>,
+    max: Vec<f64>,
+}
+
+/// Collects the data and accessors written back into a document.
+#[derive(Default)]
+struct Writer {
+    data: Vec<u8>,
+    accessors: Vec<Written>,
+}
+
+impl Writer {
+    /// Copies the elements of an accessor for every source vertex, with the same type, and
+    /// returns the index of the written accessor.
+    fn gather(
+        &mut self,
+        accessor: &Accessor,
+        semantic: &Semantic,
+        sources: &[u32],
+        vertices: usize,
+    ) -> Result<usize, GatherError> {
+        if accessor.count() != vertices {
+            return Err(GatherError::CountMismatch {
+                expected: vertices,
+                actual: accessor.count(),
+            });
+        }
+        let ty = accessor.ty();
+        let (components, values) = match ty {
+            Type::Scalar => (1, read_components::<1>(accessor)),
+            Type::Vec2 => (2, read_components::<2>(accessor)),
+            Type::Vec3 => (3, read_components::<3>(accessor)),
+            Type::Vec4 => (4, read_components::<4>(accessor)),
+            _ => return Err(GatherError::Unsupported),
+        };
+        let values = values.map_err(GatherError::Accessor)?;
+        let gathered = sources.iter().flat_map(|&source| {
+            let start = source as usize * components;
+            values[start..start + components].iter().copied()
+        });
+        Ok(self.push(
+            ty.clone(),
+            accessor.component_type(),
+            accessor.normalized(),
+            gathered.collect(),
+            Some(Target::ArrayBuffer),
+            *semantic == Semantic::Position,
+        ))
+    }
+
+    /// Encodes elements into the buffer and returns the index of their accessor. Elements of
+    /// vertex attributes are padded to 4 bytes, as the specification requires.
+    fn push(
+        &mut self,
+        ty: Type,
+        component_type: ComponentType,
+        normalized: bool,
+        values: Vec<f64>,
+        target: Option<Target>,
+        bounds: bool,
+    ) -> usize {
+        let components = match ty {
+            Type::Vec2 => 2,
+            Type::Vec3 => 3,
+            Type::Vec4 => 4,
+            _ => 1,
+        };
+        let size = components * accessor::component_size(component_type).unwrap_or(4);
+        let stride = match target {
+            Some(Target::ArrayBuffer) => size.next_multiple_of(4),
+            _ => size,
+        };
+
+        self.data.resize(self.data.len().next_multiple_of(4), 0);
+        let byte_offset = self.data.len();
+        for element in values.chunks(components) {
+            for &value in element {
+                encode(component_type, value, &mut self.data);
+            }
+            self.data.resize(self.data.len() + stride - size, 0);
+        }
+
+        let (mut min, mut max) = (Vec::new(), Vec::new());
+        if bounds && !values.is_empty() {
+            min = vec![f64::INFINITY; components];
+            max = vec![f64::NEG_INFINITY; components];
+            for element in values.chunks(components) {
+                for (i, &value) in element.iter().enumerate() {
+                    min[i] = min[i].min(value);
+                    max[i] = max[i].max(value);
+                }
+            }
+        }
+
+        self.accessors.push(Written {
+            byte_offset,
+            byte_length: self.data.len() - byte_offset,
+            byte_stride: (stride != size).then_some(stride),
+            target,
+            ty,
+            component_type,
+            normalized,
+            count: values.len() / components,
+            min,
+            max,
+        });
+        self.accessors.len() - 1
+    }
+}
+
+/// Reads the components of every element as they are stored, ignoring `normalized`.
+fn read_components<const N: usize>(accessor: &Accessor) -> Result<Vec<f64>, AccessorError> {
+    Ok(match accessor.component_type() {
+        ComponentType::Float => flatten(accessor.read_unnormalized::<[f32; N]>()?),
+        ComponentType::Byte | ComponentType::Short => {
+            flatten(accessor.read_unnormalized::<[i16; N]>()?)
+        }
+        _ => flatten(accessor.read_unnormalized::<[u32; N]>()?),
+    })
+}
+
+fn flatten<T: Into<f64> + accessor::Component, const N: usize>(
+    elements: accessor::Iter<[T; N]>,
+) -> Vec<f64> {
+    elements.flatten().map(Into::into).collect()
+}
+
+fn encode(component_type: ComponentType, value: f64, data: &mut Vec<u8>) {
+    match component_type {
+        ComponentType::Byte => data.push(value as i8 as u8),
+        ComponentType::UnsignedByte => data.push(value as u8),
+        ComponentType::Short => data.extend((value as i16).to_le_bytes()),
+        ComponentType::UnsignedShort => data.extend((value as u16).to_le_bytes()),
+        ComponentType::UnsignedInt => data.extend((value as u32).to_le_bytes()),
+        ComponentType::Float | ComponentType::Other(_) => data.extend((value as f32).to_le_bytes()),
+    }
+}
+
+fn read<T: Element>(
+    accessor: Accessor,
+    semantic: Semantic,
+    vertices: usize,
+) -> Result<Vec<T>, GeometryError> {
+    if accessor.count() != vertices {
+        return Err(GeometryError::CountMismatch {
+            semantic,
+            expected: vertices,
+            actual: accessor.count(),
+        });
+    }
+    let elements = accessor
+        .read::<T>()
+        .map_err(|source| GeometryError::Attribute { semantic, source })?;
+    Ok(elements.collect())
+}
+
+fn add(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    std::array::from_fn(|i| a[i] + b[i])
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    std::array::from_fn(|i| a[i] - b[i])
+}
+
+fn scale(v: [f32; 3], factor: f32) -> [f32; 3] {
+    v.map(|value| value * factor)
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn normalize(v: [f32; 3]) -> [f32; 3] {
+    let length = dot(v, v).sqrt();
+    if length == 0.0 {
+        return v;
+    }
+    scale(v, 1.0 / length)
+}
+
+/// A unit vector perpendicular to a normal, crossed with the axis it is least aligned with.
+fn perpendicular(normal: [f32; 3]) -> [f32; 3] {
+    let axis = match normal[0].abs() < 0.9 {
+        true => [1.0, 0.0, 0.0],
+        false => [0.0, 1.0, 0.0],
+    };
+    normalize(cross(axis, normal))
+}
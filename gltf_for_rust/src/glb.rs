@@ -0,0 +1,119 @@
+//! Parsing of the binary glTF (GLB) container format.
+
+use crate::export::ExportError;
+use crate::import::ImportError;
+
+const MAGIC: &[u8; 4] = b"glTF";
+const VERSION: u32 = 2;
+const HEADER_LENGTH: usize = 12;
+const CHUNK_HEADER_LENGTH: usize = 8;
+const CHUNK_TYPE_JSON: u32 = 0x4E4F534A;
+const CHUNK_TYPE_BIN: u32 = 0x004E4942;
+
+/// Returns true if the data starts with the GLB magic, and should be read as a binary container.
+pub(crate) fn is_glb(data: &[u8]) -> bool {
+    data.starts_with(MAGIC)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+/// The chunks contained in a binary glTF container.
+pub(crate) struct Glb<'a> {
+    /// The contents of the JSON chunk, including any trailing padding.
+    pub json: &'a [u8],
+    /// The contents of the BIN chunk, including any trailing padding.
+    pub bin: Option<&'a [u8]>,
+}
+
+impl<'a> Glb<'a> {
+    pub fn parse(data: &'a [u8]) -> Result<Self, ImportError> {
+        if data.len() < HEADER_LENGTH || !is_glb(data) {
+            return Err(ImportError::GlbInvalidHeader);
+        }
+
+        let version = read_u32(data, 4);
+        if version != VERSION {
+            return Err(ImportError::GlbUnsupportedVersion(version));
+        }
+
+        let length = read_u32(data, 8) as usize;
+        if length != data.len() {
+            return Err(ImportError::GlbLengthMismatch {
+                header: length,
+                actual: data.len(),
+            });
+        }
+
+        let mut json = None;
+        let mut bin = None;
+        let mut offset = HEADER_LENGTH;
+        let mut chunk_index = 0;
+        while offset < length {
+            if length - offset < CHUNK_HEADER_LENGTH {
+                return Err(ImportError::GlbTruncatedChunk { offset });
+            }
+
+            let chunk_length = read_u32(data, offset) as usize;
+            let chunk_type = read_u32(data, offset + 4);
+            if !chunk_length.is_multiple_of(4) {
+                return Err(ImportError::GlbMisalignedChunk { offset });
+            }
+
+            let start = offset + CHUNK_HEADER_LENGTH;
+            if length - start < chunk_length {
+                return Err(ImportError::GlbTruncatedChunk { offset });
+            }
+            let contents = &data[start..start + chunk_length];
+
+            // The JSON chunk must come first and an optional BIN chunk must directly follow it.
+            // Chunks of any other type must be ignored.
+            match (chunk_index, chunk_type) {
+                (0, CHUNK_TYPE_JSON) => json = Some(contents),
+                (0, _) => return Err(ImportError::GlbMissingJsonChunk),
+                (1, CHUNK_TYPE_BIN) => bin = Some(contents),
+                (_, CHUNK_TYPE_JSON | CHUNK_TYPE_BIN) => {
+                    return Err(ImportError::GlbUnexpectedChunk { offset, chunk_type })
+                }
+                _ => (),
+            }
+
+            offset = start + chunk_length;
+            chunk_index += 1;
+        }
+
+        Ok(Glb {
+            json: json.ok_or(ImportError::GlbMissingJsonChunk)?,
+            bin,
+        })
+    }
+}
+
+/// Writes a binary glTF container, padding the JSON chunk with spaces and the BIN chunk with zeros.
+pub(crate) fn write(json: &[u8], bin: Option<&[u8]>) -> Result<Vec<u8>, ExportError> {
+    let chunks = [
+        Some((CHUNK_TYPE_JSON, json, b' ')),
+        bin.map(|bin| (CHUNK_TYPE_BIN, bin, 0)),
+    ];
+    let length = HEADER_LENGTH
+        + chunks
+            .iter()
+            .flatten()
+            .map(|(_, contents, _)| CHUNK_HEADER_LENGTH + contents.len().next_multiple_of(4))
+            .sum::<usize>();
+    let length = u32::try_from(length).map_err(|_| ExportError::GlbTooLarge(length))?;
+
+    let mut data = Vec::with_capacity(length as usize);
+    data.extend_from_slice(MAGIC);
+    data.extend_from_slice(&VERSION.to_le_bytes());
+    data.extend_from_slice(&length.to_le_bytes());
+    for (chunk_type, contents, padding) in chunks.into_iter().flatten() {
+        let padded_length = contents.len().next_multiple_of(4);
+        data.extend_from_slice(&(padded_length as u32).to_le_bytes());
+        data.extend_from_slice(&chunk_type.to_le_bytes());
+        data.extend_from_slice(contents);
+        data.resize(data.len() + padded_length - contents.len(), padding);
+    }
+    Ok(data)
+}
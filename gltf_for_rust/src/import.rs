@@ -1,23 +1,66 @@
-use std::fs::File;
-use std::io::BufReader;
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
 
+use serde_json::{Map, Value};
 use thiserror::Error;
 
+use crate::accessor::{self, AccessorError, ComponentType, Element, Layout};
+use crate::extension::{ExtensionError, Extensions, Object, TextureSlot};
+use crate::glb::{self, Glb};
+use crate::semantic::Semantic;
+use crate::transform::{self, Matrix, Transform};
+use crate::uri::{self, FileSystemResolver, Resolver};
+use crate::validation::{self, ValidationError};
+use crate::{Get, GltfExtension, Index};
+
+use crate::generated::gltf::accessor::Type;
+use crate::generated::gltf::animation_channel_target::Path as TargetPath;
+use crate::generated::gltf::animation_sampler::Interpolation;
+use crate::generated::gltf::buffer_view::Target;
+use crate::generated::gltf::camera::Type as CameraType;
+use crate::generated::gltf::image::MimeType;
+use crate::generated::gltf::material::AlphaMode;
+use crate::generated::gltf::material_pbr_metallic_roughness::{
+    default_base_color_factor, default_metallic_factor, default_roughness_factor,
+};
+use crate::generated::gltf::mesh_primitive::Mode;
+use crate::generated::gltf::node::default_matrix;
+use crate::generated::gltf::sampler::{MagFilter, MinFilter, WrapS, WrapT};
+use crate::generated::gltf::Accessor as InnerAccessor;
+use crate::generated::gltf::Animation as InnerAnimation;
+use crate::generated::gltf::AnimationChannel as InnerAnimationChannel;
+use crate::generated::gltf::AnimationSampler as InnerAnimationSampler;
+use crate::generated::gltf::Buffer as InnerBuffer;
+use crate::generated::gltf::BufferView as InnerBufferView;
+use crate::generated::gltf::Camera as InnerCamera;
 use crate::generated::gltf::Gltf as InnerGltf;
+use crate::generated::gltf::Image as InnerImage;
+use crate::generated::gltf::Material as InnerMaterial;
+use crate::generated::gltf::MaterialNormalTextureInfo as InnerNormalTextureInfo;
+use crate::generated::gltf::MaterialOcclusionTextureInfo as InnerOcclusionTextureInfo;
+use crate::generated::gltf::MaterialPbrMetallicRoughness as InnerPbrMetallicRoughness;
+use crate::generated::gltf::Mesh as InnerMesh;
+use crate::generated::gltf::MeshPrimitive as InnerPrimitive;
 use crate::generated::gltf::Node as InnerNode;
+use crate::generated::gltf::Sampler as InnerSampler;
 use crate::generated::gltf::Scene as InnerScene;
+use crate::generated::gltf::Skin as InnerSkin;
+use crate::generated::gltf::Texture as InnerTexture;
+use crate::generated::gltf::TextureInfo as InnerTextureInfo;
+use crate::generated::gltf::{CameraOrthographic, CameraPerspective};
 
 #[macro_export]
 macro_rules! collection_wrapper {
     ($name:ident, $inner_item:ident, $wrapper_item:ident) => {
         /// Iterates over a collection on the gltf root, and returns
         pub struct $name<'a> {
-            document: &'a InnerGltf,
+            document: &'a Gltf,
             inner: std::iter::Enumerate<std::slice::Iter<'a, $inner_item>>,
         }
 
         impl<'a> $name<'a> {
-            fn new(document: &'a InnerGltf, input: &'a [$inner_item]) -> Self {
+            fn new(document: &'a Gltf, input: &'a [$inner_item]) -> Self {
                 $name {
                     document,
                     inner: input.iter().enumerate(),
@@ -35,31 +78,121 @@ macro_rules! collection_wrapper {
                     inner,
                 })
             }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
         }
 
         impl<'a> ExactSizeIterator for $name<'a> {}
     };
 }
 
-collection_wrapper!(Nodes, InnerNode, Node);
-collection_wrapper!(Scenes, InnerScene, Scene);
+/// Declares a wrapper around an object stored in a collection. The wrapper borrows the document
+/// so that references to other objects can be followed to their wrappers.
+macro_rules! object_wrapper {
+    ($(#[$attribute:meta])* $name:ident, $inner_item:ident) => {
+        $(#[$attribute])*
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name<'a> {
+            // Unused by wrappers of objects that do not refer to other objects
+            #[allow(dead_code)]
+            document: &'a Gltf,
+            index: usize,
+            inner: &'a $inner_item,
+        }
+
+        impl<'a> $name<'a> {
+            /// The position of this object in its collection.
+            pub fn index(&self) -> usize {
+                self.index
+            }
+
+            /// The deserialized JSON object, e.g. to read its extensions or extras.
+            pub fn inner(&self) -> &'a $inner_item {
+                self.inner
+            }
+        }
+    };
+}
+
+/// Declares the methods of [`Gltf`] that iterate over a collection on the gltf root and look up
+/// one of its objects by index. The wrappers of its objects look up their parsed extensions by
+/// the variant of [`Object`] named after them.
+macro_rules! root_collection {
+    (
+        $field:ident,
+        $single:ident,
+        $name:ident,
+        $inner_item:ident,
+        $wrapper_item:ident
+    ) => {
+        collection_wrapper!($name, $inner_item, $wrapper_item);
+
+        impl Gltf {
+            pub fn $field(&self) -> $name<'_> {
+                $name::new(self, &self.inner.$field)
+            }
+
+            /// Returns the object at an index of the collection, or None if it is out of range.
+            pub fn $single(&self, index: usize) -> Option<$wrapper_item<'_>> {
+                self.inner.$field.get(index).map(|inner| $wrapper_item {
+                    document: self,
+                    index,
+                    inner,
+                })
+            }
+        }
+
+        impl<'a> $wrapper_item<'a> {
+            /// Returns the extension of a type on this object, which was parsed when the document
+            /// was imported. Returns None if the object does not have the extension or it failed
+            /// to parse, see [`Gltf::extension_errors`].
+            pub fn extension<T: GltfExtension + 'static>(&self) -> Option<&'a T> {
+                let object = Object::$wrapper_item(self.index);
+                self.document.extensions.get(object)
+            }
+        }
+    };
+}
+
+root_collection!(accessors, accessor, Accessors, InnerAccessor, Accessor);
+root_collection!(animations, animation, Animations, InnerAnimation, Animation);
+root_collection!(buffers, buffer, Buffers, InnerBuffer, Buffer);
+root_collection!(
+    buffer_views,
+    buffer_view,
+    BufferViews,
+    InnerBufferView,
+    BufferView
+);
+root_collection!(cameras, camera, Cameras, InnerCamera, Camera);
+root_collection!(images, image, Images, InnerImage, Image);
+root_collection!(materials, material, Materials, InnerMaterial, Material);
+root_collection!(meshes, mesh, Meshes, InnerMesh, Mesh);
+root_collection!(nodes, node, Nodes, InnerNode, Node);
+root_collection!(samplers, sampler, Samplers, InnerSampler, Sampler);
+root_collection!(scenes, scene, Scenes, InnerScene, Scene);
+root_collection!(skins, skin, Skins, InnerSkin, Skin);
+root_collection!(textures, texture, Textures, InnerTexture, Texture);
+collection_wrapper!(MorphTargets, InnerMorphTarget, MorphTarget);
 
+/// Iterates over the nodes referred to by a list of indices. Indices that are out of range are skipped.
 pub struct NodeChildren<'a> {
-    document: &'a InnerGltf,
-    inner: std::slice::Iter<'a, i64>,
+    document: &'a Gltf,
+    inner: std::slice::Iter<'a, Index<InnerNode>>,
 }
 
 impl<'a> Iterator for NodeChildren<'a> {
     type Item = Node<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.inner.next().map(|index| {
-            let index = *index as usize;
-            Node {
+        self.inner.by_ref().find_map(|index| {
+            Some(Node {
                 document: self.document,
-                index,
-                inner: &self.document.nodes[index],
-            }
+                index: index.value(),
+                inner: self.document.inner.get(*index)?,
+            })
         })
     }
 }
@@ -67,77 +200,1262 @@ impl<'a> Iterator for NodeChildren<'a> {
 #[derive(Debug)]
 pub struct Gltf {
     inner: InnerGltf,
+    blob: Option<Vec<u8>>,
+    /// The contents loaded from the `uri` of each buffer, or None if the buffer has no `uri`.
+    buffers: Vec<Option<Vec<u8>>>,
+    /// The contents loaded from the `uri` of each image, or None if the image has no `uri`.
+    images: Vec<Option<Vec<u8>>>,
+    /// The world matrices of the nodes of each scene, computed on first use.
+    world_transforms: Vec<OnceLock<Vec<Option<Matrix>>>>,
+    /// The extensions of the objects of the document, parsed on import.
+    extensions: Extensions,
 }
 
 impl Gltf {
-    pub fn nodes(&self) -> Nodes {
-        Nodes::new(&self.inner, &self.inner.nodes)
+    /// Creates an asset from a document and the contents of its buffers, which are indexed like
+    /// the buffers of the document. Buffers without contents have no data.
+    pub(crate) fn from_document(inner: InnerGltf, mut buffers: Vec<Option<Vec<u8>>>) -> Self {
+        buffers.resize(inner.buffers.len(), None);
+        let images = vec![None; inner.images.len()];
+        let world_transforms = inner.scenes.iter().map(|_| OnceLock::new()).collect();
+        let extensions = Extensions::parse(&inner);
+        Gltf {
+            inner,
+            blob: None,
+            buffers,
+            images,
+            world_transforms,
+            extensions,
+        }
+    }
+
+    /// The deserialized JSON document.
+    pub fn document(&self) -> &InnerGltf {
+        &self.inner
+    }
+
+    /// Returns the object an index refers to, or None if the index is out of range.
+    pub fn get<T>(&self, index: Index<T>) -> Option<&T>
+    where
+        InnerGltf: Get<T>,
+    {
+        self.inner.get(index)
+    }
+
+    /// The binary chunk embedded in a GLB container, if the document was read from one.
+    pub fn blob(&self) -> Option<&[u8]> {
+        self.blob.as_deref()
+    }
+
+    /// Returns the contents of a buffer, truncated to its `byteLength`.
+    /// The first buffer of a GLB container refers to the binary chunk when it does not specify a `uri`.
+    /// Returns None when the buffer has no data, e.g. a fallback buffer of `EXT_meshopt_compression`.
+    pub fn buffer_data(&self, index: usize) -> Option<&[u8]> {
+        let buffer = self.inner.buffers.get(index)?;
+        self.buffer_contents(index)?
+            .get(..buffer.byte_length as usize)
+    }
+
+    /// Returns all bytes backing a buffer, which may include padding past its `byteLength`.
+    pub(crate) fn buffer_contents(&self, index: usize) -> Option<&[u8]> {
+        match (index, &self.buffers[index], &self.blob) {
+            (_, Some(data), _) => Some(data),
+            (0, None, Some(blob)) => Some(blob),
+            _ => None,
+        }
+    }
+
+    /// Returns the range of a buffer that is covered by a buffer view.
+    pub fn buffer_view_data(&self, index: usize) -> Option<&[u8]> {
+        let view = self.inner.buffer_views.get(index)?;
+        let offset = view.byte_offset as usize;
+        self.buffer_data(view.buffer.value())?
+            .get(offset..offset.checked_add(view.byte_length as usize)?)
+    }
+
+    fn accessor_view(&self, index: usize) -> Result<accessor::View<'_>, AccessorError> {
+        let view = self
+            .inner
+            .buffer_views
+            .get(index)
+            .ok_or(AccessorError::BufferViewUnavailable(index))?;
+        Ok(accessor::View {
+            data: self
+                .buffer_view_data(index)
+                .ok_or(AccessorError::BufferViewUnavailable(index))?,
+            offset: view.byte_offset as usize,
+            stride: view.byte_stride.map(|stride| stride as usize),
+        })
+    }
+
+    /// Returns the encoded contents of an image, either loaded from its `uri` or stored in a buffer view.
+    pub fn image_data(&self, index: usize) -> Option<&[u8]> {
+        let image = self.inner.images.get(index)?;
+        match (&self.images[index], image.buffer_view) {
+            (Some(data), _) => Some(data),
+            (None, Some(buffer_view)) => self.buffer_view_data(buffer_view.value()),
+            (None, None) => None,
+        }
     }
 
-    pub fn scenes(&self) -> Scenes {
-        Scenes::new(&self.inner, &self.inner.scenes)
+    /// The scene to display when the document is loaded, if it specifies one.
+    pub fn default_scene(&self) -> Option<Scene<'_>> {
+        self.scene(self.inner.scene?.value())
+    }
+
+    /// Returns the world matrix of every node in a scene, indexed by node. Nodes that are not part
+    /// of the scene have no matrix. The matrices are computed on the first call for each scene.
+    pub fn world_transforms(&self, scene: usize) -> Option<&[Option<Matrix>]> {
+        let transforms = self.world_transforms.get(scene)?.get_or_init(|| {
+            let mut transforms = vec![None; self.inner.nodes.len()];
+            for visit in self
+                .scene(scene)
+                .into_iter()
+                .flat_map(|scene| scene.traverse())
+            {
+                transforms[visit.node.index()] = Some(visit.world);
+            }
+            transforms
+        });
+        Some(transforms)
+    }
+
+    /// Returns an extension of the document, which was parsed when it was imported.
+    pub fn extension<T: GltfExtension + 'static>(&self) -> Option<&T> {
+        self.extensions.get(Object::Root)
+    }
+
+    /// Returns an extension of the object at a JSON pointer, e.g. `/materials/0/normalTexture`,
+    /// which was parsed when the document was imported. Returns None if the object does not have
+    /// the extension, no type is generated for it on that object or it failed to parse.
+    pub fn extension_at<T: GltfExtension + 'static>(&self, pointer: &str) -> Option<&T> {
+        self.extensions.get(Object::from_pointer(pointer)?)
+    }
+
+    /// The extensions that do not match the schema of their generated type, which were found when
+    /// the document was imported. They are missing from [`Gltf::extension`] and the `extension`
+    /// methods of the objects, but can still be read with [`crate::GltfObject::parse_extension`].
+    pub fn extension_errors(&self) -> &[ExtensionError] {
+        self.extensions.errors()
+    }
+
+    /// The extensions of the objects of the document, parsed when it was imported.
+    pub(crate) fn extensions(&self) -> &Extensions {
+        &self.extensions
+    }
+
+    /// The deserialized JSON document, for changes that do not affect the node hierarchy or the
+    /// extensions of objects.
+    pub(crate) fn document_mut(&mut self) -> &mut InnerGltf {
+        &mut self.inner
+    }
+
+    /// Appends a buffer without a `uri` that holds `data`, and returns its index. The buffer is
+    /// embedded when the document is exported.
+    pub(crate) fn push_buffer(&mut self, data: Vec<u8>) -> usize {
+        self.inner.buffers.push(InnerBuffer {
+            extensions: None,
+            extras: None,
+            name: None,
+            byte_length: data.len() as i64,
+            uri: None,
+            unknown: Map::new(),
+        });
+        self.buffers.push(Some(data));
+        self.inner.buffers.len() - 1
     }
 }
 
-#[derive(Debug)]
-pub struct Node<'a> {
-    document: &'a InnerGltf,
-    index: usize,
-    inner: &'a InnerNode,
+object_wrapper!(
+    /// A typed view into a buffer view, or sparse storage, that holds the data of e.g. a vertex attribute.
+    Accessor,
+    InnerAccessor
+);
+
+impl<'a> Accessor<'a> {
+    pub fn name(&self) -> Option<&'a str> {
+        self.inner.name.as_deref()
+    }
+    /// The number of elements referenced by this accessor.
+    pub fn count(&self) -> usize {
+        self.inner.count as usize
+    }
+    pub fn component_type(&self) -> ComponentType {
+        self.inner.component_type
+    }
+    pub fn ty(&self) -> &'a Type {
+        &self.inner.ty
+    }
+    pub fn normalized(&self) -> bool {
+        self.inner.normalized
+    }
+    /// The buffer view holding the elements, or None if they are all zero except for sparse values.
+    pub fn buffer_view(&self) -> Option<BufferView<'a>> {
+        self.document.buffer_view(self.inner.buffer_view?.value())
+    }
+    /// The offset of the first element relative to the start of the buffer view.
+    pub fn byte_offset(&self) -> usize {
+        self.inner.byte_offset as usize
+    }
+    /// The minimum value of each component, empty if the accessor does not specify bounds.
+    pub fn min(&self) -> &'a [f64] {
+        &self.inner.min
+    }
+    /// The maximum value of each component, empty if the accessor does not specify bounds.
+    pub fn max(&self) -> &'a [f64] {
+        &self.inner.max
+    }
+
+    /// Reads the elements of this accessor as `T`, e.g. `[f32; 3]` for a `VEC3` accessor.
+    /// An accessor without a buffer view reads as zeros, before any sparse values are substituted.
+    pub fn read<T: Element>(&self) -> Result<accessor::Iter<'a, T>, AccessorError> {
+        self.read_layout(Layout::new(self.inner)?)
+    }
+
+    /// Reads the elements as they are stored, ignoring `normalized`. The `min` and `max` of an
+    /// accessor refer to these values.
+    pub(crate) fn read_unnormalized<T: Element>(
+        &self,
+    ) -> Result<accessor::Iter<'a, T>, AccessorError> {
+        self.read_layout(Layout::new(self.inner)?.unnormalized())
+    }
+
+    fn read_layout<T: Element>(
+        &self,
+        layout: Layout,
+    ) -> Result<accessor::Iter<'a, T>, AccessorError> {
+        let view = match self.inner.buffer_view {
+            Some(index) => Some(self.document.accessor_view(index.value())?),
+            None => None,
+        };
+        let sparse = match &self.inner.sparse {
+            Some(sparse) => Some(accessor::Sparse {
+                count: sparse.count as usize,
+                indices: self
+                    .document
+                    .accessor_view(sparse.indices.buffer_view.value())?,
+                indices_byte_offset: sparse.indices.byte_offset as usize,
+                // The sparse indices use a subset of the accessor component types
+                indices_component_type: i64::from(sparse.indices.component_type).into(),
+                values: self
+                    .document
+                    .accessor_view(sparse.values.buffer_view.value())?,
+                values_byte_offset: sparse.values.byte_offset as usize,
+            }),
+            None => None,
+        };
+        accessor::Iter::new(
+            layout,
+            view,
+            self.inner.byte_offset as usize,
+            self.count(),
+            sparse,
+        )
+    }
+
+    /// Returns true if this accessor substitutes some of its elements through sparse storage.
+    pub fn is_sparse(&self) -> bool {
+        self.inner.sparse.is_some()
+    }
 }
 
+object_wrapper!(
+    /// A node in the hierarchy of a scene, which may place a mesh or camera.
+    Node,
+    InnerNode
+);
+
 impl<'a> Node<'a> {
-    pub fn index(&self) -> usize {
-        self.index
-    }
     pub fn name(&self) -> Option<&'a str> {
-        self.inner.name.as_ref().map(|name| name.as_str())
+        self.inner.name.as_deref()
     }
-    pub fn children(&self) -> NodeChildren {
+    pub fn children(&self) -> NodeChildren<'a> {
         NodeChildren {
             document: self.document,
             inner: self.inner.children.iter(),
         }
     }
+    pub fn camera(&self) -> Option<Camera<'a>> {
+        self.document.camera(self.inner.camera?.value())
+    }
+    pub fn mesh(&self) -> Option<Mesh<'a>> {
+        self.document.mesh(self.inner.mesh?.value())
+    }
+    /// The skin that deforms the mesh of this node.
+    pub fn skin(&self) -> Option<Skin<'a>> {
+        self.document.skin(self.inner.skin?.value())
+    }
+    /// The weights of the morph targets of the mesh, overriding the weights of the mesh when not empty.
+    pub fn weights(&self) -> &'a [f64] {
+        &self.inner.weights
+    }
+
+    /// The transform of this node relative to its parent, decomposed from its `matrix` if it has one.
+    pub fn transform(&self) -> Transform {
+        match self.has_matrix() {
+            true => Transform::from_matrix(&self.matrix()),
+            false => Transform {
+                translation: self.inner.translation.map(|value| value as f32),
+                rotation: self.inner.rotation.map(|value| value as f32),
+                scale: self.inner.scale.map(|value| value as f32),
+            },
+        }
+    }
+
+    /// The transform of this node relative to its parent as a matrix, composed from its
+    /// translation, rotation and scale if it has no `matrix`.
+    pub fn matrix(&self) -> Matrix {
+        match self.has_matrix() {
+            true => self.inner.matrix.map(|value| value as f32),
+            false => self.transform().matrix(),
+        }
+    }
+
+    fn has_matrix(&self) -> bool {
+        self.inner.matrix != default_matrix()
+    }
 }
 
-pub struct Scene<'a> {
-    document: &'a InnerGltf,
-    index: usize,
-    inner: &'a InnerScene,
+/// A node visited while traversing a scene.
+#[derive(Debug)]
+pub struct SceneNode<'a> {
+    pub node: Node<'a>,
+    /// The index of the parent node, or None for the root nodes of the scene.
+    pub parent: Option<usize>,
+    /// The transform from the node to the scene, including the transforms of its ancestors.
+    pub world: Matrix,
+}
+
+/// A node left to visit while traversing a scene, with the index and world matrix of its parent.
+type PendingNode = (Index<InnerNode>, Option<(usize, Matrix)>);
+
+/// Visits the nodes of a scene depth first, each node before its children. Nodes that are out of
+/// range or were visited before, which only happens in invalid documents, are skipped.
+pub struct Traverse<'a> {
+    document: &'a Gltf,
+    stack: Vec<PendingNode>,
+    visited: Vec<bool>,
 }
 
+impl<'a> Iterator for Traverse<'a> {
+    type Item = SceneNode<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (index, parent) = self.stack.pop()?;
+            let Some(inner) = self.document.inner.get(index) else {
+                continue;
+            };
+            if std::mem::replace(&mut self.visited[index.value()], true) {
+                continue;
+            }
+
+            let node = Node {
+                document: self.document,
+                index: index.value(),
+                inner,
+            };
+            let world = match &parent {
+                Some((_, parent_world)) => transform::multiply(parent_world, &node.matrix()),
+                None => node.matrix(),
+            };
+            let children = inner.children.iter().rev();
+            let parent_of_children = Some((node.index, world));
+            self.stack
+                .extend(children.map(|child| (*child, parent_of_children)));
+
+            return Some(SceneNode {
+                node,
+                parent: parent.map(|(parent, _)| parent),
+                world,
+            });
+        }
+    }
+}
+
+object_wrapper!(
+    /// A set of root nodes to render.
+    Scene,
+    InnerScene
+);
+
 impl<'a> Scene<'a> {
     pub fn name(&self) -> Option<&'a str> {
-        self.inner.name.as_ref().map(|name| name.as_str())
+        self.inner.name.as_deref()
+    }
+    pub fn nodes(&self) -> NodeChildren<'a> {
+        NodeChildren {
+            document: self.document,
+            inner: self.inner.nodes.iter(),
+        }
+    }
+
+    /// Visits the nodes of this scene with their world matrix, each node before its children.
+    pub fn traverse(&self) -> Traverse<'a> {
+        Traverse {
+            document: self.document,
+            stack: self
+                .inner
+                .nodes
+                .iter()
+                .rev()
+                .map(|node| (*node, None))
+                .collect(),
+            visited: vec![false; self.document.inner.nodes.len()],
+        }
+    }
+}
+
+object_wrapper!(
+    /// A block of binary data, loaded from its `uri` or the binary chunk of a GLB container.
+    Buffer,
+    InnerBuffer
+);
+
+impl<'a> Buffer<'a> {
+    pub fn name(&self) -> Option<&'a str> {
+        self.inner.name.as_deref()
     }
+    pub fn byte_length(&self) -> usize {
+        self.inner.byte_length as usize
+    }
+    pub fn uri(&self) -> Option<&'a str> {
+        self.inner.uri.as_deref()
+    }
+    /// The contents of the buffer, see [`Gltf::buffer_data`].
+    pub fn data(&self) -> Option<&'a [u8]> {
+        self.document.buffer_data(self.index)
+    }
+}
+
+object_wrapper!(
+    /// A range of a buffer.
+    BufferView,
+    InnerBufferView
+);
+
+impl<'a> BufferView<'a> {
+    pub fn name(&self) -> Option<&'a str> {
+        self.inner.name.as_deref()
+    }
+    pub fn buffer(&self) -> Option<Buffer<'a>> {
+        self.document.buffer(self.inner.buffer.value())
+    }
+    pub fn byte_offset(&self) -> usize {
+        self.inner.byte_offset as usize
+    }
+    pub fn byte_length(&self) -> usize {
+        self.inner.byte_length as usize
+    }
+    /// The distance in bytes between the starts of consecutive vertex attributes, or None if
+    /// they are tightly packed.
+    pub fn byte_stride(&self) -> Option<usize> {
+        self.inner.byte_stride.map(|stride| stride as usize)
+    }
+    pub fn target(&self) -> Option<Target> {
+        self.inner.target
+    }
+    /// The range of the buffer covered by this view, see [`Gltf::buffer_view_data`].
+    pub fn data(&self) -> Option<&'a [u8]> {
+        self.document.buffer_view_data(self.index)
+    }
+}
+
+/// The projection of a camera.
+#[derive(Clone, Copy, Debug)]
+pub enum Projection<'a> {
+    Perspective(&'a CameraPerspective),
+    Orthographic(&'a CameraOrthographic),
+}
+
+object_wrapper!(
+    /// A projection that a node places in the scene.
+    Camera,
+    InnerCamera
+);
 
+impl<'a> Camera<'a> {
+    pub fn name(&self) -> Option<&'a str> {
+        self.inner.name.as_deref()
+    }
+    /// The projection matching the `type` of the camera, or None if its properties are missing or
+    /// the type is not defined by the specification.
+    pub fn projection(&self) -> Option<Projection<'a>> {
+        match self.inner.ty {
+            CameraType::Perspective => self.inner.perspective.as_ref().map(Projection::Perspective),
+            CameraType::Orthographic => self
+                .inner
+                .orthographic
+                .as_ref()
+                .map(Projection::Orthographic),
+            CameraType::Other(_) => None,
+        }
+    }
+}
+
+object_wrapper!(
+    /// An image used by textures, stored at its `uri` or in a buffer view.
+    Image,
+    InnerImage
+);
+
+impl<'a> Image<'a> {
+    pub fn name(&self) -> Option<&'a str> {
+        self.inner.name.as_deref()
+    }
+    pub fn uri(&self) -> Option<&'a str> {
+        self.inner.uri.as_deref()
+    }
+    pub fn mime_type(&self) -> Option<&'a MimeType> {
+        self.inner.mime_type.as_ref()
+    }
+    pub fn buffer_view(&self) -> Option<BufferView<'a>> {
+        self.document.buffer_view(self.inner.buffer_view?.value())
+    }
+    /// The encoded contents of the image, see [`Gltf::image_data`].
+    pub fn data(&self) -> Option<&'a [u8]> {
+        self.document.image_data(self.index)
+    }
+}
+
+object_wrapper!(
+    /// The filtering and wrapping modes used to sample a texture.
+    Sampler,
+    InnerSampler
+);
+
+impl<'a> Sampler<'a> {
+    pub fn name(&self) -> Option<&'a str> {
+        self.inner.name.as_deref()
+    }
+    pub fn mag_filter(&self) -> Option<MagFilter> {
+        self.inner.mag_filter
+    }
+    pub fn min_filter(&self) -> Option<MinFilter> {
+        self.inner.min_filter
+    }
+    pub fn wrap_s(&self) -> WrapS {
+        self.inner.wrap_s
+    }
+    pub fn wrap_t(&self) -> WrapT {
+        self.inner.wrap_t
+    }
+}
+
+object_wrapper!(
+    /// An image together with the sampler used to read it.
+    Texture,
+    InnerTexture
+);
+
+impl<'a> Texture<'a> {
+    pub fn name(&self) -> Option<&'a str> {
+        self.inner.name.as_deref()
+    }
+    /// The sampler of the texture, or None if it uses repeat wrapping and automatic filtering.
+    pub fn sampler(&self) -> Option<Sampler<'a>> {
+        self.document.sampler(self.inner.sampler?.value())
+    }
+    /// The image of the texture, or None if it is only provided by an extension.
+    pub fn source(&self) -> Option<Image<'a>> {
+        self.document.image(self.inner.source?.value())
+    }
+}
+
+/// Declares a wrapper around a reference from a material to a texture.
+macro_rules! texture_reference {
+    ($(#[$attribute:meta])* $name:ident, $inner_item:ident) => {
+        $(#[$attribute])*
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name<'a> {
+            document: &'a Gltf,
+            object: Object,
+            inner: &'a $inner_item,
+        }
+
+        impl<'a> $name<'a> {
+            pub fn texture(&self) -> Option<Texture<'a>> {
+                self.document.texture(self.inner.index.value())
+            }
+
+            /// The set of texture coordinates to sample with, e.g. 1 for `TEXCOORD_1`.
+            pub fn tex_coord(&self) -> usize {
+                self.inner.tex_coord as usize
+            }
+
+            /// The deserialized JSON object, e.g. to read its extensions or extras.
+            pub fn inner(&self) -> &'a $inner_item {
+                self.inner
+            }
+
+            /// Returns the extension of a type on this reference, e.g. KHR_texture_transform,
+            /// which was parsed when the document was imported, see [`Material::extension`].
+            pub fn extension<T: GltfExtension + 'static>(&self) -> Option<&'a T> {
+                self.document.extensions.get(self.object)
+            }
+        }
+    };
+}
+
+texture_reference!(
+    /// A texture used by a material.
+    TextureInfo,
+    InnerTextureInfo
+);
+
+texture_reference!(
+    /// The tangent space normal texture of a material.
+    NormalTexture,
+    InnerNormalTextureInfo
+);
+
+impl NormalTexture<'_> {
+    /// The scale applied to the x and y components of the sampled normals.
+    pub fn scale(&self) -> f32 {
+        self.inner.scale as f32
+    }
+}
+
+texture_reference!(
+    /// The ambient occlusion texture of a material, read from its red channel.
+    OcclusionTexture,
+    InnerOcclusionTextureInfo
+);
+
+impl OcclusionTexture<'_> {
+    /// How strongly the occlusion is applied, from 0 for none to 1 for full occlusion.
+    pub fn strength(&self) -> f32 {
+        self.inner.strength as f32
+    }
+}
+
+/// The metallic-roughness parameters of a material. A material without them uses the defaults of
+/// the specification, which every method returns in that case.
+#[derive(Clone, Copy, Debug)]
+pub struct PbrMetallicRoughness<'a> {
+    document: &'a Gltf,
+    material: usize,
+    inner: Option<&'a InnerPbrMetallicRoughness>,
+}
+
+impl<'a> PbrMetallicRoughness<'a> {
+    /// The linear RGBA base color, multiplied with the base color texture.
+    pub fn base_color_factor(&self) -> [f32; 4] {
+        self.inner
+            .map_or_else(default_base_color_factor, |inner| inner.base_color_factor)
+            .map(|value| value as f32)
+    }
+    pub fn base_color_texture(&self) -> Option<TextureInfo<'a>> {
+        self.texture(TextureSlot::BaseColor, |inner| {
+            inner.base_color_texture.as_ref()
+        })
+    }
+    pub fn metallic_factor(&self) -> f32 {
+        self.inner
+            .map_or_else(default_metallic_factor, |inner| inner.metallic_factor) as f32
+    }
+    pub fn roughness_factor(&self) -> f32 {
+        self.inner
+            .map_or_else(default_roughness_factor, |inner| inner.roughness_factor) as f32
+    }
+    /// The texture holding roughness in its green and metalness in its blue channel.
+    pub fn metallic_roughness_texture(&self) -> Option<TextureInfo<'a>> {
+        self.texture(TextureSlot::MetallicRoughness, |inner| {
+            inner.metallic_roughness_texture.as_ref()
+        })
+    }
+
+    /// The deserialized JSON object, or None if the material does not specify one.
+    pub fn inner(&self) -> Option<&'a InnerPbrMetallicRoughness> {
+        self.inner
+    }
+
+    /// Returns the extension of a type on these parameters, which was parsed when the document
+    /// was imported, see [`Material::extension`].
+    pub fn extension<T: GltfExtension + 'static>(&self) -> Option<&'a T> {
+        let object = Object::MaterialPbrMetallicRoughness(self.material);
+        self.document.extensions.get(object)
+    }
+
+    fn texture(
+        &self,
+        slot: TextureSlot,
+        texture: impl FnOnce(&'a InnerPbrMetallicRoughness) -> Option<&'a InnerTextureInfo>,
+    ) -> Option<TextureInfo<'a>> {
+        Some(TextureInfo {
+            document: self.document,
+            object: Object::MaterialTexture(self.material, slot),
+            inner: texture(self.inner?)?,
+        })
+    }
+}
+
+object_wrapper!(
+    /// The appearance of the surface of a primitive.
+    Material,
+    InnerMaterial
+);
+
+impl<'a> Material<'a> {
+    pub fn name(&self) -> Option<&'a str> {
+        self.inner.name.as_deref()
+    }
+    pub fn pbr_metallic_roughness(&self) -> PbrMetallicRoughness<'a> {
+        PbrMetallicRoughness {
+            document: self.document,
+            material: self.index,
+            inner: self.inner.pbr_metallic_roughness.as_ref(),
+        }
+    }
+    pub fn normal_texture(&self) -> Option<NormalTexture<'a>> {
+        Some(NormalTexture {
+            document: self.document,
+            object: Object::MaterialTexture(self.index, TextureSlot::Normal),
+            inner: self.inner.normal_texture.as_ref()?,
+        })
+    }
+    pub fn occlusion_texture(&self) -> Option<OcclusionTexture<'a>> {
+        Some(OcclusionTexture {
+            document: self.document,
+            object: Object::MaterialTexture(self.index, TextureSlot::Occlusion),
+            inner: self.inner.occlusion_texture.as_ref()?,
+        })
+    }
+    pub fn emissive_texture(&self) -> Option<TextureInfo<'a>> {
+        Some(TextureInfo {
+            document: self.document,
+            object: Object::MaterialTexture(self.index, TextureSlot::Emissive),
+            inner: self.inner.emissive_texture.as_ref()?,
+        })
+    }
+    /// The linear RGB emitted color, multiplied with the emissive texture.
+    pub fn emissive_factor(&self) -> [f32; 3] {
+        self.inner.emissive_factor.map(|value| value as f32)
+    }
+    pub fn alpha_mode(&self) -> &'a AlphaMode {
+        &self.inner.alpha_mode
+    }
+    /// The alpha below which a fragment is discarded, only used by the `MASK` alpha mode.
+    pub fn alpha_cutoff(&self) -> f32 {
+        self.inner.alpha_cutoff as f32
+    }
+    pub fn double_sided(&self) -> bool {
+        self.inner.double_sided
+    }
+}
+
+object_wrapper!(
+    /// A set of primitives to render.
+    Mesh,
+    InnerMesh
+);
+
+impl<'a> Mesh<'a> {
+    pub fn name(&self) -> Option<&'a str> {
+        self.inner.name.as_deref()
+    }
+    pub fn primitives(&self) -> Primitives<'a> {
+        Primitives {
+            mesh: *self,
+            inner: self.inner.primitives.iter().enumerate(),
+        }
+    }
+    /// The default weights of the morph targets of the primitives.
+    pub fn weights(&self) -> &'a [f64] {
+        &self.inner.weights
+    }
+}
+
+/// Iterates over the vertex attributes of a primitive or morph target, as pairs of a semantic and
+/// the accessor holding the values. Attributes that do not refer to an accessor are skipped.
+pub struct Attributes<'a> {
+    document: &'a Gltf,
+    inner: serde_json::map::Iter<'a>,
+}
+
+impl<'a> Iterator for Attributes<'a> {
+    type Item = (Semantic, Accessor<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.by_ref().find_map(|(semantic, index)| {
+            let accessor = self.document.accessor(index.as_u64()? as usize)?;
+            Some((Semantic::from(semantic.as_str()), accessor))
+        })
+    }
+}
+
+/// The attributes displaced by a morph target, mapping semantics to accessor indices.
+type InnerMorphTarget = Map<String, Value>;
+
+object_wrapper!(
+    /// Displacements of the vertex attributes of a primitive, blended by the weights of the mesh or
+    /// node. Its index is the position in the primitive.
+    MorphTarget,
+    InnerMorphTarget
+);
+
+impl<'a> MorphTarget<'a> {
+    pub fn attributes(&self) -> Attributes<'a> {
+        Attributes {
+            document: self.document,
+            inner: self.inner.iter(),
+        }
+    }
+    /// Returns the accessor of the displacements of an attribute by its semantic.
+    pub fn attribute(&self, semantic: &Semantic) -> Option<Accessor<'a>> {
+        attribute(self.document, self.inner, semantic)
+    }
+    pub fn positions(&self) -> Option<Accessor<'a>> {
+        self.attribute(&Semantic::Position)
+    }
+    pub fn normals(&self) -> Option<Accessor<'a>> {
+        self.attribute(&Semantic::Normal)
+    }
+    /// The displacements of the xyz components of the tangents.
+    pub fn tangents(&self) -> Option<Accessor<'a>> {
+        self.attribute(&Semantic::Tangent)
+    }
+}
+
+/// Looks up the accessor of an attribute of a primitive or morph target.
+fn attribute<'a>(
+    document: &'a Gltf,
+    attributes: &Map<String, Value>,
+    semantic: &Semantic,
+) -> Option<Accessor<'a>> {
+    let index = attributes.get(&semantic.to_string())?.as_u64()?;
+    document.accessor(index as usize)
+}
+
+/// Iterates over the primitives of a mesh.
+pub struct Primitives<'a> {
+    mesh: Mesh<'a>,
+    inner: std::iter::Enumerate<std::slice::Iter<'a, InnerPrimitive>>,
+}
+
+impl<'a> Iterator for Primitives<'a> {
+    type Item = Primitive<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(index, inner)| Primitive {
+            document: self.mesh.document,
+            mesh: self.mesh,
+            index,
+            inner,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Primitives<'_> {}
+
+/// Geometry of a mesh, rendered with a single material.
+#[derive(Clone, Copy, Debug)]
+pub struct Primitive<'a> {
+    document: &'a Gltf,
+    mesh: Mesh<'a>,
+    index: usize,
+    inner: &'a InnerPrimitive,
+}
+
+impl<'a> Primitive<'a> {
+    /// The position of this primitive in its mesh.
     pub fn index(&self) -> usize {
         self.index
     }
-    pub fn nodes(&self) -> NodeChildren {
+    /// The deserialized JSON object, e.g. to read its extensions or extras.
+    pub fn inner(&self) -> &'a InnerPrimitive {
+        self.inner
+    }
+    pub fn mesh(&self) -> Mesh<'a> {
+        self.mesh
+    }
+    /// Returns the extension of a type on this primitive, which was parsed when the document was
+    /// imported, see [`Mesh::extension`].
+    pub fn extension<T: GltfExtension + 'static>(&self) -> Option<&'a T> {
+        let object = Object::MeshPrimitive(self.mesh.index, self.index);
+        self.document.extensions.get(object)
+    }
+    pub fn attributes(&self) -> Attributes<'a> {
+        Attributes {
+            document: self.document,
+            inner: self.inner.attributes.iter(),
+        }
+    }
+    /// Returns the accessor of an attribute by its semantic.
+    pub fn attribute(&self, semantic: &Semantic) -> Option<Accessor<'a>> {
+        attribute(self.document, &self.inner.attributes, semantic)
+    }
+    pub fn positions(&self) -> Option<Accessor<'a>> {
+        self.attribute(&Semantic::Position)
+    }
+    pub fn normals(&self) -> Option<Accessor<'a>> {
+        self.attribute(&Semantic::Normal)
+    }
+    pub fn tangents(&self) -> Option<Accessor<'a>> {
+        self.attribute(&Semantic::Tangent)
+    }
+    /// The texture coordinates of a set, e.g. 1 for `TEXCOORD_1`.
+    pub fn tex_coords(&self, set: u32) -> Option<Accessor<'a>> {
+        self.attribute(&Semantic::TexCoord(set))
+    }
+    pub fn colors(&self, set: u32) -> Option<Accessor<'a>> {
+        self.attribute(&Semantic::Color(set))
+    }
+    pub fn joints(&self, set: u32) -> Option<Accessor<'a>> {
+        self.attribute(&Semantic::Joints(set))
+    }
+    pub fn weights(&self, set: u32) -> Option<Accessor<'a>> {
+        self.attribute(&Semantic::Weights(set))
+    }
+    /// The accessor holding the vertex indices, or None if the vertices are not indexed.
+    pub fn indices(&self) -> Option<Accessor<'a>> {
+        self.document.accessor(self.inner.indices?.value())
+    }
+    /// The material of the primitive, or None if it uses the default material.
+    pub fn material(&self) -> Option<Material<'a>> {
+        self.document.material(self.inner.material?.value())
+    }
+    pub fn mode(&self) -> Mode {
+        self.inner.mode
+    }
+    pub fn targets(&self) -> MorphTargets<'a> {
+        MorphTargets::new(self.document, &self.inner.targets)
+    }
+}
+
+object_wrapper!(
+    /// The joints and inverse bind matrices used to deform a mesh.
+    Skin,
+    InnerSkin
+);
+
+impl<'a> Skin<'a> {
+    pub fn name(&self) -> Option<&'a str> {
+        self.inner.name.as_deref()
+    }
+    /// The accessor holding a matrix for each joint, or None if they are all identity matrices.
+    pub fn inverse_bind_matrices(&self) -> Option<Accessor<'a>> {
+        self.document
+            .accessor(self.inner.inverse_bind_matrices?.value())
+    }
+    pub fn joints(&self) -> NodeChildren<'a> {
         NodeChildren {
             document: self.document,
-            inner: self.inner.nodes.iter(),
+            inner: self.inner.joints.iter(),
+        }
+    }
+    /// The common root of the joint hierarchy, if the skin specifies one.
+    pub fn skeleton(&self) -> Option<Node<'a>> {
+        self.document.node(self.inner.skeleton?.value())
+    }
+}
+
+object_wrapper!(
+    /// Keyframe animation of the properties of nodes.
+    Animation,
+    InnerAnimation
+);
+
+impl<'a> Animation<'a> {
+    pub fn name(&self) -> Option<&'a str> {
+        self.inner.name.as_deref()
+    }
+    pub fn channels(&self) -> Channels<'a> {
+        Channels {
+            animation: *self,
+            inner: self.inner.channels.iter().enumerate(),
+        }
+    }
+    pub fn samplers(&self) -> AnimationSamplers<'a> {
+        AnimationSamplers {
+            animation: *self,
+            inner: self.inner.samplers.iter().enumerate(),
         }
     }
 }
 
+/// Iterates over the channels of an animation.
+pub struct Channels<'a> {
+    animation: Animation<'a>,
+    inner: std::iter::Enumerate<std::slice::Iter<'a, InnerAnimationChannel>>,
+}
+
+impl<'a> Iterator for Channels<'a> {
+    type Item = Channel<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(index, inner)| Channel {
+            animation: self.animation,
+            index,
+            inner,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Channels<'_> {}
+
+/// Connects a sampler of an animation to the node property it animates.
+#[derive(Clone, Copy, Debug)]
+pub struct Channel<'a> {
+    animation: Animation<'a>,
+    index: usize,
+    inner: &'a InnerAnimationChannel,
+}
+
+impl<'a> Channel<'a> {
+    /// The position of this channel in its animation.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// The deserialized JSON object, e.g. to read its extensions or extras.
+    pub fn inner(&self) -> &'a InnerAnimationChannel {
+        self.inner
+    }
+    pub fn animation(&self) -> Animation<'a> {
+        self.animation
+    }
+    /// Returns the extension of a type on this channel, which was parsed when the document was
+    /// imported, see [`Animation::extension`].
+    pub fn extension<T: GltfExtension + 'static>(&self) -> Option<&'a T> {
+        let object = Object::AnimationChannel(self.animation.index, self.index);
+        self.animation.document.extensions.get(object)
+    }
+    /// The sampler providing the keyframes, which refers to the samplers of the animation.
+    pub fn sampler(&self) -> Option<AnimationSampler<'a>> {
+        Some(AnimationSampler {
+            animation: self.animation,
+            index: self.inner.sampler.value(),
+            inner: self.animation.inner.get(self.inner.sampler)?,
+        })
+    }
+    /// The animated node, or None if the target is only defined by an extension.
+    pub fn target_node(&self) -> Option<Node<'a>> {
+        self.animation
+            .document
+            .node(self.inner.target.node?.value())
+    }
+    pub fn target_path(&self) -> &'a TargetPath {
+        &self.inner.target.path
+    }
+}
+
+/// Iterates over the samplers of an animation.
+pub struct AnimationSamplers<'a> {
+    animation: Animation<'a>,
+    inner: std::iter::Enumerate<std::slice::Iter<'a, InnerAnimationSampler>>,
+}
+
+impl<'a> Iterator for AnimationSamplers<'a> {
+    type Item = AnimationSampler<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(index, inner)| AnimationSampler {
+            animation: self.animation,
+            index,
+            inner,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for AnimationSamplers<'_> {}
+
+/// Keyframe times and values of an animation, and how to interpolate between them.
+#[derive(Clone, Copy, Debug)]
+pub struct AnimationSampler<'a> {
+    animation: Animation<'a>,
+    index: usize,
+    inner: &'a InnerAnimationSampler,
+}
+
+impl<'a> AnimationSampler<'a> {
+    /// The position of this sampler in its animation.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// The deserialized JSON object, e.g. to read its extensions or extras.
+    pub fn inner(&self) -> &'a InnerAnimationSampler {
+        self.inner
+    }
+    pub fn animation(&self) -> Animation<'a> {
+        self.animation
+    }
+    /// Returns the extension of a type on this sampler, which was parsed when the document was
+    /// imported, see [`Animation::extension`].
+    pub fn extension<T: GltfExtension + 'static>(&self) -> Option<&'a T> {
+        let object = Object::AnimationSampler(self.animation.index, self.index);
+        self.animation.document.extensions.get(object)
+    }
+    /// The accessor holding the keyframe times in seconds.
+    pub fn input(&self) -> Option<Accessor<'a>> {
+        self.animation.document.accessor(self.inner.input.value())
+    }
+    /// The accessor holding the keyframe values.
+    pub fn output(&self) -> Option<Accessor<'a>> {
+        self.animation.document.accessor(self.inner.output.value())
+    }
+    pub fn interpolation(&self) -> &'a Interpolation {
+        &self.inner.interpolation
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum ImportError {
     #[error("IO error: {0}")]
     Io(std::io::Error),
     #[error("Deserialization failed: {0}")]
     DeserializeError(serde_json::Error),
+    #[error("GLB header is invalid or truncated")]
+    GlbInvalidHeader,
+    #[error("GLB version {0} is not supported, only version 2 is")]
+    GlbUnsupportedVersion(u32),
+    #[error("GLB header specifies a length of {header} bytes but the container is {actual} bytes")]
+    GlbLengthMismatch { header: usize, actual: usize },
+    #[error("GLB chunk at offset {offset} extends past the end of the container")]
+    GlbTruncatedChunk { offset: usize },
+    #[error("GLB chunk at offset {offset} has a length that is not a multiple of 4")]
+    GlbMisalignedChunk { offset: usize },
+    #[error("GLB container does not start with a JSON chunk")]
+    GlbMissingJsonChunk,
+    #[error("GLB chunk at offset {offset} has unexpected type {chunk_type:#010x}")]
+    GlbUnexpectedChunk { offset: usize, chunk_type: u32 },
+    #[error("Data URI is malformed")]
+    MalformedDataUri,
+    #[error("URI {0} has a scheme that is not supported, only data URIs and relative paths are")]
+    UnsupportedScheme(String),
+    #[error("External resource {0} is referenced but no resolver is available")]
+    ExternalReference(String),
+    #[error("Failed to resolve {path}: {source}")]
+    Resolve {
+        path: String,
+        source: std::io::Error,
+    },
+    #[error(
+        "Buffer {index} has a byteLength of {expected} bytes but only {actual} bytes were loaded"
+    )]
+    BufferTooShort {
+        index: usize,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Document failed validation with {}", validation_summary(.0))]
+    Validation(Vec<ValidationError>),
+}
+
+/// Describes the errors of a failed validation by their number and the first of them.
+fn validation_summary(errors: &[ValidationError]) -> String {
+    match errors.first() {
+        Some(first) => format!("{} errors, the first is {first}", errors.len()),
+        None => "no errors".to_string(),
+    }
+}
+
+/// Options controlling how an asset is imported.
+#[derive(Clone, Debug, Default)]
+pub struct ImportOptions {
+    /// Validates the document, failing with [`ImportError::Validation`] when an index is out of
+    /// range, the node hierarchy is malformed or a constraint of the schema is violated.
+    /// See [`crate::validation::validate`].
+    pub validate: bool,
 }
 
+/// Imports a glTF asset from a file, either in the JSON (.gltf) or binary (.glb) format.
+/// External resources are resolved relative to the directory containing the file.
 pub fn import(path: &str) -> Result<Gltf, ImportError> {
-    let file = File::open(path).map_err(ImportError::Io)?;
-    let reader = BufReader::new(file);
+    import_with_options(path, &ImportOptions::default())
+}
+
+/// Imports a glTF asset from a file like [`import`], with options.
+pub fn import_with_options(path: &str, options: &ImportOptions) -> Result<Gltf, ImportError> {
+    let data = fs::read(path).map_err(ImportError::Io)?;
+    let base = Path::new(path).parent().unwrap_or(Path::new(""));
+    import_from(&data, Some(&FileSystemResolver::new(base)), options)
+}
+
+/// Imports a glTF asset from memory. Binary containers are detected by their magic bytes.
+/// Only embedded resources are loaded, any external reference fails with [`ImportError::ExternalReference`].
+pub fn import_slice(data: &[u8]) -> Result<Gltf, ImportError> {
+    import_from(data, None, &ImportOptions::default())
+}
+
+/// Imports a glTF asset from memory, loading external resources through the given resolver.
+pub fn import_with_resolver(data: &[u8], resolver: &dyn Resolver) -> Result<Gltf, ImportError> {
+    import_from(data, Some(resolver), &ImportOptions::default())
+}
+
+/// Imports a glTF asset from memory with options. External resources are loaded through the
+/// resolver if one is given.
+pub fn import_slice_with_options(
+    data: &[u8],
+    resolver: Option<&dyn Resolver>,
+    options: &ImportOptions,
+) -> Result<Gltf, ImportError> {
+    import_from(data, resolver, options)
+}
+
+fn import_from(
+    data: &[u8],
+    resolver: Option<&dyn Resolver>,
+    options: &ImportOptions,
+) -> Result<Gltf, ImportError> {
+    let (json, blob) = if glb::is_glb(data) {
+        let glb = Glb::parse(data)?;
+        (glb.json, glb.bin.map(<[u8]>::to_vec))
+    } else {
+        (data, None)
+    };
 
     // Read the JSON contents of the file into the generated structures
-    let inner: InnerGltf =
-        serde_json::from_reader(reader).map_err(ImportError::DeserializeError)?;
+    let inner: InnerGltf = serde_json::from_slice(json).map_err(ImportError::DeserializeError)?;
+    let mut gltf = Gltf::from_document(inner, Vec::new());
+    if options.validate {
+        let errors = validation::validate_with_extensions(&gltf.inner, &gltf.extensions);
+        if !errors.is_empty() {
+            return Err(ImportError::Validation(errors));
+        }
+    }
+
+    // External resources are only loaded once the document is known to be valid
+    let load = |uri: Option<&str>| uri.map(|uri| uri::load(uri, resolver)).transpose();
+    gltf.buffers = gltf
+        .inner
+        .buffers
+        .iter()
+        .map(|buffer| load(buffer.uri.as_deref()))
+        .collect::<Result<Vec<_>, _>>()?;
+    gltf.images = gltf
+        .inner
+        .images
+        .iter()
+        .map(|image| load(image.uri.as_deref()))
+        .collect::<Result<Vec<_>, _>>()?;
+    gltf.blob = blob;
+
+    // Every buffer with data must provide at least byteLength bytes
+    for (index, buffer) in gltf.inner.buffers.iter().enumerate() {
+        let Some(actual) = gltf.buffer_contents(index).map(<[u8]>::len) else {
+            continue;
+        };
+        let expected = buffer.byte_length as usize;
+        if actual < expected {
+            return Err(ImportError::BufferTooShort {
+                index,
+                expected,
+                actual,
+            });
+        }
+    }
 
-    Ok(Gltf { inner })
+    Ok(gltf)
 }
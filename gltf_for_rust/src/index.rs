@@ -0,0 +1,107 @@
+//! Typed references between objects of a glTF document.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::schema::Gltf;
+
+/// The index of an object of type `T` in the collection it is stored in, e.g. `Index<Node>` for
+/// an index into the nodes of the glTF root. Ids are non-negative, so a negative id fails to deserialize.
+pub struct Index<T> {
+    value: u32,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Index<T> {
+    pub fn new(value: u32) -> Self {
+        Index {
+            value,
+            marker: PhantomData,
+        }
+    }
+
+    /// The position of the object in its collection.
+    pub fn value(self) -> usize {
+        self.value as usize
+    }
+}
+
+// Implemented by hand, deriving would require `T` to implement the traits as well.
+impl<T> Clone for Index<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Index<T> {}
+
+impl<T> PartialEq for Index<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T> Eq for Index<T> {}
+
+impl<T> PartialOrd for Index<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Index<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T> Hash for Index<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<T> fmt::Debug for Index<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Index({})", self.value)
+    }
+}
+
+impl<T> fmt::Display for Index<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<T> Serialize for Index<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Index<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u32::deserialize(deserializer).map(Index::new)
+    }
+}
+
+/// Looks up the object an index refers to. Implemented by every object that holds a collection
+/// referred to by ids, e.g. the glTF root for `Index<Node>` and an animation for `Index<AnimationSampler>`.
+pub trait Get<T> {
+    /// Returns the object an index refers to, or None if the index is out of range.
+    fn get(&self, index: Index<T>) -> Option<&T>;
+}
+
+impl Gltf {
+    /// Returns the object an index refers to, or None if the index is out of range.
+    pub fn get<T>(&self, index: Index<T>) -> Option<&T>
+    where
+        Self: Get<T>,
+    {
+        Get::get(self, index)
+    }
+}
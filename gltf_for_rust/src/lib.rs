@@ -4,7 +4,34 @@ use thiserror::Error;
 
 mod generated;
 
-mod import;
+pub mod accessor;
+pub mod animation;
+pub mod bounds;
+pub mod builder;
+pub mod export;
+pub mod extension;
+pub mod geometry;
+mod glb;
+pub mod import;
+mod index;
+pub mod morph;
+pub mod semantic;
+pub mod skinning;
+pub mod topology;
+pub mod transform;
+pub mod uri;
+pub mod validation;
+
+pub use index::{Get, Index};
+
+/// Types generated from the core glTF 2.0 schema, e.g. [`schema::Accessor`] or [`schema::Node`].
+/// Enumerations embedded in a type live in the module of that type, e.g. `schema::accessor::Type`.
+pub use generated::gltf as schema;
+
+/// Types generated from the extension schemas, grouped in one module per extension.
+/// Each extension object is re-exported under the name of the object it extends, e.g.
+/// `extensions::khr_lights_punctual::NodeExtension`.
+pub use generated::extensions;
 #[cfg(test)]
 mod test;
 
@@ -29,7 +56,7 @@ pub trait GltfObject {
             .as_ref()
             .and_then(|extensions| extensions.get(T::extension_name()));
         if let Some(extension) = extension {
-            serde_json::from_value::<T>(extension.clone())
+            T::deserialize(extension)
                 .map_err(|e| ParseExtensionError::FailedToParse { inner: Box::new(e) })
         } else {
             Err(ParseExtensionError::NotFound)
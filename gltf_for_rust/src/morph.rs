@@ -0,0 +1,154 @@
+//! Blending of morph targets into the vertex attributes of a primitive.
+
+use thiserror::Error;
+
+use crate::accessor::{self, AccessorError};
+use crate::import::{Accessor, Node, Primitive};
+use crate::semantic::Semantic;
+
+#[derive(Error, Debug)]
+pub enum MorphError {
+    #[error("Attribute {0} is missing")]
+    MissingAttribute(Semantic),
+    #[error("Attribute {semantic} can not be read: {source}")]
+    Attribute {
+        semantic: Semantic,
+        source: AccessorError,
+    },
+    #[error("Attribute {semantic} of morph target {target} can not be read: {source}")]
+    TargetAttribute {
+        target: usize,
+        semantic: Semantic,
+        source: AccessorError,
+    },
+    #[error(
+        "Attribute {semantic} of morph target {target} has {actual} elements but the primitive has {expected} vertices"
+    )]
+    CountMismatch {
+        target: usize,
+        semantic: Semantic,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+/// The weights of the morph targets of the mesh of a node: the weights of the node if it
+/// specifies them, otherwise those of its mesh.
+pub fn weights(node: &Node) -> Vec<f32> {
+    let weights = match (node.weights(), node.mesh()) {
+        ([], Some(mesh)) => mesh.weights(),
+        (weights, _) => weights,
+    };
+    weights.iter().map(|&weight| weight as f32).collect()
+}
+
+/// Vertex attributes after blending the morph targets of a primitive.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MorphedVertices {
+    pub positions: Vec<[f32; 3]>,
+    /// The normals, empty if the primitive has none.
+    pub normals: Vec<[f32; 3]>,
+    /// The tangents with their handedness in w, empty if the primitive has none.
+    pub tangents: Vec<[f32; 4]>,
+}
+
+/// Adds the displacements of the morph targets of a primitive, each multiplied by its weight, to
+/// its positions, normals and tangents. Targets without a weight, or with a weight of zero, are
+/// skipped, as are the zeros of sparse displacements without a buffer view. Normals and tangents
+/// are normalized after blending.
+pub fn blend(primitive: &Primitive, weights: &[f32]) -> Result<MorphedVertices, MorphError> {
+    let positions = primitive
+        .positions()
+        .ok_or(MorphError::MissingAttribute(Semantic::Position))?;
+    let mut vertices = MorphedVertices {
+        positions: read(positions, Semantic::Position)?,
+        normals: primitive
+            .normals()
+            .map(|normals| read(normals, Semantic::Normal))
+            .transpose()?
+            .unwrap_or_default(),
+        tangents: primitive
+            .tangents()
+            .map(|tangents| read(tangents, Semantic::Tangent))
+            .transpose()?
+            .unwrap_or_default(),
+    };
+
+    for (target, &weight) in primitive.targets().zip(weights) {
+        if weight == 0.0 {
+            continue;
+        }
+        let displacements = [
+            (Semantic::Position, target.positions()),
+            (Semantic::Normal, target.normals()),
+            (Semantic::Tangent, target.tangents()),
+        ];
+        for (semantic, accessor) in displacements {
+            let Some(accessor) = accessor else {
+                continue;
+            };
+            let target_error = |source| MorphError::TargetAttribute {
+                target: target.index(),
+                semantic: semantic.clone(),
+                source,
+            };
+            let expected = vertices.positions.len();
+            if accessor.count() != expected {
+                return Err(MorphError::CountMismatch {
+                    target: target.index(),
+                    semantic,
+                    expected,
+                    actual: accessor.count(),
+                });
+            }
+
+            let elements = accessor.read::<[f32; 3]>().map_err(target_error)?;
+            match semantic {
+                Semantic::Position => displace(&mut vertices.positions, elements, weight),
+                Semantic::Normal => displace(&mut vertices.normals, elements, weight),
+                _ => displace(&mut vertices.tangents, elements, weight),
+            }
+        }
+    }
+
+    vertices.normals.iter_mut().for_each(|n| normalize(n));
+    vertices
+        .tangents
+        .iter_mut()
+        .for_each(|tangent| normalize(&mut tangent[..3]));
+    Ok(vertices)
+}
+
+fn read<const N: usize>(
+    accessor: Accessor,
+    semantic: Semantic,
+) -> Result<Vec<[f32; N]>, MorphError> {
+    let elements = accessor
+        .read::<[f32; N]>()
+        .map_err(|source| MorphError::Attribute { semantic, source })?;
+    Ok(elements.collect())
+}
+
+/// Adds weighted displacements to the first three components of the vertices. Vertices are only
+/// displaced if the primitive has the attribute.
+fn displace<const N: usize>(
+    vertices: &mut [[f32; N]],
+    displacements: accessor::Iter<[f32; 3]>,
+    weight: f32,
+) {
+    for (index, displacement) in displacements.stored() {
+        let Some(vertex) = vertices.get_mut(index) else {
+            return;
+        };
+        for (value, delta) in vertex.iter_mut().zip(displacement) {
+            *value += weight * delta;
+        }
+    }
+}
+
+fn normalize(vector: &mut [f32]) {
+    let length = vector.iter().map(|value| value * value).sum::<f32>().sqrt();
+    if length > 0.0 {
+        vector.iter_mut().for_each(|value| *value /= length);
+    }
+}
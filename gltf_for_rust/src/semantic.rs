@@ -0,0 +1,111 @@
+//! Semantics of the vertex attributes of a primitive, e.g. `POSITION` or `TEXCOORD_0`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::schema::{Accessor, MeshPrimitive};
+use crate::Index;
+
+/// The semantic of a vertex attribute. Attributes of which a primitive may have several sets are
+/// numbered, e.g. `TEXCOORD_1` is `TexCoord(1)`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(from = "String", into = "String")]
+pub enum Semantic {
+    Position,
+    Normal,
+    Tangent,
+    TexCoord(u32),
+    Color(u32),
+    Joints(u32),
+    Weights(u32),
+    /// An application-specific attribute, whose name starts with an underscore, e.g. `_TEMPERATURE`.
+    Custom(String),
+    /// A name that is not defined by the specification.
+    Other(String),
+}
+
+impl From<&str> for Semantic {
+    fn from(name: &str) -> Self {
+        let numbered = name
+            .split_once('_')
+            .and_then(|(prefix, set)| Some((prefix, parse_set(set)?)));
+        match (name, numbered) {
+            ("POSITION", _) => Semantic::Position,
+            ("NORMAL", _) => Semantic::Normal,
+            ("TANGENT", _) => Semantic::Tangent,
+            (_, Some(("TEXCOORD", set))) => Semantic::TexCoord(set),
+            (_, Some(("COLOR", set))) => Semantic::Color(set),
+            (_, Some(("JOINTS", set))) => Semantic::Joints(set),
+            (_, Some(("WEIGHTS", set))) => Semantic::Weights(set),
+            _ if name.starts_with('_') => Semantic::Custom(name.to_string()),
+            _ => Semantic::Other(name.to_string()),
+        }
+    }
+}
+
+/// Parses the set of a numbered attribute. A set with leading zeros is not accepted, since it
+/// would not convert back to the same name.
+fn parse_set(set: &str) -> Option<u32> {
+    let canonical = set == "0" || !set.starts_with('0');
+    match canonical && set.bytes().all(|byte| byte.is_ascii_digit()) {
+        true => set.parse().ok(),
+        false => None,
+    }
+}
+
+impl From<String> for Semantic {
+    fn from(name: String) -> Self {
+        Semantic::from(name.as_str())
+    }
+}
+
+impl From<Semantic> for String {
+    fn from(semantic: Semantic) -> Self {
+        semantic.to_string()
+    }
+}
+
+impl fmt::Display for Semantic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Semantic::Position => f.write_str("POSITION"),
+            Semantic::Normal => f.write_str("NORMAL"),
+            Semantic::Tangent => f.write_str("TANGENT"),
+            Semantic::TexCoord(set) => write!(f, "TEXCOORD_{set}"),
+            Semantic::Color(set) => write!(f, "COLOR_{set}"),
+            Semantic::Joints(set) => write!(f, "JOINTS_{set}"),
+            Semantic::Weights(set) => write!(f, "WEIGHTS_{set}"),
+            Semantic::Custom(name) | Semantic::Other(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Maps the semantics of the attributes of a primitive or morph target to their accessors.
+pub type AttributeMap = BTreeMap<Semantic, Index<Accessor>>;
+
+/// Types the attributes of a primitive or morph target. Values that are not accessor indices,
+/// which validation reports, are skipped.
+pub fn attribute_map(attributes: &Map<String, Value>) -> AttributeMap {
+    attributes
+        .iter()
+        .filter_map(|(name, index)| {
+            let index = u32::try_from(index.as_u64()?).ok()?;
+            Some((Semantic::from(name.as_str()), Index::new(index)))
+        })
+        .collect()
+}
+
+impl MeshPrimitive {
+    /// The attributes of this primitive by their semantic, see [`attribute_map`].
+    pub fn attribute_map(&self) -> AttributeMap {
+        attribute_map(&self.attributes)
+    }
+
+    /// The attributes displaced by each morph target of this primitive.
+    pub fn target_maps(&self) -> Vec<AttributeMap> {
+        self.targets.iter().map(attribute_map).collect()
+    }
+}
@@ -0,0 +1,279 @@
+//! Skinning of meshes on the CPU, e.g. to compute the bounds of an animated character.
+
+use thiserror::Error;
+
+use crate::accessor::AccessorError;
+use crate::import::{Node, Primitive, Skin};
+use crate::semantic::Semantic;
+use crate::transform::{self, Matrix};
+
+#[derive(Error, Debug)]
+pub enum SkinningError {
+    #[error("Attribute {0} is missing")]
+    MissingAttribute(Semantic),
+    #[error("Attribute {semantic} can not be read: {source}")]
+    Attribute {
+        semantic: Semantic,
+        source: AccessorError,
+    },
+    #[error(
+        "Attribute {semantic} has {actual} elements but the primitive has {expected} vertices"
+    )]
+    CountMismatch {
+        semantic: Semantic,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("Inverse bind matrices can not be read: {0}")]
+    InverseBindMatrices(AccessorError),
+    #[error("Skin has {joints} joints but {matrices} inverse bind matrices")]
+    InverseBindMatrixCount { joints: usize, matrices: usize },
+    #[error("Node {0} does not exist or has no world matrix")]
+    MissingNode(usize),
+    #[error("Vertex {vertex} is influenced by joint {joint} but the skin has {joints} joints")]
+    JointOutOfRange {
+        vertex: usize,
+        joint: usize,
+        joints: usize,
+    },
+}
+
+/// Computes the joint matrices of a skin, which transform a vertex in the bind pose to the space
+/// of the node that instantiates the mesh: the world matrix of the joint, times its inverse bind
+/// matrix, relative to the world matrix of the mesh node. The world matrices are indexed by node,
+/// e.g. from [`crate::animation::Pose::world_matrices`].
+///
+/// A mesh node with a singular world matrix, e.g. one scaled to zero, is treated as the origin.
+pub fn joint_matrices(
+    skin: &Skin,
+    node: &Node,
+    world_matrices: &[Matrix],
+) -> Result<Vec<Matrix>, SkinningError> {
+    let joints = &skin.inner().joints;
+    let inverse_bind_matrices: Vec<Matrix> = match skin.inverse_bind_matrices() {
+        Some(accessor) => accessor
+            .read::<[f32; 16]>()
+            .map_err(SkinningError::InverseBindMatrices)?
+            .collect(),
+        None => vec![transform::IDENTITY; joints.len()],
+    };
+    if inverse_bind_matrices.len() != joints.len() {
+        return Err(SkinningError::InverseBindMatrixCount {
+            joints: joints.len(),
+            matrices: inverse_bind_matrices.len(),
+        });
+    }
+
+    let world = |index: usize| {
+        world_matrices
+            .get(index)
+            .ok_or(SkinningError::MissingNode(index))
+    };
+    let node_inverse = transform::invert(world(node.index())?).unwrap_or(transform::IDENTITY);
+    joints
+        .iter()
+        .zip(&inverse_bind_matrices)
+        .map(|(joint, inverse_bind_matrix)| {
+            let joint_world = transform::multiply(world(joint.value())?, inverse_bind_matrix);
+            Ok(transform::multiply(&node_inverse, &joint_world))
+        })
+        .collect()
+}
+
+/// The joints influencing each vertex of a primitive and their weights, read from every set of
+/// `JOINTS_n` and `WEIGHTS_n` attributes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Influences {
+    /// The joint indices of each set, indexed by set and then by vertex.
+    pub joints: Vec<Vec<[u16; 4]>>,
+    /// The weights of each set, matching the joint indices.
+    pub weights: Vec<Vec<[f32; 4]>>,
+}
+
+impl Influences {
+    /// Reads the influences of a primitive. Joints may be stored as unsigned bytes or shorts, and
+    /// weights as floats or normalized unsigned bytes or shorts.
+    pub fn read(primitive: &Primitive) -> Result<Self, SkinningError> {
+        let mut influences = Influences::default();
+        for set in 0.. {
+            let Some(joints) = primitive.joints(set) else {
+                break;
+            };
+            let weights = primitive
+                .weights(set)
+                .ok_or(SkinningError::MissingAttribute(Semantic::Weights(set)))?;
+
+            let joints = joints
+                .read::<[u16; 4]>()
+                .map_err(|source| SkinningError::Attribute {
+                    semantic: Semantic::Joints(set),
+                    source,
+                })?;
+            let weights =
+                weights
+                    .read::<[f32; 4]>()
+                    .map_err(|source| SkinningError::Attribute {
+                        semantic: Semantic::Weights(set),
+                        source,
+                    })?;
+            // Every set must cover the same vertices as the first one.
+            let expected = influences.vertex_count().unwrap_or(joints.len());
+            if joints.len() != expected {
+                return Err(SkinningError::CountMismatch {
+                    semantic: Semantic::Joints(set),
+                    expected,
+                    actual: joints.len(),
+                });
+            }
+            if weights.len() != expected {
+                return Err(SkinningError::CountMismatch {
+                    semantic: Semantic::Weights(set),
+                    expected,
+                    actual: weights.len(),
+                });
+            }
+            influences.joints.push(joints.collect());
+            influences.weights.push(weights.collect());
+        }
+        Ok(influences)
+    }
+
+    /// The number of vertices, or None if the primitive has no influences.
+    pub fn vertex_count(&self) -> Option<usize> {
+        self.joints.first().map(Vec::len)
+    }
+
+    /// The sum of the joint matrices influencing a vertex, each multiplied by its weight.
+    pub fn skin_matrix(
+        &self,
+        vertex: usize,
+        joint_matrices: &[Matrix],
+    ) -> Result<Matrix, SkinningError> {
+        let mut matrix = [0.0; 16];
+        let expected = self.vertex_count().unwrap_or_default();
+        for (set, (joints, weights)) in self.joints.iter().zip(&self.weights).enumerate() {
+            let mismatch = |semantic: Semantic, actual: usize| SkinningError::CountMismatch {
+                semantic,
+                expected,
+                actual,
+            };
+            let joints = joints
+                .get(vertex)
+                .ok_or_else(|| mismatch(Semantic::Joints(set as u32), joints.len()))?;
+            let weights = weights
+                .get(vertex)
+                .ok_or_else(|| mismatch(Semantic::Weights(set as u32), weights.len()))?;
+            for (&joint, &weight) in joints.iter().zip(weights) {
+                if weight == 0.0 {
+                    continue;
+                }
+                let joint_matrix =
+                    joint_matrices
+                        .get(joint as usize)
+                        .ok_or(SkinningError::JointOutOfRange {
+                            vertex,
+                            joint: joint as usize,
+                            joints: joint_matrices.len(),
+                        })?;
+                for (value, joint_value) in matrix.iter_mut().zip(joint_matrix) {
+                    *value += weight * joint_value;
+                }
+            }
+        }
+        Ok(matrix)
+    }
+}
+
+/// Vertex positions and normals after skinning.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SkinnedVertices {
+    pub positions: Vec<[f32; 3]>,
+    /// The skinned normals, empty if no normals were given.
+    pub normals: Vec<[f32; 3]>,
+}
+
+/// Skins vertex positions and, if not empty, normals. The vertices can be read from a primitive
+/// or be the result of applying its morph targets first.
+pub fn skin(
+    positions: &[[f32; 3]],
+    normals: &[[f32; 3]],
+    influences: &Influences,
+    joint_matrices: &[Matrix],
+) -> Result<SkinnedVertices, SkinningError> {
+    let vertex_count = influences
+        .vertex_count()
+        .ok_or(SkinningError::MissingAttribute(Semantic::Joints(0)))?;
+    let mismatch = |semantic: Semantic, actual: usize| SkinningError::CountMismatch {
+        semantic,
+        expected: positions.len(),
+        actual,
+    };
+    if vertex_count != positions.len() {
+        return Err(mismatch(Semantic::Joints(0), vertex_count));
+    }
+    if !normals.is_empty() && normals.len() != positions.len() {
+        return Err(mismatch(Semantic::Normal, normals.len()));
+    }
+
+    let mut skinned = SkinnedVertices::default();
+    for (vertex, &position) in positions.iter().enumerate() {
+        let matrix = influences.skin_matrix(vertex, joint_matrices)?;
+        skinned
+            .positions
+            .push(transform::transform_point(&matrix, position));
+        if let Some(&normal) = normals.get(vertex) {
+            skinned.normals.push(transform_normal(&matrix, normal));
+        }
+    }
+    Ok(skinned)
+}
+
+/// Reads the positions, normals and influences of a primitive and skins them.
+pub fn skin_primitive(
+    primitive: &Primitive,
+    joint_matrices: &[Matrix],
+) -> Result<SkinnedVertices, SkinningError> {
+    let read = |semantic: Semantic| -> Result<Option<Vec<[f32; 3]>>, SkinningError> {
+        let Some(accessor) = primitive.attribute(&semantic) else {
+            return Ok(None);
+        };
+        let values = accessor
+            .read::<[f32; 3]>()
+            .map_err(|source| SkinningError::Attribute { semantic, source })?;
+        Ok(Some(values.collect()))
+    };
+    let positions =
+        read(Semantic::Position)?.ok_or(SkinningError::MissingAttribute(Semantic::Position))?;
+    let normals = read(Semantic::Normal)?.unwrap_or_default();
+    skin(
+        &positions,
+        &normals,
+        &Influences::read(primitive)?,
+        joint_matrices,
+    )
+}
+
+/// Transforms a normal by the inverse transpose of a matrix, computed as the cofactor matrix of
+/// its upper 3x3 part which only differs by the determinant, and normalizes it.
+fn transform_normal(m: &Matrix, normal: [f32; 3]) -> [f32; 3] {
+    let column = |index: usize| [m[index * 4], m[index * 4 + 1], m[index * 4 + 2]];
+    let cross = |a: [f32; 3], b: [f32; 3]| {
+        [
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ]
+    };
+    let (x, y, z) = (column(0), column(1), column(2));
+    let cofactors = [cross(y, z), cross(z, x), cross(x, y)];
+    let determinant: f32 = x.iter().zip(cofactors[0]).map(|(a, b)| a * b).sum();
+
+    let transformed: [f32; 3] = std::array::from_fn(|i| {
+        (0..3).map(|j| normal[j] * cofactors[j][i]).sum::<f32>() * determinant.signum()
+    });
+    let length = transformed.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if length == 0.0 {
+        return transformed;
+    }
+    transformed.map(|value| value / length)
+}
@@ -1,8 +1,38 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::{fs, io};
 
-use serde_json;
+use base64::Engine;
 
-use crate::import::{import, Node};
+use crate::accessor::{AccessorError, ComponentType};
+use crate::animation::{AnimationError, Evaluator, Pose};
+use crate::bounds::{self, Aabb};
+use crate::builder::DocumentBuilder;
+use crate::export::{export, to_glb, to_json, ExportError, ExportOptions};
+use crate::extensions::{
+    cesium_primitive_outline, ext_texture_webp, khr_lights_punctual,
+    khr_materials_emissive_strength, khr_materials_variants, khr_texture_transform, msft_lod,
+};
+use crate::geometry::{self, GeometryError};
+use crate::import::{
+    import, import_slice, import_slice_with_options, import_with_resolver, ImportError,
+    ImportOptions, Node, Projection,
+};
+use crate::morph;
+use crate::schema::animation_channel_target::Path;
+use crate::schema::animation_sampler::Interpolation;
+use crate::schema::image::MimeType;
+use crate::schema::material::AlphaMode;
+use crate::schema::mesh_primitive::Mode;
+use crate::schema::sampler::{MagFilter, WrapS};
+use crate::schema::MeshPrimitive;
+use crate::semantic::Semantic;
+use crate::skinning::{self, Influences, SkinningError};
+use crate::topology::{Topology, TopologyError};
+use crate::transform::{self, Transform};
+use crate::uri::{FileSystemResolver, Resolver};
+use crate::validation::{report, validate, Code, Severity, Validate, ValidationErrorKind};
+use crate::{Get, Index};
 
 fn visit(depth: usize, node: &Node) {
     println!(
@@ -31,3 +61,2157 @@ fn test() {
         }
     }
 }
+
+#[test]
+fn node_children_skip_invalid_indices() {
+    let json = br#"{
+        "asset": {"version": "2.0"},
+        "scenes": [{"nodes": [0, 7]}],
+        "nodes": [{"name": "root", "children": [1, 3]}, {"name": "child"}]
+    }"#;
+    let gltf = import_slice(json).unwrap();
+
+    let scene = gltf.scenes().next().unwrap();
+    let roots: Vec<_> = scene.nodes().map(|node| node.index()).collect();
+    assert_eq!(roots, [0]);
+    let root = scene.nodes().next().unwrap();
+    let children: Vec<_> = root.children().map(|node| node.name()).collect();
+    assert_eq!(children, [Some("child")]);
+}
+
+fn validation_errors(json: serde_json::Value) -> Vec<(String, ValidationErrorKind)> {
+    let document = serde_json::from_value(json).unwrap();
+    validate(&document)
+        .into_iter()
+        .map(|error| (error.pointer, error.kind))
+        .collect()
+}
+
+#[test]
+fn validate_references() {
+    let errors = validation_errors(serde_json::json!({
+        "asset": {"version": "2.0"},
+        "accessors": [{"componentType": 5126, "count": 1, "type": "VEC3"}],
+        "meshes": [{"primitives": [{"attributes": {"POSITION": 0, "NORMAL": 4}, "material": 0}]}],
+        "nodes": [
+            {"mesh": 0, "children": [1, 2]},
+            {"extensions": {"KHR_lights_punctual": {"light": 1}, "MSFT_lod": {"ids": [5]}}}
+        ],
+        "extensions": {
+            "KHR_lights_punctual": {"lights": [{"type": "point"}]},
+            "EXT_lights_ies": {"lights": [{"bufferView": 3}]}
+        }
+    }));
+    assert_eq!(
+        errors,
+        [
+            (
+                "/meshes/0/primitives/0/attributes/NORMAL".to_string(),
+                ValidationErrorKind::IndexOutOfRange { index: 4, count: 1 }
+            ),
+            (
+                "/meshes/0/primitives/0/material".to_string(),
+                ValidationErrorKind::IndexOutOfRange { index: 0, count: 0 }
+            ),
+            (
+                "/nodes/0/children/1".to_string(),
+                ValidationErrorKind::IndexOutOfRange { index: 2, count: 2 }
+            ),
+            (
+                "/extensions/EXT_lights_ies/lights/0/bufferView".to_string(),
+                ValidationErrorKind::IndexOutOfRange { index: 3, count: 0 }
+            ),
+            (
+                "/nodes/1/extensions/KHR_lights_punctual/light".to_string(),
+                ValidationErrorKind::IndexOutOfRange { index: 1, count: 1 }
+            ),
+            (
+                "/nodes/1/extensions/MSFT_lod/ids/0".to_string(),
+                ValidationErrorKind::IndexOutOfRange { index: 5, count: 2 }
+            ),
+        ]
+    );
+}
+
+#[test]
+fn validate_untyped_extension_references() {
+    let errors = validation_errors(serde_json::json!({
+        "asset": {"version": "2.0", "extensions": {"KHR_xmp_json_ld": {"packet": 0}}},
+        "accessors": [{"componentType": 5126, "count": 1, "type": "VEC3"}],
+        "materials": [{"extensions": {"KHR_xmp_json_ld": {"packet": 1}}}],
+        "nodes": [{
+            "extensions": {
+                "EXT_mesh_gpu_instancing": {"attributes": {"TRANSLATION": 0, "SCALE": 2}},
+                "KHR_xmp_json_ld": {"packet": "first"}
+            }
+        }],
+        "extensions": {"KHR_xmp_json_ld": {"packets": [{"@context": {}}]}}
+    }));
+    assert_eq!(
+        errors,
+        [
+            (
+                "/nodes/0/extensions/EXT_mesh_gpu_instancing/attributes/SCALE".to_string(),
+                ValidationErrorKind::IndexOutOfRange { index: 2, count: 1 }
+            ),
+            (
+                "/materials/0/extensions/KHR_xmp_json_ld/packet".to_string(),
+                ValidationErrorKind::IndexOutOfRange { index: 1, count: 1 }
+            ),
+            (
+                "/nodes/0/extensions/KHR_xmp_json_ld/packet".to_string(),
+                ValidationErrorKind::InvalidIndex("\"first\"".to_string())
+            ),
+        ]
+    );
+}
+
+/// Validates a document with one texture and a material with the given extensions.
+fn material_extension_errors(extensions: serde_json::Value) -> Vec<(String, ValidationErrorKind)> {
+    validation_errors(serde_json::json!({
+        "asset": {"version": "2.0"},
+        "images": [{"uri": "image.png"}],
+        "textures": [{"source": 0}],
+        "materials": [{"extensions": extensions}]
+    }))
+}
+
+#[test]
+fn validate_material_extension_textures() {
+    let texture = serde_json::json!({"index": 999});
+    let errors = material_extension_errors(serde_json::json!({
+        "KHR_materials_anisotropy": {"anisotropyTexture": texture},
+        "KHR_materials_clearcoat": {
+            "clearcoatTexture": texture,
+            "clearcoatRoughnessTexture": {"index": 0}
+        },
+        "KHR_materials_iridescence": {
+            "iridescenceTexture": texture,
+            "iridescenceThicknessTexture": texture
+        },
+        "KHR_materials_sheen": {"sheenColorTexture": texture, "sheenRoughnessTexture": texture},
+        "KHR_materials_specular": {"specularTexture": texture, "specularColorTexture": texture},
+        "KHR_materials_transmission": {"transmissionTexture": texture},
+        "KHR_materials_volume": {"thicknessTexture": texture},
+        "ADOBE_materials_clearcoat_specular": {"clearcoatSpecularTexture": texture},
+        "ADOBE_materials_clearcoat_tint": {"clearcoatTintTexture": texture},
+        "ADOBE_materials_thin_transparency": {"transmissionTexture": texture}
+    }));
+    let pointers: Vec<_> = errors
+        .iter()
+        .map(|(pointer, kind)| {
+            assert_eq!(
+                *kind,
+                ValidationErrorKind::IndexOutOfRange {
+                    index: 999,
+                    count: 1
+                }
+            );
+            pointer.strip_prefix("/materials/0/extensions/").unwrap()
+        })
+        .collect();
+    assert_eq!(
+        pointers,
+        [
+            "KHR_materials_anisotropy/anisotropyTexture/index",
+            "KHR_materials_clearcoat/clearcoatTexture/index",
+            "KHR_materials_iridescence/iridescenceTexture/index",
+            "KHR_materials_iridescence/iridescenceThicknessTexture/index",
+            "KHR_materials_sheen/sheenColorTexture/index",
+            "KHR_materials_sheen/sheenRoughnessTexture/index",
+            "KHR_materials_specular/specularTexture/index",
+            "KHR_materials_specular/specularColorTexture/index",
+            "KHR_materials_transmission/transmissionTexture/index",
+            "KHR_materials_volume/thicknessTexture/index",
+            "ADOBE_materials_clearcoat_specular/clearcoatSpecularTexture/index",
+            "ADOBE_materials_clearcoat_tint/clearcoatTintTexture/index",
+            "ADOBE_materials_thin_transparency/transmissionTexture/index",
+        ]
+    );
+}
+
+#[test]
+fn validate_material_extension_normal_textures() {
+    let errors = material_extension_errors(serde_json::json!({
+        "KHR_materials_clearcoat": {"clearcoatNormalTexture": {"index": 2, "scale": 0.5}}
+    }));
+    assert_eq!(
+        errors,
+        [(
+            "/materials/0/extensions/KHR_materials_clearcoat/clearcoatNormalTexture/index"
+                .to_string(),
+            ValidationErrorKind::IndexOutOfRange { index: 2, count: 1 }
+        )]
+    );
+}
+
+#[test]
+fn validate_material_extension_packing_textures() {
+    let errors = material_extension_errors(serde_json::json!({
+        "MSFT_packing_normalRoughnessMetallic": {
+            "normalRoughnessMetallicTexture": {"index": 1}
+        },
+        "MSFT_packing_occlusionRoughnessMetallic": {
+            "occlusionRoughnessMetallicTexture": {"index": 0},
+            "roughnessMetallicOcclusionTexture": {"index": 3},
+            "normalTexture": {"index": -1}
+        }
+    }));
+    let pointer = "/materials/0/extensions";
+    assert_eq!(
+        errors,
+        [
+            (
+                format!("{pointer}/MSFT_packing_normalRoughnessMetallic/normalRoughnessMetallicTexture/index"),
+                ValidationErrorKind::IndexOutOfRange { index: 1, count: 1 }
+            ),
+            (
+                format!("{pointer}/MSFT_packing_occlusionRoughnessMetallic/roughnessMetallicOcclusionTexture/index"),
+                ValidationErrorKind::IndexOutOfRange { index: 3, count: 1 }
+            ),
+            (
+                format!("{pointer}/MSFT_packing_occlusionRoughnessMetallic/normalTexture/index"),
+                ValidationErrorKind::InvalidIndex("-1".to_string())
+            ),
+        ]
+    );
+}
+
+#[test]
+fn validate_node_hierarchy() {
+    let errors = validation_errors(serde_json::json!({
+        "asset": {"version": "2.0"},
+        "scenes": [{"nodes": [0, 3]}],
+        "nodes": [{"children": [1]}, {"children": [2]}, {"children": [1]}, {"children": [1]}]
+    }));
+    assert_eq!(
+        errors,
+        [
+            (
+                "/nodes/2/children/0".to_string(),
+                ValidationErrorKind::MultipleParents {
+                    node: 1,
+                    first: 0,
+                    second: 2
+                }
+            ),
+            (
+                "/nodes/3/children/0".to_string(),
+                ValidationErrorKind::MultipleParents {
+                    node: 1,
+                    first: 0,
+                    second: 3
+                }
+            ),
+        ]
+    );
+
+    let errors = validation_errors(serde_json::json!({
+        "asset": {"version": "2.0"},
+        "scenes": [{"nodes": [1]}],
+        "nodes": [{"children": [1]}, {"children": [2]}, {"children": [0]}, {"children": [3]}]
+    }));
+    assert_eq!(
+        errors,
+        [
+            ("/nodes/0".to_string(), ValidationErrorKind::NodeCycle(0)),
+            ("/nodes/3".to_string(), ValidationErrorKind::NodeCycle(3)),
+            (
+                "/scenes/0/nodes/0".to_string(),
+                ValidationErrorKind::NonRootSceneNode(1)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn import_with_validation() {
+    let json = br#"{"asset": {"version": "2.0"}, "scene": 0}"#;
+    assert!(import_slice(json).is_ok());
+
+    let options = ImportOptions { validate: true };
+    let result = import_slice_with_options(json, None, &options);
+    let Err(ImportError::Validation(errors)) = result else {
+        panic!("expected validation to fail");
+    };
+    assert_eq!(
+        errors[0].to_string(),
+        "/scene: Index 0 is out of range for a collection of 0 objects"
+    );
+    assert_eq!(
+        ImportError::Validation(errors).to_string(),
+        "Document failed validation with 2 errors, the first is /scene: Index 0 is out of range \
+         for a collection of 0 objects"
+    );
+    assert_eq!(
+        ImportError::Validation(Vec::new()).to_string(),
+        "Document failed validation with no errors"
+    );
+}
+
+#[test]
+fn validate_schema_constraints() {
+    let errors = validation_errors(serde_json::json!({
+        "asset": {"version": "2"},
+        "scene": 0,
+        "buffers": [{"byteLength": 4}],
+        "bufferViews": [{"buffer": 0, "byteLength": 4, "byteStride": 2}],
+        "cameras": [
+            {"type": "perspective", "perspective": {"yfov": 0, "znear": 0.1}},
+            {"type": "orthographic", "perspective": {"yfov": 1, "znear": 0.1}}
+        ],
+        "nodes": [{"matrix": [2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 1], "translation": [1, 0, 0]}]
+    }));
+    assert_eq!(
+        errors,
+        [
+            (
+                "/scene".to_string(),
+                ValidationErrorKind::IndexOutOfRange { index: 0, count: 0 }
+            ),
+            (
+                "/scene".to_string(),
+                ValidationErrorKind::MissingDependency {
+                    property: "scene".to_string(),
+                    dependency: "scenes".to_string()
+                }
+            ),
+            (
+                "/asset/version".to_string(),
+                ValidationErrorKind::PatternMismatch {
+                    value: "2".to_string(),
+                    pattern: "^[0-9]+\\.[0-9]+$".to_string()
+                }
+            ),
+            (
+                "/bufferViews/0/byteStride".to_string(),
+                ValidationErrorKind::BelowMinimum {
+                    value: 2.0,
+                    minimum: 4.0
+                }
+            ),
+            (
+                "/cameras/0/perspective/yfov".to_string(),
+                ValidationErrorKind::NotAboveMinimum {
+                    value: 0.0,
+                    minimum: 0.0
+                }
+            ),
+            (
+                "/cameras/1".to_string(),
+                ValidationErrorKind::OneOfMismatch(0)
+            ),
+            (
+                "/nodes/0".to_string(),
+                ValidationErrorKind::ForbiddenCombination
+            ),
+        ]
+    );
+}
+
+#[test]
+fn validate_generated_type() {
+    let node: crate::schema::Node = serde_json::from_value(serde_json::json!({
+        "rotation": [0, 0, 2, 1],
+        "skin": 0
+    }))
+    .unwrap();
+    let errors = node.validate();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(
+        errors[0].to_string(),
+        "/skin: Property skin requires mesh to be present"
+    );
+    assert_eq!(
+        errors[1].to_string(),
+        "/rotation/2: Value 2 is greater than the maximum of 1"
+    );
+}
+
+fn make_glb(version: u32, chunks: &[(u32, &[u8])]) -> Vec<u8> {
+    let length = 12 + chunks.iter().map(|(_, c)| 8 + c.len()).sum::<usize>();
+    let mut data = Vec::new();
+    data.extend_from_slice(b"glTF");
+    data.extend_from_slice(&version.to_le_bytes());
+    data.extend_from_slice(&(length as u32).to_le_bytes());
+    for (chunk_type, contents) in chunks {
+        data.extend_from_slice(&(contents.len() as u32).to_le_bytes());
+        data.extend_from_slice(&chunk_type.to_le_bytes());
+        data.extend_from_slice(contents);
+    }
+    data
+}
+
+const JSON_CHUNK: u32 = 0x4E4F534A;
+const BIN_CHUNK: u32 = 0x004E4942;
+const GLB_JSON: &[u8] = br#"{"asset":{"version":"2.0"},"buffers":[{"byteLength":6}]}    "#;
+
+#[test]
+fn import_glb() {
+    let bin = [1u8, 2, 3, 4, 5, 6, 0, 0];
+    let data = make_glb(
+        2,
+        &[(JSON_CHUNK, GLB_JSON), (0x1234, &[0; 4]), (BIN_CHUNK, &bin)],
+    );
+    assert!(matches!(
+        import_slice(&data),
+        Err(ImportError::GlbUnexpectedChunk { offset: 92, .. })
+    ));
+
+    let data = make_glb(
+        2,
+        &[(JSON_CHUNK, GLB_JSON), (BIN_CHUNK, &bin), (0x1234, &[0; 4])],
+    );
+    let gltf = import_slice(&data).unwrap();
+    assert_eq!(gltf.blob(), Some(&bin[..]));
+    assert_eq!(gltf.buffer_data(0), Some(&bin[..6]));
+}
+
+#[test]
+fn import_malformed_glb() {
+    let data = make_glb(1, &[(JSON_CHUNK, GLB_JSON)]);
+    assert!(matches!(
+        import_slice(&data),
+        Err(ImportError::GlbUnsupportedVersion(1))
+    ));
+
+    let mut data = make_glb(2, &[(JSON_CHUNK, GLB_JSON)]);
+    data.extend_from_slice(&[0; 4]);
+    assert!(matches!(
+        import_slice(&data),
+        Err(ImportError::GlbLengthMismatch { .. })
+    ));
+
+    let data = make_glb(2, &[(JSON_CHUNK, &GLB_JSON[1..])]);
+    assert!(matches!(
+        import_slice(&data),
+        Err(ImportError::GlbMisalignedChunk { offset: 12 })
+    ));
+
+    let data = make_glb(2, &[(BIN_CHUNK, &[0; 4])]);
+    assert!(matches!(
+        import_slice(&data),
+        Err(ImportError::GlbMissingJsonChunk)
+    ));
+
+    let mut data = make_glb(2, &[(JSON_CHUNK, GLB_JSON)]);
+    data[12] += 4;
+    assert!(matches!(
+        import_slice(&data),
+        Err(ImportError::GlbTruncatedChunk { offset: 12 })
+    ));
+
+    assert!(matches!(
+        import_slice(b"glTF\x02\x00"),
+        Err(ImportError::GlbInvalidHeader)
+    ));
+}
+
+struct MemoryResolver(HashMap<&'static str, Vec<u8>>);
+
+impl Resolver for MemoryResolver {
+    fn resolve(&self, path: &str) -> io::Result<Vec<u8>> {
+        self.0
+            .get(path)
+            .cloned()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+}
+
+#[test]
+fn import_data_uri() {
+    let json = br#"{
+        "asset": {"version": "2.0"},
+        "buffers": [
+            {"byteLength": 4, "uri": "data:application/octet-stream;base64,AQIDBAU="},
+            {"byteLength": 3, "uri": "data:,a%20b"}
+        ],
+        "bufferViews": [{"buffer": 0, "byteOffset": 1, "byteLength": 2}],
+        "images": [{"bufferView": 0, "mimeType": "image/png"}]
+    }"#;
+    let gltf = import_slice(json).unwrap();
+    assert_eq!(gltf.buffer_data(0), Some(&[1u8, 2, 3, 4][..]));
+    assert_eq!(gltf.buffer_data(1), Some(&b"a b"[..]));
+    assert_eq!(gltf.buffer_view_data(0), Some(&[2u8, 3][..]));
+    assert_eq!(gltf.image_data(0), Some(&[2u8, 3][..]));
+
+    let json =
+        br#"{"asset":{"version":"2.0"},"buffers":[{"byteLength":4,"uri":"data:;base64,!!!!"}]}"#;
+    assert!(matches!(
+        import_slice(json),
+        Err(ImportError::MalformedDataUri)
+    ));
+}
+
+#[test]
+fn import_external_uri() {
+    let json = br#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 4, "uri": "data/my%20buffer.bin"}],
+        "images": [{"uri": "image.png"}]
+    }"#;
+    assert!(matches!(
+        import_slice(json),
+        Err(ImportError::ExternalReference(path)) if path == "data/my buffer.bin"
+    ));
+
+    let mut resolver = MemoryResolver(HashMap::from([
+        ("data/my buffer.bin", vec![1, 2, 3, 4, 5]),
+        ("image.png", vec![0x89, b'P', b'N', b'G']),
+    ]));
+    let gltf = import_with_resolver(json, &resolver).unwrap();
+    assert_eq!(gltf.buffer_data(0), Some(&[1u8, 2, 3, 4][..]));
+    assert_eq!(gltf.image_data(0), Some(&[0x89, b'P', b'N', b'G'][..]));
+
+    resolver.0.insert("data/my buffer.bin", vec![1, 2]);
+    assert!(matches!(
+        import_with_resolver(json, &resolver),
+        Err(ImportError::BufferTooShort {
+            index: 0,
+            expected: 4,
+            actual: 2
+        })
+    ));
+
+    resolver.0.remove("image.png");
+    resolver.0.insert("data/my buffer.bin", vec![1, 2, 3, 4]);
+    assert!(matches!(
+        import_with_resolver(json, &resolver),
+        Err(ImportError::Resolve { path, .. }) if path == "image.png"
+    ));
+}
+
+#[test]
+fn import_relative_to_file() {
+    let dir = std::env::temp_dir().join(format!("gltf_for_rust_import_{}", std::process::id()));
+    fs::create_dir_all(dir.join("data")).unwrap();
+    fs::write(dir.join("data/my buffer.bin"), [1u8, 2, 3, 4]).unwrap();
+    fs::write(
+        dir.join("asset.gltf"),
+        br#"{"asset":{"version":"2.0"},"buffers":[{"byteLength":4,"uri":"data/my%20buffer.bin"}]}"#,
+    )
+    .unwrap();
+
+    let gltf = import(dir.join("asset.gltf").to_str().unwrap());
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(gltf.unwrap().buffer_data(0), Some(&[1u8, 2, 3, 4][..]));
+}
+
+#[test]
+fn import_rejects_paths_outside_base() {
+    let dir = std::env::temp_dir().join(format!("gltf_for_rust_escape_{}", std::process::id()));
+    fs::create_dir_all(dir.join("asset")).unwrap();
+    fs::write(dir.join("secret.bin"), [1u8, 2, 3, 4]).unwrap();
+    let resolver = FileSystemResolver::new(dir.join("asset"));
+    let parent = resolver.resolve("../secret.bin");
+    let absolute = resolver.resolve(dir.join("secret.bin").to_str().unwrap());
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(parent.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(absolute.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+
+    let json = br#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 4, "uri": "data/../../secret.bin"}]
+    }"#;
+    assert!(matches!(
+        import_with_resolver(json, &resolver),
+        Err(ImportError::Resolve { path, .. }) if path == "data/../../secret.bin"
+    ));
+}
+
+#[test]
+fn import_rejects_uri_schemes() {
+    let json = br#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 4, "uri": "http://example.com/buffer.bin"}]
+    }"#;
+    let resolver = MemoryResolver(HashMap::from([(
+        "http://example.com/buffer.bin",
+        vec![0; 4],
+    )]));
+    assert!(matches!(
+        import_with_resolver(json, &resolver),
+        Err(ImportError::UnsupportedScheme(uri)) if uri == "http://example.com/buffer.bin"
+    ));
+}
+
+fn data_uri(bytes: &[u8]) -> String {
+    format!(
+        "data:application/octet-stream;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(bytes)
+    )
+}
+
+#[test]
+fn read_accessors() {
+    let mut bin = Vec::new();
+    for (position, color) in [
+        ([1f32, 2.0, 3.0], [255u8, 0, 51, 0]),
+        ([4.0, 5.0, 6.0], [0, 255, 0, 255]),
+    ] {
+        position
+            .iter()
+            .for_each(|p| bin.extend_from_slice(&p.to_le_bytes()));
+        bin.extend_from_slice(&color);
+    }
+    [0u16, 1, 65535]
+        .iter()
+        .for_each(|i| bin.extend_from_slice(&i.to_le_bytes()));
+    bin.extend_from_slice(&[0, 0]);
+    bin.extend_from_slice(&[1, 2, 0, 0, 3, 4, 0, 0]);
+    bin.extend_from_slice(&[0x80, 0x7f, 0, 0]);
+
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": {}, "uri": "{}"}}],
+            "bufferViews": [
+                {{"buffer": 0, "byteLength": 32, "byteStride": 16}},
+                {{"buffer": 0, "byteOffset": 32, "byteLength": 6}},
+                {{"buffer": 0, "byteOffset": 40, "byteLength": 8}},
+                {{"buffer": 0, "byteOffset": 48, "byteLength": 2}}
+            ],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3"}},
+                {{"bufferView": 0, "byteOffset": 12, "componentType": 5121, "normalized": true, "count": 2, "type": "VEC4"}},
+                {{"bufferView": 1, "componentType": 5123, "count": 3, "type": "SCALAR"}},
+                {{"componentType": 5126, "count": 2, "type": "VEC2"}},
+                {{"bufferView": 2, "componentType": 5121, "count": 1, "type": "MAT2"}},
+                {{"bufferView": 3, "componentType": 5120, "normalized": true, "count": 2, "type": "SCALAR"}},
+                {{"bufferView": 1, "componentType": 5123, "count": 4, "type": "SCALAR"}},
+                {{"bufferView": 0, "byteOffset": 2, "componentType": 5126, "count": 1, "type": "SCALAR"}}
+            ]
+        }}"#,
+        bin.len(),
+        data_uri(&bin)
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+    let accessor = |index| gltf.accessor(index).unwrap();
+
+    let positions = accessor(0).read::<[f32; 3]>().unwrap();
+    assert_eq!(positions.len(), 2);
+    assert_eq!(
+        positions.collect::<Vec<_>>(),
+        [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]
+    );
+
+    assert_eq!(
+        accessor(1).read::<[f32; 4]>().unwrap().collect::<Vec<_>>(),
+        [[1.0, 0.0, 0.2, 0.0], [0.0, 1.0, 0.0, 1.0]]
+    );
+    assert_eq!(
+        accessor(1).read::<[u8; 4]>().unwrap().collect::<Vec<_>>(),
+        [[255, 0, 51, 0], [0, 255, 0, 255]]
+    );
+
+    assert_eq!(accessor(2).component_type(), ComponentType::UnsignedShort);
+    assert_eq!(
+        accessor(2).read::<u32>().unwrap().collect::<Vec<_>>(),
+        [0, 1, 65535]
+    );
+    assert!(matches!(
+        accessor(2).read::<u8>(),
+        Err(AccessorError::IncompatibleComponentType {
+            component_type: ComponentType::UnsignedShort,
+            ..
+        })
+    ));
+    assert!(matches!(
+        accessor(2).read::<[u32; 2]>(),
+        Err(AccessorError::ComponentCountMismatch {
+            expected: 1,
+            requested: 2
+        })
+    ));
+
+    assert_eq!(
+        accessor(3).read::<[f32; 2]>().unwrap().collect::<Vec<_>>(),
+        [[0.0, 0.0], [0.0, 0.0]]
+    );
+    assert_eq!(
+        accessor(4).read::<[u8; 4]>().unwrap().collect::<Vec<_>>(),
+        [[1, 2, 3, 4]]
+    );
+    assert_eq!(
+        accessor(5).read::<f32>().unwrap().collect::<Vec<_>>(),
+        [-1.0, 1.0]
+    );
+    assert!(matches!(
+        accessor(6).read::<u16>(),
+        Err(AccessorError::OutOfBounds {
+            required: 8,
+            available: 6
+        })
+    ));
+    assert!(matches!(
+        accessor(7).read::<f32>(),
+        Err(AccessorError::MisalignedOffset(2))
+    ));
+}
+
+#[test]
+fn read_sparse_accessors() {
+    let mut bin = Vec::new();
+    [1f32, 2.0, 3.0, 4.0, 5.0]
+        .iter()
+        .for_each(|v| bin.extend_from_slice(&v.to_le_bytes()));
+    [1u16, 3]
+        .iter()
+        .for_each(|i| bin.extend_from_slice(&i.to_le_bytes()));
+    [20f32, 40.0]
+        .iter()
+        .for_each(|v| bin.extend_from_slice(&v.to_le_bytes()));
+    bin.extend_from_slice(&[0, 4, 3, 1]);
+    bin.extend_from_slice(&[5, 0, 0, 0]);
+    [7f32, 9.0]
+        .iter()
+        .for_each(|v| bin.extend_from_slice(&v.to_le_bytes()));
+
+    let sparse = |count, indices, offset, component_type, values| {
+        format!(
+            r#""sparse": {{"count": {count}, "indices": {{"bufferView": {indices}, "byteOffset": {offset}, "componentType": {component_type}}}, "values": {{"bufferView": {values}}}}}"#
+        )
+    };
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": {}, "uri": "{}"}}],
+            "bufferViews": [
+                {{"buffer": 0, "byteLength": 20}},
+                {{"buffer": 0, "byteOffset": 20, "byteLength": 4}},
+                {{"buffer": 0, "byteOffset": 24, "byteLength": 8}},
+                {{"buffer": 0, "byteOffset": 32, "byteLength": 4}},
+                {{"buffer": 0, "byteOffset": 36, "byteLength": 4}},
+                {{"buffer": 0, "byteOffset": 40, "byteLength": 8}}
+            ],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 5, "type": "SCALAR", {}}},
+                {{"componentType": 5126, "count": 5, "type": "SCALAR", {}}},
+                {{"componentType": 5126, "count": 5, "type": "SCALAR", {}}},
+                {{"componentType": 5126, "count": 5, "type": "SCALAR", {}}},
+                {{"componentType": 5126, "count": 5, "type": "SCALAR", {}}},
+                {{"componentType": 5126, "count": 5, "type": "SCALAR", {}}}
+            ]
+        }}"#,
+        bin.len(),
+        data_uri(&bin),
+        sparse(2, 1, 0, 5123, 2),
+        sparse(2, 3, 0, 5121, 5),
+        sparse(2, 3, 2, 5121, 5),
+        sparse(1, 4, 0, 5121, 5),
+        sparse(1, 4, 0, 5126, 5),
+        sparse(3, 1, 0, 5123, 2),
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+    let accessor = |index| gltf.accessor(index).unwrap();
+
+    assert!(accessor(0).is_sparse());
+    assert_eq!(
+        accessor(0).read::<f32>().unwrap().collect::<Vec<_>>(),
+        [1.0, 20.0, 3.0, 40.0, 5.0]
+    );
+    assert_eq!(
+        accessor(1).read::<f32>().unwrap().collect::<Vec<_>>(),
+        [7.0, 0.0, 0.0, 0.0, 9.0]
+    );
+    assert!(matches!(
+        accessor(2).read::<f32>(),
+        Err(AccessorError::SparseIndicesNotIncreasing { position: 1 })
+    ));
+    assert!(matches!(
+        accessor(3).read::<f32>(),
+        Err(AccessorError::SparseIndexOutOfRange { index: 5, count: 5 })
+    ));
+    assert!(matches!(
+        accessor(4).read::<f32>(),
+        Err(AccessorError::InvalidSparseIndexType(5126))
+    ));
+    assert!(matches!(
+        accessor(5).read::<f32>(),
+        Err(AccessorError::OutOfBounds {
+            required: 6,
+            available: 4
+        })
+    ));
+}
+
+#[test]
+fn read_accessors_with_huge_count() {
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 16, "uri": "{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": 16}}],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 4611686018427387904, "type": "VEC4"}},
+                {{"componentType": 5126, "count": 5, "type": "SCALAR", "sparse": {{
+                    "count": 4611686018427387904,
+                    "indices": {{"bufferView": 0, "componentType": 5125}},
+                    "values": {{"bufferView": 0}}
+                }}}}
+            ]
+        }}"#,
+        data_uri(&[0; 16])
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+
+    // The size of the elements overflows, which must not wrap around into a size that fits
+    assert!(matches!(
+        gltf.accessor(0).unwrap().read::<[f32; 4]>(),
+        Err(AccessorError::OutOfBounds {
+            required: usize::MAX,
+            available: 16
+        })
+    ));
+    assert!(matches!(
+        gltf.accessor(1).unwrap().read::<f32>(),
+        Err(AccessorError::OutOfBounds {
+            required: usize::MAX,
+            available: 16
+        })
+    ));
+}
+
+#[test]
+fn report_semantic_errors() {
+    let mut bin = Vec::new();
+    for position in [[0f32, 0.0, 0.0], [1.0, 2.0, 3.0], [-1.0, 0.0, 0.0]] {
+        position
+            .iter()
+            .for_each(|p| bin.extend_from_slice(&p.to_le_bytes()));
+    }
+    bin.extend_from_slice(&[0, 1, 5, 255]);
+    for joint in 0u32..12 {
+        bin.extend_from_slice(&(joint % 3).to_le_bytes());
+    }
+
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": {}, "uri": "{}"}}],
+            "bufferViews": [
+                {{"buffer": 0, "byteLength": 36}},
+                {{"buffer": 0, "byteOffset": 36, "byteLength": 4}},
+                {{"buffer": 0, "byteOffset": 40, "byteLength": 48}},
+                {{"buffer": 0, "byteOffset": 80, "byteLength": 16}}
+            ],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [-1, 0, 0], "max": [1, 2, 4]}},
+                {{"bufferView": 1, "componentType": 5121, "count": 4, "type": "SCALAR"}},
+                {{"bufferView": 2, "componentType": 5125, "count": 3, "type": "VEC4"}}
+            ],
+            "meshes": [{{"primitives": [{{"attributes": {{"POSITION": 0, "JOINTS_0": 2}}, "indices": 1}}]}}],
+            "nodes": [{{"mesh": 0}}]
+        }}"#,
+        bin.len(),
+        data_uri(&bin)
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+    let report = report(&gltf);
+
+    let messages: Vec<_> = report
+        .messages
+        .iter()
+        .map(|message| (message.severity, message.code, message.pointer.as_str()))
+        .collect();
+    let primitive = "/meshes/0/primitives/0";
+    assert_eq!(
+        messages,
+        [
+            (
+                Severity::Error,
+                Code::BufferViewTooLong,
+                "/bufferViews/3/byteLength"
+            ),
+            (
+                Severity::Error,
+                Code::AccessorMaxMismatch,
+                "/accessors/0/max/2"
+            ),
+            (
+                Severity::Error,
+                Code::MeshPrimitiveAttributeInvalidFormat,
+                &format!("{primitive}/attributes/JOINTS_0")
+            ),
+            (
+                Severity::Error,
+                Code::MeshPrimitiveJointsWeightsMismatch,
+                &format!("{primitive}/attributes/JOINTS_0")
+            ),
+            (
+                Severity::Error,
+                Code::AccessorIndexOutOfRange,
+                &format!("{primitive}/indices")
+            ),
+            (
+                Severity::Error,
+                Code::AccessorIndexPrimitiveRestart,
+                &format!("{primitive}/indices")
+            ),
+            (
+                Severity::Error,
+                Code::AccessorNonIndexUnsignedInt,
+                "/accessors/2/componentType"
+            ),
+            (
+                Severity::Warning,
+                Code::NodeSkinnedMeshWithoutSkin,
+                "/nodes/0/mesh"
+            ),
+        ]
+    );
+    assert!(report.has_errors());
+    assert_eq!(report.max_severity(), Some(Severity::Error));
+    assert_eq!(
+        report.messages[1].to_string(),
+        "error ACCESSOR_MAX_MISMATCH /accessors/0/max/2: Declared maximum 4 does not match the actual maximum 3"
+    );
+    assert_eq!(
+        report.messages[4].message,
+        "1 indices are out of range for 3 vertices, the first is 5 at position 2"
+    );
+}
+
+#[test]
+fn report_buffer_view_past_largest_offset() {
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 4, "uri": "{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteOffset": {}, "byteLength": 4}}]
+        }}"#,
+        data_uri(&[0; 4]),
+        i64::MAX
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+    let report = report(&gltf);
+
+    let message = report
+        .messages
+        .iter()
+        .find(|message| message.code == Code::BufferViewTooLong)
+        .unwrap();
+    assert_eq!(message.pointer, "/bufferViews/0/byteLength");
+    assert_eq!(
+        message.message,
+        "Buffer view ends past the largest possible offset, its buffer is 4 bytes long"
+    );
+}
+
+#[test]
+fn report_valid_asset() {
+    let mut bin = Vec::new();
+    for position in [[0f32, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]] {
+        position
+            .iter()
+            .for_each(|p| bin.extend_from_slice(&p.to_le_bytes()));
+    }
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 36, "uri": "{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": 36}}],
+            "accessors": [{{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]}}],
+            "meshes": [{{"primitives": [{{"attributes": {{"POSITION": 0}}}}]}}],
+            "nodes": [{{"mesh": 0}}],
+            "scenes": [{{"nodes": [0]}}]
+        }}"#,
+        data_uri(&bin)
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+    let report = report(&gltf);
+    assert_eq!(report.messages, []);
+    assert_eq!(report.max_severity(), None);
+}
+
+fn assert_close(actual: &[f32], expected: &[f32]) {
+    assert_eq!(actual.len(), expected.len());
+    for (actual_value, expected_value) in actual.iter().zip(expected) {
+        assert!(
+            (actual_value - expected_value).abs() < 1e-5,
+            "{actual:?} is not close to {expected:?}"
+        );
+    }
+}
+
+#[test]
+fn node_transforms() {
+    let half_sqrt = std::f32::consts::FRAC_1_SQRT_2;
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "scenes": [{{"nodes": [0, 3]}}],
+            "nodes": [
+                {{"translation": [1, 0, 0], "rotation": [0, 0, {half_sqrt}, {half_sqrt}], "children": [1]}},
+                {{"matrix": [2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 0, 1, 0, 1], "children": [2]}},
+                {{"scale": [1, 1, -1]}},
+                {{}},
+                {{"translation": [5, 5, 5]}}
+            ]
+        }}"#
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+    let nodes: Vec<_> = gltf.nodes().collect();
+
+    let transform = nodes[1].transform();
+    assert_close(&transform.translation, &[0.0, 1.0, 0.0]);
+    assert_close(&transform.rotation, &[0.0, 0.0, 0.0, 1.0]);
+    assert_close(&transform.scale, &[2.0, 2.0, 2.0]);
+    assert_close(
+        &nodes[0].transform().rotation,
+        &[0.0, 0.0, half_sqrt, half_sqrt],
+    );
+
+    let visits: Vec<_> = gltf.scene(0).unwrap().traverse().collect();
+    let order: Vec<_> = visits
+        .iter()
+        .map(|visit| (visit.node.index(), visit.parent))
+        .collect();
+    assert_eq!(order, [(0, None), (1, Some(0)), (2, Some(1)), (3, None)]);
+    #[rustfmt::skip]
+    assert_close(&visits[1].world, &[
+        0.0, 2.0, 0.0, 0.0,
+        -2.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, 2.0, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+    ]);
+    #[rustfmt::skip]
+    assert_close(&visits[2].world, &[
+        0.0, 2.0, 0.0, 0.0,
+        -2.0, 0.0, 0.0, 0.0,
+        0.0, 0.0, -2.0, 0.0,
+        0.0, 0.0, 0.0, 1.0,
+    ]);
+
+    let world_transforms = gltf.world_transforms(0).unwrap();
+    assert_eq!(world_transforms.len(), 5);
+    assert_eq!(world_transforms[2], Some(visits[2].world));
+    assert_eq!(world_transforms[4], None);
+    assert!(gltf.world_transforms(1).is_none());
+}
+
+#[test]
+fn decompose_transform() {
+    let transform = Transform {
+        translation: [1.0, -2.0, 3.0],
+        rotation: [0.5, -0.5, 0.5, 0.5],
+        scale: [-1.0, 2.0, 0.5],
+    };
+    let decomposed = Transform::from_matrix(&transform.matrix());
+    assert_close(&decomposed.matrix(), &transform.matrix());
+    assert_close(&decomposed.translation, &transform.translation);
+    assert_close(&decomposed.scale, &transform.scale);
+}
+
+#[test]
+fn navigate_wrappers() {
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "scene": 0,
+            "scenes": [{{"nodes": [0]}}],
+            "nodes": [
+                {{"mesh": 0, "skin": 0, "camera": 0, "children": [1]}},
+                {{"name": "joint"}}
+            ],
+            "cameras": [
+                {{"type": "perspective", "perspective": {{"yfov": 1.0, "znear": 0.1}}}},
+                {{"type": "orthographic"}}
+            ],
+            "skins": [{{"joints": [1, 5], "skeleton": 1}}],
+            "buffers": [{{"byteLength": 4, "uri": "{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteOffset": 1, "byteLength": 3}}],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5121, "count": 3, "type": "SCALAR", "min": [0], "max": [2]}}
+            ],
+            "images": [{{"bufferView": 0, "mimeType": "image/png"}}],
+            "samplers": [{{"magFilter": 9728}}],
+            "textures": [{{"sampler": 0, "source": 0}}, {{}}],
+            "materials": [
+                {{"normalTexture": {{"index": 0, "texCoord": 1}}, "alphaMode": "MASK"}},
+                {{"pbrMetallicRoughness": {{"baseColorTexture": {{"index": 1}}, "roughnessFactor": 0.5}}}}
+            ],
+            "meshes": [{{
+                "primitives": [
+                    {{"attributes": {{"POSITION": 0, "TEXCOORD_0": 7}}, "indices": 0, "material": 1, "targets": [{{"POSITION": 0}}]}},
+                    {{"attributes": {{}}, "mode": 1}}
+                ]
+            }}],
+            "animations": [{{
+                "channels": [{{"sampler": 0, "target": {{"node": 1, "path": "rotation"}}}}],
+                "samplers": [{{"input": 0, "output": 0, "interpolation": "STEP"}}]
+            }}]
+        }}"#,
+        data_uri(&[9, 0, 1, 2])
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+
+    let node = gltf.default_scene().unwrap().nodes().next().unwrap();
+    assert!(matches!(
+        node.camera().unwrap().projection(),
+        Some(Projection::Perspective(perspective)) if perspective.yfov == 1.0
+    ));
+    assert!(gltf.camera(1).unwrap().projection().is_none());
+    let skin = node.skin().unwrap();
+    let joints: Vec<_> = skin.joints().map(|joint| joint.name()).collect();
+    assert_eq!(joints, [Some("joint")]);
+    assert_eq!(skin.skeleton().unwrap().index(), 1);
+    assert!(skin.inverse_bind_matrices().is_none());
+
+    let mesh = node.mesh().unwrap();
+    let primitives: Vec<_> = mesh.primitives().collect();
+    assert_eq!(primitives.len(), 2);
+    let attributes: Vec<_> = primitives[0]
+        .attributes()
+        .map(|(semantic, accessor)| (semantic, accessor.index()))
+        .collect();
+    assert_eq!(attributes, [(Semantic::Position, 0)]);
+    assert!(primitives[0].tex_coords(0).is_none());
+    assert_eq!(
+        primitives[0].targets().next().unwrap().attributes().count(),
+        1
+    );
+    assert_eq!(primitives[0].mode(), Mode::Triangles);
+    assert_eq!(primitives[1].mode(), Mode::Lines);
+    assert!(primitives[1].indices().is_none() && primitives[1].material().is_none());
+
+    let indices = primitives[0].indices().unwrap();
+    assert_eq!((indices.min(), indices.max()), (&[0.0][..], &[2.0][..]));
+    let view = indices.buffer_view().unwrap();
+    assert_eq!(view.data(), Some(&[0u8, 1, 2][..]));
+    assert_eq!(view.buffer().unwrap().data(), Some(&[9u8, 0, 1, 2][..]));
+
+    // Material properties that are not specified surface their defaults
+    let material = primitives[0].material().unwrap();
+    let pbr = material.pbr_metallic_roughness();
+    assert_eq!(pbr.base_color_factor(), [1.0; 4]);
+    assert_eq!((pbr.metallic_factor(), pbr.roughness_factor()), (1.0, 0.5));
+    assert!(pbr
+        .base_color_texture()
+        .unwrap()
+        .texture()
+        .unwrap()
+        .source()
+        .is_none());
+    assert_eq!(material.alpha_mode(), &AlphaMode::Opaque);
+    assert_eq!(material.alpha_cutoff(), 0.5);
+    assert!(!material.double_sided());
+
+    let material = gltf.material(0).unwrap();
+    assert!(material.pbr_metallic_roughness().inner().is_none());
+    assert_eq!(material.pbr_metallic_roughness().metallic_factor(), 1.0);
+    assert_eq!(material.alpha_mode(), &AlphaMode::Mask);
+    let normal = material.normal_texture().unwrap();
+    assert_eq!((normal.tex_coord(), normal.scale()), (1, 1.0));
+    let texture = normal.texture().unwrap();
+    assert_eq!(
+        texture.sampler().unwrap().mag_filter(),
+        Some(MagFilter::Nearest)
+    );
+    assert_eq!(texture.sampler().unwrap().wrap_s(), WrapS::Repeat);
+    let image = texture.source().unwrap();
+    assert_eq!(image.mime_type(), Some(&MimeType::ImagePng));
+    assert_eq!(image.data(), Some(&[0u8, 1, 2][..]));
+
+    let animation = gltf.animations().next().unwrap();
+    let channel = animation.channels().next().unwrap();
+    assert_eq!(channel.target_node().unwrap().name(), Some("joint"));
+    assert_eq!(channel.target_path(), &Path::Rotation);
+    let sampler = channel.sampler().unwrap();
+    assert_eq!(sampler.interpolation(), &Interpolation::Step);
+    assert_eq!(sampler.input().unwrap().index(), 0);
+    assert_eq!(animation.samplers().len(), 1);
+}
+
+#[test]
+fn sample_animations() {
+    let half_sqrt = std::f32::consts::FRAC_1_SQRT_2;
+    #[rustfmt::skip]
+    let floats: &[f32] = &[
+        // Keyframe times
+        0.0, 1.0, 2.0,
+        0.0, 1.0,
+        // Translations
+        0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 2.0, 0.0,
+        // Rotations
+        0.0, 0.0, 0.0, 1.0, 0.0, 0.0, half_sqrt, half_sqrt,
+        // Weights of two morph targets, with in-tangents and out-tangents
+        0.0, 0.0, 0.0, 1.0, 1.0, 0.0,
+        0.0, 0.0, 1.0, 0.0, 0.0, 0.0,
+        // Scales
+        1.0, 1.0, 1.0, 2.0, 2.0, 2.0,
+    ];
+    let bin: Vec<u8> = floats.iter().flat_map(|f| f.to_le_bytes()).collect();
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "nodes": [{{"mesh": 0}}],
+            "meshes": [{{"primitives": [{{"attributes": {{}}}}], "weights": [0.25, 0.75]}}],
+            "buffers": [{{"byteLength": {}, "uri": "{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": {}}}],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 3, "type": "SCALAR"}},
+                {{"bufferView": 0, "byteOffset": 12, "componentType": 5126, "count": 2, "type": "SCALAR"}},
+                {{"bufferView": 0, "byteOffset": 20, "componentType": 5126, "count": 3, "type": "VEC3"}},
+                {{"bufferView": 0, "byteOffset": 56, "componentType": 5126, "count": 2, "type": "VEC4"}},
+                {{"bufferView": 0, "byteOffset": 88, "componentType": 5126, "count": 12, "type": "SCALAR"}},
+                {{"bufferView": 0, "byteOffset": 136, "componentType": 5126, "count": 2, "type": "VEC3"}}
+            ],
+            "animations": [
+                {{
+                    "channels": [
+                        {{"sampler": 0, "target": {{"node": 0, "path": "translation"}}}},
+                        {{"sampler": 1, "target": {{"node": 0, "path": "rotation"}}}},
+                        {{"sampler": 2, "target": {{"node": 0, "path": "weights"}}}},
+                        {{"sampler": 3, "target": {{"node": 0, "path": "scale"}}}},
+                        {{"sampler": 3, "target": {{"path": "pointer"}}}}
+                    ],
+                    "samplers": [
+                        {{"input": 0, "output": 2}},
+                        {{"input": 1, "output": 3}},
+                        {{"input": 1, "output": 4, "interpolation": "CUBICSPLINE"}},
+                        {{"input": 1, "output": 5, "interpolation": "STEP"}}
+                    ]
+                }},
+                {{
+                    "channels": [{{"sampler": 0, "target": {{"node": 0, "path": "scale"}}}}],
+                    "samplers": [{{"input": 0, "output": 5}}]
+                }},
+                {{
+                    "channels": [{{"sampler": 1, "target": {{"node": 0, "path": "scale"}}}}],
+                    "samplers": [{{"input": 0, "output": 5}}]
+                }}
+            ]
+        }}"#,
+        bin.len(),
+        data_uri(&bin),
+        bin.len()
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+    let rest = Pose::rest(&gltf);
+    assert_eq!(rest.transforms, [Transform::IDENTITY]);
+    assert_eq!(rest.weights, [vec![0.25, 0.75]]);
+
+    let evaluator = Evaluator::new(&gltf.animation(0).unwrap()).unwrap();
+    assert_eq!(evaluator.duration(), 2.0);
+
+    let mut pose = rest.clone();
+    evaluator.sample(0.5, &mut pose);
+    let transform = pose.transforms[0];
+    assert_close(&transform.translation, &[0.5, 0.0, 0.0]);
+    let (sin, cos) = std::f32::consts::FRAC_PI_8.sin_cos();
+    assert_close(&transform.rotation, &[0.0, 0.0, sin, cos]);
+    assert_close(&transform.scale, &[1.0, 1.0, 1.0]);
+    assert_close(&pose.weights[0], &[0.625, 0.5]);
+
+    evaluator.sample(1.5, &mut pose);
+    let transform = pose.transforms[0];
+    assert_close(&transform.translation, &[1.0, 1.0, 0.0]);
+    assert_close(&transform.rotation, &[0.0, 0.0, half_sqrt, half_sqrt]);
+    assert_close(&transform.scale, &[2.0, 2.0, 2.0]);
+    assert_close(&pose.weights[0], &[1.0, 0.0]);
+
+    // Times before the first keyframe are clamped
+    evaluator.sample(-1.0, &mut pose);
+    assert_close(&pose.transforms[0].translation, &[0.0; 3]);
+    assert_close(&pose.weights[0], &[0.0, 1.0]);
+
+    assert!(matches!(
+        Evaluator::new(&gltf.animation(1).unwrap()),
+        Err(AnimationError::KeyframeMismatch {
+            sampler: 0,
+            inputs: 3,
+            outputs: 2
+        })
+    ));
+    assert!(matches!(
+        Evaluator::new(&gltf.animation(2).unwrap()),
+        Err(AnimationError::MissingSampler {
+            channel: 0,
+            sampler: 1
+        })
+    ));
+}
+
+#[test]
+fn skin_vertices() {
+    let half_sqrt = std::f32::consts::FRAC_1_SQRT_2;
+    let mut inverse_bind_matrices = [transform::IDENTITY; 2];
+    inverse_bind_matrices[1][13] = -1.0;
+    #[rustfmt::skip]
+    let vertices: &[f32] = &[
+        // Positions
+        0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 2.0, 0.0,
+        // Normals
+        1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0,
+        // Weights
+        1.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0,
+    ];
+    let mut bin: Vec<u8> = inverse_bind_matrices
+        .iter()
+        .flatten()
+        .chain(vertices)
+        .flat_map(|f| f.to_le_bytes())
+        .collect();
+    bin.extend_from_slice(&[0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, 0]);
+
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "scenes": [{{"nodes": [0, 1]}}],
+            "nodes": [
+                {{"mesh": 0, "skin": 0, "translation": [10, 0, 0]}},
+                {{"children": [2]}},
+                {{"translation": [0, 1, 0]}}
+            ],
+            "skins": [{{"joints": [1, 2], "inverseBindMatrices": 0}}],
+            "meshes": [{{"primitives": [{{
+                "attributes": {{"POSITION": 1, "NORMAL": 2, "WEIGHTS_0": 3, "JOINTS_0": 4}}
+            }}]}}],
+            "buffers": [{{"byteLength": {}, "uri": "{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": {}}}],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 2, "type": "MAT4"}},
+                {{"bufferView": 0, "byteOffset": 128, "componentType": 5126, "count": 3, "type": "VEC3"}},
+                {{"bufferView": 0, "byteOffset": 164, "componentType": 5126, "count": 3, "type": "VEC3"}},
+                {{"bufferView": 0, "byteOffset": 200, "componentType": 5126, "count": 3, "type": "VEC4"}},
+                {{"bufferView": 0, "byteOffset": 248, "componentType": 5121, "count": 3, "type": "VEC4"}}
+            ]
+        }}"#,
+        bin.len(),
+        data_uri(&bin),
+        bin.len()
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+    let node = gltf.node(0).unwrap();
+    let skin = node.skin().unwrap();
+    let primitive = node.mesh().unwrap().primitives().next().unwrap();
+
+    // Rotate the second joint a quarter turn around z
+    let mut pose = Pose::rest(&gltf);
+    pose.transforms[2].rotation = [0.0, 0.0, half_sqrt, half_sqrt];
+    let world_matrices = pose.world_matrices(&gltf);
+    assert_close(&world_matrices[2][12..15], &[0.0, 1.0, 0.0]);
+
+    let joint_matrices = skinning::joint_matrices(&skin, &node, &world_matrices).unwrap();
+    assert_close(&joint_matrices[0][12..15], &[-10.0, 0.0, 0.0]);
+    let skinned = skinning::skin_primitive(&primitive, &joint_matrices).unwrap();
+    assert_close(
+        skinned.positions.as_flattened(),
+        &[-10.0, 0.0, 0.0, -10.0, 1.0, 0.0, -11.0, 1.0, 0.0],
+    );
+    assert_close(
+        skinned.normals.as_flattened(),
+        &[1.0, 0.0, 0.0, half_sqrt, half_sqrt, 0.0, 0.0, 1.0, 0.0],
+    );
+
+    let influences = Influences {
+        joints: vec![vec![[2, 0, 0, 0]]],
+        weights: vec![vec![[1.0, 0.0, 0.0, 0.0]]],
+    };
+    assert!(matches!(
+        skinning::skin(&[[0.0; 3]], &[], &influences, &joint_matrices),
+        Err(SkinningError::JointOutOfRange {
+            vertex: 0,
+            joint: 2,
+            joints: 2
+        })
+    ));
+    assert!(matches!(
+        skinning::skin(&[[0.0; 3]; 2], &[], &influences, &joint_matrices),
+        Err(SkinningError::CountMismatch {
+            expected: 2,
+            actual: 1,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn read_influences_with_mismatched_sets() {
+    let mut bin: Vec<u8> = [0.0f32; 6]
+        .iter()
+        .chain(&[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0])
+        .flat_map(|f| f.to_le_bytes())
+        .collect();
+    bin.extend_from_slice(&[0; 8]);
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "meshes": [{{"primitives": [{{
+                "attributes": {{
+                    "POSITION": 0,
+                    "WEIGHTS_0": 1, "JOINTS_0": 2,
+                    "WEIGHTS_1": 3, "JOINTS_1": 4
+                }}
+            }}]}}],
+            "buffers": [{{"byteLength": {}, "uri": "{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": {}}}],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3"}},
+                {{"bufferView": 0, "byteOffset": 24, "componentType": 5126, "count": 2, "type": "VEC4"}},
+                {{"bufferView": 0, "byteOffset": 56, "componentType": 5121, "count": 2, "type": "VEC4"}},
+                {{"bufferView": 0, "byteOffset": 24, "componentType": 5126, "count": 1, "type": "VEC4"}},
+                {{"bufferView": 0, "byteOffset": 56, "componentType": 5121, "count": 1, "type": "VEC4"}}
+            ]
+        }}"#,
+        bin.len(),
+        data_uri(&bin),
+        bin.len()
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+    let primitive = gltf.mesh(0).unwrap().primitives().next().unwrap();
+    assert!(matches!(
+        Influences::read(&primitive),
+        Err(SkinningError::CountMismatch {
+            semantic: Semantic::Joints(1),
+            expected: 2,
+            actual: 1
+        })
+    ));
+
+    // Influences built by hand are checked when skinning instead of panicking
+    let influences = Influences {
+        joints: vec![vec![[0; 4]; 2], vec![[0; 4]]],
+        weights: vec![vec![[1.0, 0.0, 0.0, 0.0]; 2], vec![[0.0; 4]]],
+    };
+    assert!(matches!(
+        skinning::skin(&[[0.0; 3]; 2], &[], &influences, &[transform::IDENTITY]),
+        Err(SkinningError::CountMismatch {
+            semantic: Semantic::Joints(1),
+            expected: 2,
+            actual: 1
+        })
+    ));
+}
+
+#[test]
+fn invert_matrix() {
+    let transform = Transform {
+        translation: [1.0, -2.0, 3.0],
+        rotation: [0.5, -0.5, 0.5, 0.5],
+        scale: [-1.0, 2.0, 0.5],
+    };
+    let matrix = transform.matrix();
+    let inverse = transform::invert(&matrix).unwrap();
+    assert_close(
+        &transform::multiply(&inverse, &matrix),
+        &transform::IDENTITY,
+    );
+    assert!(transform::invert(&[0.0; 16]).is_none());
+}
+
+#[test]
+fn blend_morph_targets() {
+    #[rustfmt::skip]
+    let floats: &[f32] = &[
+        // Positions
+        0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+        // Normals
+        0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0,
+        // Sparse position displacement of the second vertex
+        0.0, 2.0, 0.0,
+        // Normal displacements
+        1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0,
+    ];
+    let mut bin: Vec<u8> = floats.iter().flat_map(|f| f.to_le_bytes()).collect();
+    bin.extend_from_slice(&[1, 0, 0, 0]);
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "nodes": [{{"mesh": 0}}, {{"mesh": 0, "weights": [0.5, 0.0]}}],
+            "meshes": [{{
+                "primitives": [{{
+                    "attributes": {{"POSITION": 0, "NORMAL": 1}},
+                    "targets": [{{"POSITION": 2}}, {{"NORMAL": 3}}]
+                }}],
+                "weights": [1.0, 1.0]
+            }}],
+            "buffers": [{{"byteLength": {}, "uri": "{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": {}}}],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3"}},
+                {{"bufferView": 0, "byteOffset": 36, "componentType": 5126, "count": 3, "type": "VEC3"}},
+                {{
+                    "componentType": 5126, "count": 3, "type": "VEC3",
+                    "sparse": {{
+                        "count": 1,
+                        "indices": {{"bufferView": 0, "byteOffset": 120, "componentType": 5121}},
+                        "values": {{"bufferView": 0, "byteOffset": 72}}
+                    }}
+                }},
+                {{"bufferView": 0, "byteOffset": 84, "componentType": 5126, "count": 3, "type": "VEC3"}}
+            ]
+        }}"#,
+        bin.len(),
+        data_uri(&bin),
+        bin.len()
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+    let target = gltf
+        .mesh(0)
+        .unwrap()
+        .primitives()
+        .next()
+        .unwrap()
+        .targets()
+        .next();
+    let positions = target.unwrap().positions().unwrap();
+    let stored: Vec<_> = positions.read::<[f32; 3]>().unwrap().stored().collect();
+    assert_eq!(stored, [(1, [0.0, 2.0, 0.0])]);
+
+    let nodes: Vec<_> = gltf.nodes().collect();
+    assert_eq!(morph::weights(&nodes[0]), [1.0, 1.0]);
+    assert_eq!(morph::weights(&nodes[1]), [0.5, 0.0]);
+
+    let primitive = nodes[0].mesh().unwrap().primitives().next().unwrap();
+    let blended = morph::blend(&primitive, &morph::weights(&nodes[0])).unwrap();
+    assert_close(
+        blended.positions.as_flattened(),
+        &[0.0, 0.0, 0.0, 1.0, 2.0, 0.0, 0.0, 1.0, 0.0],
+    );
+    let half_sqrt = std::f32::consts::FRAC_1_SQRT_2;
+    assert_close(
+        blended.normals.as_flattened(),
+        &[half_sqrt, 0.0, half_sqrt, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0],
+    );
+    assert!(blended.tangents.is_empty());
+
+    let blended = morph::blend(&primitive, &morph::weights(&nodes[1])).unwrap();
+    assert_close(&blended.positions[1], &[1.0, 1.0, 0.0]);
+    assert_close(&blended.normals[0], &[0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn attribute_semantics() {
+    for (name, semantic) in [
+        ("POSITION", Semantic::Position),
+        ("TEXCOORD_0", Semantic::TexCoord(0)),
+        ("COLOR_12", Semantic::Color(12)),
+        ("JOINTS_1", Semantic::Joints(1)),
+        ("WEIGHTS_1", Semantic::Weights(1)),
+        ("_BATCH_ID", Semantic::Custom("_BATCH_ID".to_string())),
+        ("TEXCOORD_01", Semantic::Other("TEXCOORD_01".to_string())),
+        ("TEXCOORD", Semantic::Other("TEXCOORD".to_string())),
+        ("NORMAL_0", Semantic::Other("NORMAL_0".to_string())),
+    ] {
+        assert_eq!(Semantic::from(name), semantic);
+        assert_eq!(semantic.to_string(), name);
+    }
+
+    let primitive: MeshPrimitive = serde_json::from_str(
+        r#"{"attributes": {"TEXCOORD_1": 2, "POSITION": 0, "_ID": 1, "NORMAL": -1}}"#,
+    )
+    .unwrap();
+    let attributes: Vec<_> = primitive
+        .attribute_map()
+        .into_iter()
+        .map(|(semantic, index)| (semantic, index.value()))
+        .collect();
+    assert_eq!(
+        attributes,
+        [
+            (Semantic::Position, 0),
+            (Semantic::TexCoord(1), 2),
+            (Semantic::Custom("_ID".to_string()), 1)
+        ]
+    );
+    let serialized = serde_json::to_value(primitive.attribute_map()).unwrap();
+    assert_eq!(serialized["TEXCOORD_1"], 2);
+}
+
+#[test]
+fn triangulate_primitives() {
+    let indices = [0, 1, 2, 3, 4];
+    let convert = |mode| Topology::from_indices(mode, &indices).unwrap();
+    assert_eq!(convert(Mode::Points), Topology::Points(indices.to_vec()));
+    assert_eq!(convert(Mode::Lines), Topology::Lines(vec![[0, 1], [2, 3]]));
+    assert_eq!(
+        convert(Mode::LineStrip),
+        Topology::Lines(vec![[0, 1], [1, 2], [2, 3], [3, 4]])
+    );
+    assert_eq!(
+        convert(Mode::LineLoop),
+        Topology::Lines(vec![[0, 1], [1, 2], [2, 3], [3, 4], [4, 0]])
+    );
+    assert_eq!(
+        convert(Mode::Triangles),
+        Topology::Triangles(vec![[0, 1, 2]])
+    );
+    assert_eq!(
+        convert(Mode::TriangleStrip),
+        Topology::Triangles(vec![[0, 1, 2], [1, 3, 2], [2, 3, 4]])
+    );
+    assert_eq!(
+        convert(Mode::TriangleFan),
+        Topology::Triangles(vec![[1, 2, 0], [2, 3, 0], [3, 4, 0]])
+    );
+    assert_eq!(
+        Topology::from_indices(Mode::TriangleStrip, &[0, 1]).unwrap(),
+        Topology::Triangles(vec![])
+    );
+    assert!(matches!(
+        Topology::from_indices(Mode::Other(7), &indices),
+        Err(TopologyError::UnsupportedMode(7))
+    ));
+
+    let bin: Vec<u8> = [0u16, 3, 1, 2]
+        .iter()
+        .flat_map(|index| index.to_le_bytes())
+        .chain([0; 48])
+        .collect();
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "meshes": [{{
+                "primitives": [
+                    {{"attributes": {{"POSITION": 1}}, "indices": 0, "mode": 5}},
+                    {{"attributes": {{"POSITION": 1}}, "mode": 6}},
+                    {{"attributes": {{"_ID": 1}}, "mode": 0}}
+                ]
+            }}],
+            "buffers": [{{"byteLength": {}, "uri": "{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": {}}}],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5123, "count": 4, "type": "SCALAR"}},
+                {{"bufferView": 0, "byteOffset": 8, "componentType": 5126, "count": 4, "type": "VEC3"}}
+            ]
+        }}"#,
+        bin.len(),
+        data_uri(&bin),
+        bin.len()
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+    let topologies: Vec<_> = gltf
+        .mesh(0)
+        .unwrap()
+        .primitives()
+        .map(|primitive| Topology::read(&primitive).unwrap())
+        .collect();
+    assert_eq!(
+        topologies,
+        [
+            Topology::Triangles(vec![[0, 3, 1], [3, 2, 1]]),
+            Topology::Triangles(vec![[1, 2, 0], [2, 3, 0]]),
+            Topology::Points(vec![0, 1, 2, 3]),
+        ]
+    );
+}
+
+#[test]
+fn generate_normals_and_tangents() {
+    let positions = [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [1.0, 1.0, 1.0],
+    ];
+    let tex_coords = [[0.0f32, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0]];
+    let mut bin: Vec<u8> = positions
+        .as_flattened()
+        .iter()
+        .chain(tex_coords.as_flattened())
+        .flat_map(|f: &f32| f.to_le_bytes())
+        .collect();
+    bin.extend([0u16, 1, 2, 2, 1, 3].iter().flat_map(|i| i.to_le_bytes()));
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "meshes": [{{
+                "primitives": [{{
+                    "attributes": {{"POSITION": 0, "TEXCOORD_1": 1}},
+                    "indices": 2,
+                    "material": 0
+                }}]
+            }}],
+            "materials": [{{"normalTexture": {{"index": 0, "texCoord": 1}}}}],
+            "textures": [{{}}],
+            "buffers": [{{"byteLength": {}, "uri": "{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": {}}}],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 4, "type": "VEC3"}},
+                {{"bufferView": 0, "byteOffset": 48, "componentType": 5126, "count": 4, "type": "VEC2"}},
+                {{"bufferView": 0, "byteOffset": 80, "componentType": 5123, "count": 6, "type": "SCALAR"}}
+            ]
+        }}"#,
+        bin.len(),
+        data_uri(&bin),
+        bin.len()
+    );
+    let mut gltf = import_slice(json.as_bytes()).unwrap();
+    let primitive = gltf.mesh(0).unwrap().primitives().next().unwrap();
+    let generated = geometry::generate(&primitive).unwrap();
+
+    // The two triangles are not coplanar, so the vertices of their shared edge are split
+    assert_eq!(generated.sources, [0, 1, 2, 2, 1, 3]);
+    assert_eq!(generated.triangles, [[0, 1, 2], [3, 4, 5]]);
+    let third = 1.0 / 3.0f32.sqrt();
+    assert_close(&generated.normals[0], &[0.0, 0.0, 1.0]);
+    assert_close(&generated.normals[5], &[-third, -third, third]);
+    let half_sqrt = std::f32::consts::FRAC_1_SQRT_2;
+    assert_close(&generated.tangents[0], &[1.0, 0.0, 0.0, 1.0]);
+    assert_close(&generated.tangents[5], &[half_sqrt, 0.0, half_sqrt, 1.0]);
+
+    // Mirrored texture coordinates flip the tangent and its handedness
+    let mirrored = geometry::tangents(
+        &positions,
+        &tex_coords.map(|[u, v]| [1.0 - u, v]),
+        &[[0.0, 0.0, 1.0]; 3],
+        &[[0, 1, 2]],
+    );
+    assert_close(mirrored[0].as_slice(), &[-1.0, 0.0, 0.0, -1.0]);
+
+    assert_eq!((generated.mesh, generated.primitive), (0, 0));
+    generated.write(&mut gltf).unwrap();
+    let exported = to_json(&gltf, &ExportOptions::default()).unwrap();
+    let gltf = import_slice(&exported).unwrap();
+    let primitive = gltf.mesh(0).unwrap().primitives().next().unwrap();
+    let indices: Vec<u32> = primitive.indices().unwrap().read().unwrap().collect();
+    assert_eq!(indices, [0, 1, 2, 3, 4, 5]);
+    let positions = primitive.positions().unwrap();
+    assert_eq!(positions.max(), [1.0, 1.0, 1.0]);
+    let tex_coords: Vec<[f32; 2]> = primitive.tex_coords(1).unwrap().read().unwrap().collect();
+    assert_eq!(tex_coords[4], [1.0, 0.0]);
+    let tangents: Vec<[f32; 4]> = primitive.tangents().unwrap().read().unwrap().collect();
+    assert_eq!(tangents, generated.tangents);
+    let mut gltf = import_slice(&exported).unwrap();
+    let missing = geometry::Generated {
+        primitive: 1,
+        ..Default::default()
+    };
+    assert!(matches!(
+        missing.write(&mut gltf),
+        Err(GeometryError::MissingPrimitive {
+            mesh: 0,
+            primitive: 1
+        })
+    ));
+
+    // Vertices generated for a larger primitive are rejected instead of read out of range
+    let larger = geometry::Generated {
+        sources: vec![0, 6],
+        ..Default::default()
+    };
+    assert!(matches!(
+        larger.write(&mut gltf),
+        Err(GeometryError::VertexOutOfRange {
+            vertex: 6,
+            vertices: 6
+        })
+    ));
+}
+
+#[test]
+fn compute_bounds() {
+    #[rustfmt::skip]
+    let floats: &[f32] = &[
+        // Positions
+        0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 2.0, 0.0,
+        // Position displacements
+        0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 3.0,
+    ];
+    let bin: Vec<u8> = floats.iter().flat_map(|f| f.to_le_bytes()).collect();
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "scene": 0,
+            "scenes": [{{"nodes": [0]}}],
+            "nodes": [
+                {{"mesh": 0, "translation": [10.0, 0.0, 0.0], "children": [1]}},
+                {{"mesh": 0, "scale": [2.0, 2.0, 2.0]}}
+            ],
+            "meshes": [{{
+                "primitives": [{{"attributes": {{"POSITION": 0}}, "targets": [{{"POSITION": 1}}]}}],
+                "weights": [0.5]
+            }}],
+            "buffers": [{{"byteLength": {}, "uri": "{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": {}}}],
+            "accessors": [
+                {{
+                    "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+                    "min": [0.0, 0.0, 0.0], "max": [9.0, 9.0, 9.0]
+                }},
+                {{
+                    "bufferView": 0, "byteOffset": 36, "componentType": 5126, "count": 3, "type": "VEC3",
+                    "min": [0.0, 0.0, 0.0], "max": [0.0, 0.0, 3.0]
+                }}
+            ]
+        }}"#,
+        bin.len(),
+        data_uri(&bin),
+        bin.len()
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+
+    let primitive = gltf.mesh(0).unwrap().primitives().next().unwrap();
+    let unmorphed = bounds::primitive(&primitive, &[]).unwrap().unwrap();
+    assert_eq!(unmorphed.aabb.min, [0.0, 0.0, 0.0]);
+    assert_eq!(unmorphed.aabb.max, [1.0, 2.0, 0.0]);
+    assert_close(&unmorphed.sphere.center, &[0.5, 1.0, 0.0]);
+    assert_close(&[unmorphed.sphere.radius], &[1.25f32.sqrt()]);
+    let morphed = bounds::primitive(&primitive, &[0.5]).unwrap().unwrap();
+    assert_eq!(morphed.aabb.max, [1.0, 2.0, 1.5]);
+    assert_close(&[morphed.sphere.radius], &[1.25f32.sqrt() + 1.5]);
+
+    let pose = Pose::rest(&gltf);
+    let world_matrices = pose.world_matrices(&gltf);
+    let child = gltf.node(1).unwrap();
+    let child_bounds = bounds::node(&child, &pose, &world_matrices)
+        .unwrap()
+        .unwrap();
+    assert_close(&child_bounds.aabb.min, &[10.0, 0.0, 0.0]);
+    assert_close(&child_bounds.aabb.max, &[12.0, 4.0, 3.0]);
+    assert_close(
+        &[child_bounds.sphere.radius],
+        &[2.0 * morphed.sphere.radius],
+    );
+    let scene = gltf.default_scene().unwrap();
+    let scene_bounds = bounds::scene(&scene, &pose, &world_matrices)
+        .unwrap()
+        .unwrap();
+    assert_eq!(scene_bounds.aabb, child_bounds.aabb);
+    assert!(scene_bounds.sphere.radius >= child_bounds.sphere.radius);
+
+    // A unit cube rotated by 45 degrees around Z
+    let (sin, cos) = std::f32::consts::FRAC_PI_8.sin_cos();
+    let transformed = Aabb {
+        min: [-1.0, -1.0, -1.0],
+        max: [1.0, 1.0, 1.0],
+    }
+    .transform(
+        &Transform {
+            rotation: [0.0, 0.0, sin, cos],
+            ..Transform::default()
+        }
+        .matrix(),
+    );
+    assert_close(&transformed.max, &[2.0f32.sqrt(), 2.0f32.sqrt(), 1.0]);
+
+    let options = ExportOptions {
+        update_position_bounds: true,
+        ..Default::default()
+    };
+    let exported: serde_json::Value =
+        serde_json::from_slice(&to_json(&gltf, &options).unwrap()).unwrap();
+    assert_eq!(
+        exported["accessors"][0]["max"],
+        serde_json::json!([1.0, 2.0, 0.0])
+    );
+    assert_eq!(
+        exported["accessors"][1]["max"],
+        serde_json::json!([0.0, 0.0, 3.0])
+    );
+    let exported: serde_json::Value =
+        serde_json::from_slice(&to_json(&gltf, &ExportOptions::default()).unwrap()).unwrap();
+    assert_eq!(
+        exported["accessors"][0]["max"],
+        serde_json::json!([9.0, 9.0, 9.0])
+    );
+}
+
+#[test]
+fn build_document() {
+    let mut builder = DocumentBuilder::new();
+    let positions = builder.vertices(&[[0.0f32, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 2.0, 0.0]]);
+    let colors = builder.normalized_vertices(&[[255u8, 0, 0], [0, 255, 0], [0, 0, 255]]);
+    let indices = builder.indices(&[0, 1, 2]);
+    // Accessors can not be empty, so nothing is added for empty input
+    assert_eq!(builder.indices(&[]), None);
+    assert_eq!(builder.accessor::<[f32; 16]>(&[]), None);
+    let image = builder.image(b"not really a png", MimeType::ImagePng);
+    let texture = builder.texture(image);
+    let material = builder.material([1.0, 0.5, 0.5, 1.0], 0.0, 0.8);
+    let mesh = builder.mesh();
+    builder.primitive(
+        mesh,
+        [
+            (Semantic::Position, positions.unwrap()),
+            (Semantic::Color(0), colors.unwrap()),
+        ],
+        indices,
+        Some(material),
+    );
+    let root = builder.node(None, Transform::default());
+    let child = builder.node(
+        Some(mesh),
+        Transform {
+            translation: [0.0, 1.0, 0.0],
+            ..Transform::default()
+        },
+    );
+    builder.add_child(root, child);
+    let scene = builder.scene(&[root]);
+    builder.scene(&[]);
+    assert_eq!(texture.value(), 0);
+    let gltf = builder.finish();
+
+    let document = gltf.document();
+    assert_eq!(document.scene, Some(scene));
+    assert_eq!(document.accessors.len(), 3);
+    assert_eq!(document.accessors[0].min, [0.0, 0.0, 0.0]);
+    assert_eq!(document.accessors[0].max, [1.0, 2.0, 0.0]);
+    assert_eq!(document.buffer_views[1].byte_stride, Some(4));
+    assert_eq!(document.buffer_views[1].byte_length, 11);
+    assert_eq!(
+        document.accessors[2].component_type,
+        ComponentType::UnsignedShort
+    );
+    assert_eq!(
+        document.buffers[0].byte_length as usize,
+        gltf.buffer_data(0).unwrap().len()
+    );
+
+    let gltf = import_slice(&to_glb(&gltf, &ExportOptions::default()).unwrap()).unwrap();
+    assert!(!report(&gltf).has_errors());
+    let primitive = gltf.mesh(0).unwrap().primitives().next().unwrap();
+    let colors: Vec<[f32; 3]> = primitive.colors(0).unwrap().read().unwrap().collect();
+    assert_eq!(colors[1], [0.0, 1.0, 0.0]);
+    let material = primitive.material().unwrap();
+    assert_eq!(material.pbr_metallic_roughness().roughness_factor(), 0.8);
+    assert_eq!(gltf.image_data(0), Some(&b"not really a png"[..]));
+    let world = gltf.world_transforms(0).unwrap();
+    assert_eq!(world[1].unwrap()[13], 1.0);
+}
+
+#[test]
+fn typed_extensions() {
+    let json = br#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["KHR_lights_punctual", "KHR_materials_emissive_strength",
+            "KHR_materials_clearcoat", "KHR_materials_variants", "KHR_texture_transform",
+            "CESIUM_primitive_outline", "EXT_texture_webp", "MSFT_lod", "EXT_unknown"],
+        "extensions": {
+            "KHR_lights_punctual": {"lights": [{"type": "point"}]},
+            "KHR_materials_variants": {"variants": [{"name": "red"}]}
+        },
+        "nodes": [
+            {"extensions": {"KHR_lights_punctual": {"light": 0}, "MSFT_lod": {"ids": [1]}}},
+            {"extensions": {"KHR_lights_punctual": {"light": "sun"}, "EXT_unknown": {}}}
+        ],
+        "textures": [{"extensions": {"EXT_texture_webp": {}}}],
+        "materials": [{
+            "pbrMetallicRoughness": {"baseColorTexture": {
+                "index": 0,
+                "extensions": {"KHR_texture_transform": {"texCoord": 1}}
+            }},
+            "normalTexture": {
+                "index": 0,
+                "extensions": {"KHR_texture_transform": {"rotation": 1.5}}
+            },
+            "extensions": {
+                "KHR_materials_emissive_strength": {"emissiveStrength": 4.0},
+                "KHR_materials_clearcoat": {
+                    "clearcoatTexture": {
+                        "index": 0,
+                        "extensions": {"KHR_texture_transform": {"offset": [0.5, 0]}}
+                    },
+                    "clearcoatNormalTexture": {
+                        "index": 0,
+                        "extensions": {"KHR_texture_transform": {"scale": "large"}}
+                    }
+                }
+            }
+        }],
+        "meshes": [{"primitives": [{
+            "attributes": {},
+            "extensions": {
+                "KHR_materials_variants": {"mappings": [{"material": 0, "variants": [0]}]},
+                "CESIUM_primitive_outline": {}
+            }
+        }]}]
+    }"#;
+    let gltf = import_slice(json).unwrap();
+
+    let lights = gltf.extension::<khr_lights_punctual::RootExtension>();
+    let lights = lights.unwrap();
+    assert_eq!(lights.lights.len(), 1);
+    let node = gltf.node(0).unwrap();
+    let light = node.extension::<khr_lights_punctual::NodeExtension>();
+    // Light ids are typed so they can be looked up in the lights of the root
+    let light = lights.get(light.unwrap().light).unwrap();
+    assert_eq!(light.ty, khr_lights_punctual::light::Type::Point);
+    // Extensions are looked up by the type generated for the object they extend
+    assert!(node
+        .extension::<khr_lights_punctual::RootExtension>()
+        .is_none());
+    // Extensions with schemas named after the glTF root are generated for the object they extend
+    let lod = node.extension::<msft_lod::NodeExtension>();
+    assert_eq!(lod.unwrap().ids, [Index::new(1)]);
+    let texture = gltf.texture(0).unwrap();
+    let webp = texture.extension::<ext_texture_webp::TextureExtension>();
+    assert_eq!(webp.unwrap().source, None);
+    let material = gltf.material(0).unwrap();
+    let emissive = material.extension::<khr_materials_emissive_strength::MaterialExtension>();
+    assert_eq!(emissive.unwrap().emissive_strength, 4.0);
+    let normal = material.normal_texture().unwrap();
+    let transform = normal.extension::<khr_texture_transform::TextureInfoExtension>();
+    assert_eq!(transform.unwrap().rotation, 1.5);
+    let transform = gltf
+        .extension_at::<khr_texture_transform::TextureInfoExtension>("/materials/0/normalTexture");
+    assert_eq!(transform.unwrap().rotation, 1.5);
+    let pbr = material.pbr_metallic_roughness();
+    let base_color = pbr.base_color_texture().unwrap();
+    let transform = base_color.extension::<khr_texture_transform::TextureInfoExtension>();
+    assert_eq!(transform.unwrap().tex_coord, Some(1));
+    assert!(pbr
+        .extension::<khr_texture_transform::TextureInfoExtension>()
+        .is_none());
+    // Textures of material extensions are walked as well
+    let transform = gltf.extension_at::<khr_texture_transform::TextureInfoExtension>(
+        "/materials/0/extensions/KHR_materials_clearcoat/clearcoatTexture",
+    );
+    assert_eq!(transform.unwrap().offset, [0.5, 0.0]);
+    assert!(gltf
+        .extension_at::<khr_texture_transform::TextureInfoExtension>("/materials/0/normal")
+        .is_none());
+    let primitive = gltf.mesh(0).unwrap().primitives().next().unwrap();
+    let variants = primitive.extension::<khr_materials_variants::MeshPrimitiveExtension>();
+    assert_eq!(variants.unwrap().mappings[0].variants, [0]);
+    let outline = primitive.extension::<cesium_primitive_outline::MeshPrimitiveExtension>();
+    assert_eq!(outline.unwrap().indices, None);
+
+    // A malformed extension is reported once on import instead of failing the import
+    let node = gltf.node(1).unwrap();
+    assert!(node
+        .extension::<khr_lights_punctual::NodeExtension>()
+        .is_none());
+    let errors: Vec<&str> = gltf
+        .extension_errors()
+        .iter()
+        .map(|error| error.pointer.as_str())
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            "/materials/0/extensions/KHR_materials_clearcoat/clearcoatNormalTexture/extensions/KHR_texture_transform",
+            "/nodes/1/extensions/KHR_lights_punctual",
+        ]
+    );
+    // Validation reports the same extensions, each of them once
+    let report = report(&gltf);
+    let invalid: Vec<&str> = report
+        .messages
+        .iter()
+        .filter(|message| message.code == Code::InvalidExtension)
+        .map(|message| message.pointer.as_str())
+        .collect();
+    assert_eq!(invalid, errors);
+}
+
+#[test]
+fn export_json() {
+    let bin = [1u8, 2, 3, 4, 5, 6, 0, 0];
+    let json =
+        br#"{"asset":{"version":"2.0"},"buffers":[{"byteLength":6}],"nodes":[{"name":"root"}]}  "#;
+    let gltf = import_slice(&make_glb(2, &[(JSON_CHUNK, json), (BIN_CHUNK, &bin)])).unwrap();
+
+    let exported = to_json(&gltf, &ExportOptions::default()).unwrap();
+    let value: serde_json::Value = serde_json::from_slice(&exported).unwrap();
+    assert_eq!(value["nodes"], serde_json::json!([{"name": "root"}]));
+    assert!(value["buffers"][0]["uri"]
+        .as_str()
+        .unwrap()
+        .starts_with("data:application/octet-stream;base64,"));
+
+    let gltf = import_slice(&exported).unwrap();
+    assert_eq!(gltf.buffer_data(0), Some(&bin[..6]));
+}
+
+#[test]
+fn export_glb() {
+    let json = format!(
+        r#"{{"asset":{{"version":"2.0"}},"buffers":[{{"byteLength":5,"uri":"{}"}}]}}"#,
+        data_uri(&[1, 2, 3, 4, 5])
+    );
+    let gltf = import_slice(json.as_bytes()).unwrap();
+
+    let exported = to_glb(&gltf, &ExportOptions::default()).unwrap();
+    assert_eq!(exported.len() % 4, 0);
+    let gltf = import_slice(&exported).unwrap();
+    assert_eq!(gltf.document().buffers[0].uri, None);
+    assert_eq!(gltf.blob(), Some(&[1u8, 2, 3, 4, 5, 0, 0, 0][..]));
+    assert_eq!(gltf.buffer_data(0), Some(&[1u8, 2, 3, 4, 5][..]));
+}
+
+/// Exports an asset whose buffer has a `uri`, returning the result and whether any file was written.
+fn export_with_buffer_uri(name: &str, uri: &str) -> (Result<(), ExportError>, bool) {
+    let json = br#"{"asset":{"version":"2.0"},"buffers":[{"byteLength":4,"uri":"buffer.bin"}]}"#;
+    let resolver = MemoryResolver(HashMap::from([("buffer.bin", vec![1, 2, 3, 4])]));
+    let mut gltf = import_with_resolver(json, &resolver).unwrap();
+    gltf.document_mut().buffers[0].uri = Some(uri.to_string());
+
+    let dir = std::env::temp_dir().join(format!("gltf_for_rust_{name}_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let result = export(
+        &gltf,
+        dir.join("asset.gltf").to_str().unwrap(),
+        &ExportOptions::default(),
+    );
+    let written = fs::read_dir(&dir).unwrap().next().is_some();
+    fs::remove_dir_all(&dir).unwrap();
+    (result, written)
+}
+
+#[test]
+fn export_rejects_absolute_paths() {
+    let target = std::env::temp_dir().join("gltf_for_rust_export_absolute.bin");
+    let (result, written) = export_with_buffer_uri("export_absolute", target.to_str().unwrap());
+    assert!(matches!(result, Err(ExportError::PathOutsideDirectory(_))));
+    assert!(!written);
+    assert!(!target.exists());
+}
+
+#[test]
+fn export_rejects_parent_paths() {
+    let (result, written) = export_with_buffer_uri("export_parent", "bin/../../escaped.bin");
+    assert!(matches!(
+        result,
+        Err(ExportError::PathOutsideDirectory(uri)) if uri == "bin/../../escaped.bin"
+    ));
+    assert!(!written);
+}
+
+#[test]
+fn export_rejects_uri_schemes() {
+    let (result, written) =
+        export_with_buffer_uri("export_scheme", "http://example.com/buffer.bin");
+    assert!(matches!(
+        result,
+        Err(ExportError::UnsupportedScheme(uri)) if uri == "http://example.com/buffer.bin"
+    ));
+    assert!(!written);
+}
+
+#[test]
+fn export_external_buffers() {
+    let json =
+        br#"{"asset":{"version":"2.0"},"buffers":[{"byteLength":4,"uri":"bin/my%20buffer.bin"}]}"#;
+    let resolver = MemoryResolver(HashMap::from([("bin/my buffer.bin", vec![1, 2, 3, 4])]));
+    let gltf = import_with_resolver(json, &resolver).unwrap();
+
+    let dir = std::env::temp_dir().join(format!("gltf_for_rust_export_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("asset.gltf");
+    export(&gltf, path.to_str().unwrap(), &ExportOptions::default()).unwrap();
+    let reimported = import(path.to_str().unwrap());
+    let embedded = to_json(
+        &gltf,
+        &ExportOptions {
+            embed_buffers: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+
+    let reimported = reimported.unwrap();
+    assert_eq!(
+        reimported.document().buffers[0].uri.as_deref(),
+        Some("bin/my%20buffer.bin")
+    );
+    assert_eq!(reimported.buffer_data(0), Some(&[1u8, 2, 3, 4][..]));
+    assert_eq!(
+        import_slice(&embedded).unwrap().buffer_data(0),
+        Some(&[1u8, 2, 3, 4][..])
+    );
+}
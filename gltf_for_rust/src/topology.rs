@@ -0,0 +1,92 @@
+//! Conversion of the topology of a primitive, e.g. a triangle strip, into a list of points, lines
+//! or triangles.
+
+use thiserror::Error;
+
+use crate::accessor::AccessorError;
+use crate::import::Primitive;
+
+use crate::generated::gltf::mesh_primitive::Mode;
+
+#[derive(Error, Debug)]
+pub enum TopologyError {
+    #[error("Indices can not be read: {0}")]
+    Indices(AccessorError),
+    #[error("Primitive mode {0} is not supported")]
+    UnsupportedMode(i64),
+}
+
+/// The vertices of a primitive as a list of independent points, lines or triangles.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Topology {
+    Points(Vec<u32>),
+    Lines(Vec<[u32; 2]>),
+    Triangles(Vec<[u32; 3]>),
+}
+
+impl Topology {
+    /// Converts a list of vertex indices drawn with a primitive mode. Strips and fans keep the
+    /// winding order of their first triangle, as defined by the specification. Trailing indices
+    /// that do not form a complete line or triangle are ignored.
+    pub fn from_indices(mode: Mode, indices: &[u32]) -> Result<Self, TopologyError> {
+        let count = indices.len();
+        Ok(match mode {
+            Mode::Points => Topology::Points(indices.to_vec()),
+            Mode::Lines => Topology::Lines(
+                indices
+                    .chunks_exact(2)
+                    .map(|line| [line[0], line[1]])
+                    .collect(),
+            ),
+            Mode::LineStrip => {
+                Topology::Lines(indices.windows(2).map(|line| [line[0], line[1]]).collect())
+            }
+            Mode::LineLoop => {
+                let mut lines: Vec<_> = indices.windows(2).map(|line| [line[0], line[1]]).collect();
+                if count > 2 {
+                    lines.push([indices[count - 1], indices[0]]);
+                }
+                Topology::Lines(lines)
+            }
+            Mode::Triangles => Topology::Triangles(
+                indices
+                    .chunks_exact(3)
+                    .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+                    .collect(),
+            ),
+            // Every other triangle of a strip is flipped to keep a consistent winding
+            Mode::TriangleStrip => Topology::Triangles(
+                (0..count.saturating_sub(2))
+                    .map(|i| [indices[i], indices[i + 1 + i % 2], indices[i + 2 - i % 2]])
+                    .collect(),
+            ),
+            Mode::TriangleFan => Topology::Triangles(
+                (1..count.saturating_sub(1))
+                    .map(|i| [indices[i], indices[i + 1], indices[0]])
+                    .collect(),
+            ),
+            Mode::Other(mode) => return Err(TopologyError::UnsupportedMode(mode)),
+        })
+    }
+
+    /// Reads the topology of a primitive. A primitive without indices draws its vertices in order.
+    pub fn read(primitive: &Primitive) -> Result<Self, TopologyError> {
+        Topology::from_indices(primitive.mode(), &indices(primitive)?)
+    }
+}
+
+/// Reads the vertex indices of a primitive, or generates sequential indices for every vertex if
+/// it has none. The number of vertices is taken from `POSITION`, or any other attribute if the
+/// primitive has no positions.
+pub fn indices(primitive: &Primitive) -> Result<Vec<u32>, TopologyError> {
+    if let Some(indices) = primitive.indices() {
+        let indices = indices.read::<u32>().map_err(TopologyError::Indices)?;
+        return Ok(indices.collect());
+    }
+
+    let vertices = primitive
+        .positions()
+        .or_else(|| primitive.attributes().next().map(|(_, accessor)| accessor));
+    let count = vertices.map_or(0, |accessor| accessor.count());
+    Ok((0..count as u32).collect())
+}
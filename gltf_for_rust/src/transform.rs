@@ -0,0 +1,186 @@
+//! Transforms of the nodes in a scene. Matrices are 4x4 and stored in column-major order, like the
+//! `matrix` of a node, and rotations are unit quaternions in (x, y, z, w) order.
+
+/// A 4x4 matrix in column-major order.
+pub type Matrix = [f32; 16];
+
+#[rustfmt::skip]
+pub const IDENTITY: Matrix = [
+    1.0, 0.0, 0.0, 0.0,
+    0.0, 1.0, 0.0, 0.0,
+    0.0, 0.0, 1.0, 0.0,
+    0.0, 0.0, 0.0, 1.0,
+];
+
+/// Multiplies two matrices. The result applies `b` first and `a` second.
+pub fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    std::array::from_fn(|index| {
+        let (column, row) = (index / 4, index % 4);
+        (0..4).map(|k| a[k * 4 + row] * b[column * 4 + k]).sum()
+    })
+}
+
+/// Inverts a matrix, returns None if it is singular.
+pub fn invert(m: &Matrix) -> Option<Matrix> {
+    // Cofactor expansion through the 2x2 determinants of the upper and lower two rows
+    let a = |row: usize, column: usize| m[row * 4 + column];
+    let s = [
+        a(0, 0) * a(1, 1) - a(1, 0) * a(0, 1),
+        a(0, 0) * a(1, 2) - a(1, 0) * a(0, 2),
+        a(0, 0) * a(1, 3) - a(1, 0) * a(0, 3),
+        a(0, 1) * a(1, 2) - a(1, 1) * a(0, 2),
+        a(0, 1) * a(1, 3) - a(1, 1) * a(0, 3),
+        a(0, 2) * a(1, 3) - a(1, 2) * a(0, 3),
+    ];
+    let c = [
+        a(2, 0) * a(3, 1) - a(3, 0) * a(2, 1),
+        a(2, 0) * a(3, 2) - a(3, 0) * a(2, 2),
+        a(2, 0) * a(3, 3) - a(3, 0) * a(2, 3),
+        a(2, 1) * a(3, 2) - a(3, 1) * a(2, 2),
+        a(2, 1) * a(3, 3) - a(3, 1) * a(2, 3),
+        a(2, 2) * a(3, 3) - a(3, 2) * a(2, 3),
+    ];
+    let determinant =
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+    if determinant == 0.0 {
+        return None;
+    }
+
+    let inverse: Matrix = [
+        a(1, 1) * c[5] - a(1, 2) * c[4] + a(1, 3) * c[3],
+        -a(0, 1) * c[5] + a(0, 2) * c[4] - a(0, 3) * c[3],
+        a(3, 1) * s[5] - a(3, 2) * s[4] + a(3, 3) * s[3],
+        -a(2, 1) * s[5] + a(2, 2) * s[4] - a(2, 3) * s[3],
+        -a(1, 0) * c[5] + a(1, 2) * c[2] - a(1, 3) * c[1],
+        a(0, 0) * c[5] - a(0, 2) * c[2] + a(0, 3) * c[1],
+        -a(3, 0) * s[5] + a(3, 2) * s[2] - a(3, 3) * s[1],
+        a(2, 0) * s[5] - a(2, 2) * s[2] + a(2, 3) * s[1],
+        a(1, 0) * c[4] - a(1, 1) * c[2] + a(1, 3) * c[0],
+        -a(0, 0) * c[4] + a(0, 1) * c[2] - a(0, 3) * c[0],
+        a(3, 0) * s[4] - a(3, 1) * s[2] + a(3, 3) * s[0],
+        -a(2, 0) * s[4] + a(2, 1) * s[2] - a(2, 3) * s[0],
+        -a(1, 0) * c[3] + a(1, 1) * c[1] - a(1, 2) * c[0],
+        a(0, 0) * c[3] - a(0, 1) * c[1] + a(0, 2) * c[0],
+        -a(3, 0) * s[3] + a(3, 1) * s[1] - a(3, 2) * s[0],
+        a(2, 0) * s[3] - a(2, 1) * s[1] + a(2, 2) * s[0],
+    ];
+    Some(inverse.map(|value| value / determinant))
+}
+
+/// Transforms a point, including the translation of the matrix.
+pub fn transform_point(m: &Matrix, p: [f32; 3]) -> [f32; 3] {
+    let [x, y, z] = transform_vector(m, p);
+    [x + m[12], y + m[13], z + m[14]]
+}
+
+/// Transforms a direction, ignoring the translation of the matrix.
+pub fn transform_vector(m: &Matrix, v: [f32; 3]) -> [f32; 3] {
+    std::array::from_fn(|row| m[row] * v[0] + m[4 + row] * v[1] + m[8 + row] * v[2])
+}
+
+/// A translation, rotation and scale, applied to a point in reverse order: scale first.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform {
+    pub translation: [f32; 3],
+    pub rotation: [f32; 4],
+    pub scale: [f32; 3],
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::IDENTITY
+    }
+}
+
+impl Transform {
+    pub const IDENTITY: Transform = Transform {
+        translation: [0.0; 3],
+        rotation: [0.0, 0.0, 0.0, 1.0],
+        scale: [1.0; 3],
+    };
+
+    /// Composes the transform into a matrix.
+    pub fn matrix(&self) -> Matrix {
+        let [x, y, z, w] = self.rotation;
+        let [sx, sy, sz] = self.scale;
+        let [tx, ty, tz] = self.translation;
+        #[rustfmt::skip]
+        let matrix = [
+            (1.0 - 2.0 * (y * y + z * z)) * sx, 2.0 * (x * y + z * w) * sx, 2.0 * (x * z - y * w) * sx, 0.0,
+            2.0 * (x * y - z * w) * sy, (1.0 - 2.0 * (x * x + z * z)) * sy, 2.0 * (y * z + x * w) * sy, 0.0,
+            2.0 * (x * z + y * w) * sz, 2.0 * (y * z - x * w) * sz, (1.0 - 2.0 * (x * x + y * y)) * sz, 0.0,
+            tx, ty, tz, 1.0,
+        ];
+        matrix
+    }
+
+    /// Decomposes a matrix without shear or projection, which the specification requires of the
+    /// `matrix` of a node. A mirroring matrix is decomposed into a negative scale along x.
+    pub fn from_matrix(matrix: &Matrix) -> Self {
+        let column = |index: usize| {
+            [
+                matrix[index * 4],
+                matrix[index * 4 + 1],
+                matrix[index * 4 + 2],
+            ]
+        };
+        let length = |v: [f32; 3]| (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+        let (x, y, z) = (column(0), column(1), column(2));
+
+        let determinant = x[0] * (y[1] * z[2] - z[1] * y[2]) - y[0] * (x[1] * z[2] - z[1] * x[2])
+            + z[0] * (x[1] * y[2] - y[1] * x[2]);
+        let sign = if determinant < 0.0 { -1.0 } else { 1.0 };
+        let scale = [sign * length(x), length(y), length(z)];
+
+        // The rotation part of the matrix, r(row, column), with the scale removed
+        let r = |row: usize, column: usize| {
+            let scale = scale[column];
+            let value = matrix[column * 4 + row];
+            if scale == 0.0 {
+                value
+            } else {
+                value / scale
+            }
+        };
+        let trace = r(0, 0) + r(1, 1) + r(2, 2);
+        let rotation = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            [
+                (r(2, 1) - r(1, 2)) / s,
+                (r(0, 2) - r(2, 0)) / s,
+                (r(1, 0) - r(0, 1)) / s,
+                0.25 * s,
+            ]
+        } else if r(0, 0) > r(1, 1) && r(0, 0) > r(2, 2) {
+            let s = (1.0 + r(0, 0) - r(1, 1) - r(2, 2)).sqrt() * 2.0;
+            [
+                0.25 * s,
+                (r(0, 1) + r(1, 0)) / s,
+                (r(0, 2) + r(2, 0)) / s,
+                (r(2, 1) - r(1, 2)) / s,
+            ]
+        } else if r(1, 1) > r(2, 2) {
+            let s = (1.0 + r(1, 1) - r(0, 0) - r(2, 2)).sqrt() * 2.0;
+            [
+                (r(0, 1) + r(1, 0)) / s,
+                0.25 * s,
+                (r(1, 2) + r(2, 1)) / s,
+                (r(0, 2) - r(2, 0)) / s,
+            ]
+        } else {
+            let s = (1.0 + r(2, 2) - r(0, 0) - r(1, 1)).sqrt() * 2.0;
+            [
+                (r(0, 2) + r(2, 0)) / s,
+                (r(1, 2) + r(2, 1)) / s,
+                0.25 * s,
+                (r(1, 0) - r(0, 1)) / s,
+            ]
+        };
+
+        Transform {
+            translation: [matrix[12], matrix[13], matrix[14]],
+            rotation,
+            scale,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Resolution of the `uri` property of buffers and images.
+
+use std::borrow::Cow;
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use base64::Engine;
+use percent_encoding::percent_decode_str;
+
+use crate::import::ImportError;
+
+/// Loads the external resources referenced by a glTF asset.
+///
+/// Implement this trait to serve resources from somewhere other than the file system, such as an
+/// in-memory virtual file system or an archive.
+pub trait Resolver {
+    /// Returns the contents of the resource at `path`.
+    /// The path is percent-decoded and relative to the asset that references it.
+    fn resolve(&self, path: &str) -> io::Result<Vec<u8>>;
+}
+
+/// Resolves resources from the file system, relative to a base directory. Paths that are absolute
+/// or contain a `..` component are rejected, so that an asset can not read files outside of the
+/// base directory.
+#[derive(Debug, Clone)]
+pub struct FileSystemResolver {
+    base: PathBuf,
+}
+
+impl FileSystemResolver {
+    pub fn new(base: impl Into<PathBuf>) -> Self {
+        FileSystemResolver { base: base.into() }
+    }
+}
+
+impl Resolver for FileSystemResolver {
+    fn resolve(&self, path: &str) -> io::Result<Vec<u8>> {
+        let path = contained_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "path is absolute or leaves the base directory",
+            )
+        })?;
+        fs::read(self.base.join(path))
+    }
+}
+
+/// Loads the contents of a `uri` property. Data URIs are decoded in place, URIs with any other
+/// scheme are rejected and the rest are treated as relative paths and passed to the resolver.
+pub(crate) fn load(uri: &str, resolver: Option<&dyn Resolver>) -> Result<Vec<u8>, ImportError> {
+    if let Some(data) = uri.strip_prefix("data:") {
+        return decode_data_uri(data);
+    }
+    if has_scheme(uri) {
+        return Err(ImportError::UnsupportedScheme(uri.to_string()));
+    }
+
+    let path = decode_path(uri);
+    let resolver = resolver.ok_or_else(|| ImportError::ExternalReference(path.to_string()))?;
+    resolver
+        .resolve(&path)
+        .map_err(|source| ImportError::Resolve {
+            path: path.to_string(),
+            source,
+        })
+}
+
+/// Decodes the part of a data URI following the `data:` scheme, i.e. `[<mediatype>][;base64],<data>`.
+fn decode_data_uri(data: &str) -> Result<Vec<u8>, ImportError> {
+    let (header, payload) = data.split_once(',').ok_or(ImportError::MalformedDataUri)?;
+    if header.ends_with(";base64") {
+        base64::engine::general_purpose::STANDARD
+            .decode(payload)
+            .map_err(|_| ImportError::MalformedDataUri)
+    } else {
+        Ok(percent_decode_str(payload).collect())
+    }
+}
+
+/// Returns true if the URI embeds its contents, rather than referring to an external resource.
+pub(crate) fn is_data_uri(uri: &str) -> bool {
+    uri.starts_with("data:")
+}
+
+/// Returns true if the URI starts with a scheme, e.g. `http:`, rather than being a relative path.
+pub(crate) fn has_scheme(uri: &str) -> bool {
+    uri.split_once(':').is_some_and(|(scheme, _)| {
+        let mut chars = scheme.chars();
+        chars
+            .next()
+            .is_some_and(|first| first.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
+
+/// Returns the path if it stays within the directory it is relative to, i.e. it is not absolute
+/// and has no `..` component.
+pub(crate) fn contained_path(path: &str) -> Option<&Path> {
+    let path = Path::new(path);
+    path.components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+        .then_some(path)
+}
+
+/// Percent-decodes a relative URI into the path that is passed to a resolver.
+pub(crate) fn decode_path(uri: &str) -> Cow<'_, str> {
+    percent_decode_str(uri).decode_utf8_lossy()
+}
+
+/// Encodes binary data as a base64 data URI.
+pub(crate) fn encode_data_uri(data: &[u8]) -> String {
+    format!(
+        "data:application/octet-stream;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(data)
+    )
+}
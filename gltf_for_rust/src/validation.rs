@@ -0,0 +1,797 @@
+//! Checks the references between the objects of a document: every index must refer to an existing
+//! object, and the nodes must form a forest in which scenes refer to root nodes only. The
+//! constraints of the schema that the generated types can not express are checked through the
+//! [`Validate`] implementation of each type.
+//!
+//! [`report`] checks an imported asset against the rest of the specification, including the
+//! contents of its buffers.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use regex::Regex;
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::extension::{self, Extensions, Object};
+use crate::extensions::*;
+use crate::schema::{Gltf, MeshPrimitive, TextureInfo};
+use crate::{GltfExtension, Index};
+
+mod report;
+
+pub use report::{report, Code, Message, Report, Severity};
+
+/// A problem found while validating a document.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("{pointer}: {kind}")]
+pub struct ValidationError {
+    /// JSON pointer to the offending value, e.g. `/nodes/0/children/1`.
+    pub pointer: String,
+    pub kind: ValidationErrorKind,
+}
+
+#[non_exhaustive]
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ValidationErrorKind {
+    #[error("Index {index} is out of range for a collection of {count} objects")]
+    IndexOutOfRange { index: usize, count: usize },
+    #[error("Value {0} is not a valid index")]
+    InvalidIndex(String),
+    #[error("Node {node} is a child of both node {first} and node {second}")]
+    MultipleParents {
+        node: usize,
+        first: usize,
+        second: usize,
+    },
+    #[error("Node {0} is its own ancestor")]
+    NodeCycle(usize),
+    #[error("Node {0} is a root of a scene but has a parent")]
+    NonRootSceneNode(usize),
+    #[error("Extension could not be parsed: {0}")]
+    InvalidExtension(String),
+    #[error("Value {value} is less than the minimum of {minimum}")]
+    BelowMinimum { value: f64, minimum: f64 },
+    #[error("Value {value} must be greater than {minimum}")]
+    NotAboveMinimum { value: f64, minimum: f64 },
+    #[error("Value {value} is greater than the maximum of {maximum}")]
+    AboveMaximum { value: f64, maximum: f64 },
+    #[error("String is shorter than the minimum length of {0}")]
+    TooShort(usize),
+    #[error("Value {value:?} does not match the pattern {pattern}")]
+    PatternMismatch { value: String, pattern: String },
+    #[error("Property {property} requires {dependency} to be present")]
+    MissingDependency {
+        property: String,
+        dependency: String,
+    },
+    #[error("Object has a combination of properties that is not allowed")]
+    ForbiddenCombination,
+    #[error("Object must match exactly one of its alternatives, but matches {0}")]
+    OneOfMismatch(usize),
+}
+
+/// Checks the constraints of the schema that the generated types can not express, e.g. minimum
+/// values or properties that exclude each other. Implemented by every generated type.
+pub trait Validate {
+    /// Checks this object and the objects it holds, reporting problems relative to `pointer`.
+    fn validate_at(&self, pointer: &str, errors: &mut Vec<ValidationError>);
+
+    /// Checks this object and the objects it holds, returning every problem that was found.
+    fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        self.validate_at("", &mut errors);
+        errors
+    }
+}
+
+/// Checks a number against the bounds of its schema, used by the generated [`Validate`] implementations.
+#[doc(hidden)]
+pub fn check_number(
+    pointer: &str,
+    value: f64,
+    minimum: Option<f64>,
+    exclusive_minimum: Option<f64>,
+    maximum: Option<f64>,
+    errors: &mut Vec<ValidationError>,
+) {
+    let kind = if let Some(minimum) = minimum.filter(|minimum| value < *minimum) {
+        ValidationErrorKind::BelowMinimum { value, minimum }
+    } else if let Some(minimum) = exclusive_minimum.filter(|minimum| value <= *minimum) {
+        ValidationErrorKind::NotAboveMinimum { value, minimum }
+    } else if let Some(maximum) = maximum.filter(|maximum| value > *maximum) {
+        ValidationErrorKind::AboveMaximum { value, maximum }
+    } else {
+        return;
+    };
+    errors.push(ValidationError {
+        pointer: pointer.to_string(),
+        kind,
+    });
+}
+
+/// Checks a string against the length and pattern of its schema, used by the generated
+/// [`Validate`] implementations. The length is counted in characters, as in JSON schema.
+#[doc(hidden)]
+pub fn check_string(
+    pointer: &str,
+    value: &str,
+    min_length: Option<usize>,
+    pattern: Option<&str>,
+    errors: &mut Vec<ValidationError>,
+) {
+    if let Some(min_length) = min_length.filter(|length| value.chars().count() < *length) {
+        errors.push(ValidationError {
+            pointer: pointer.to_string(),
+            kind: ValidationErrorKind::TooShort(min_length),
+        });
+    }
+
+    if let Some(pattern) = pattern.filter(|pattern| !matches_pattern(value, pattern)) {
+        errors.push(ValidationError {
+            pointer: pointer.to_string(),
+            kind: ValidationErrorKind::PatternMismatch {
+                value: value.to_string(),
+                pattern: pattern.to_string(),
+            },
+        });
+    }
+}
+
+/// Matches a value against a pattern of the schema, compiling each pattern only once.
+fn matches_pattern(value: &str, pattern: &str) -> bool {
+    static PATTERNS: OnceLock<Mutex<HashMap<String, Regex>>> = OnceLock::new();
+    let mut patterns = PATTERNS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    if !patterns.contains_key(pattern) {
+        // The patterns come from the schemas, so an invalid one is a bug in the generator
+        let regex = Regex::new(pattern).expect("schema contains an invalid pattern");
+        patterns.insert(pattern.to_string(), regex);
+    }
+    patterns[pattern].is_match(value)
+}
+
+/// Validates the references of a document and the constraints of its schema, returning every
+/// problem that was found. Extensions known to this crate are parsed so the indices they hold are
+/// checked as well.
+pub fn validate(document: &Gltf) -> Vec<ValidationError> {
+    validate_with_extensions(document, &Extensions::parse(document))
+}
+
+/// Validates a document like [`validate`], given the extensions already parsed from it.
+pub(crate) fn validate_with_extensions(
+    document: &Gltf,
+    extensions: &Extensions,
+) -> Vec<ValidationError> {
+    let mut validator = Validator {
+        document,
+        extensions,
+        errors: Vec::new(),
+    };
+    validator.references();
+    validator.extension_errors();
+    validator.extension_references();
+    validator.node_hierarchy();
+    document.validate_at("", &mut validator.errors);
+    validator.errors
+}
+
+/// Escapes a key for use in a JSON pointer.
+pub(crate) fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+struct Validator<'a> {
+    document: &'a Gltf,
+    extensions: &'a Extensions,
+    errors: Vec<ValidationError>,
+}
+
+impl<'a> Validator<'a> {
+    fn error(&mut self, pointer: String, kind: ValidationErrorKind) {
+        self.errors.push(ValidationError { pointer, kind });
+    }
+
+    /// Checks that an index refers to one of `count` objects.
+    fn index<T>(&mut self, pointer: String, index: Index<T>, count: usize) {
+        if index.value() >= count {
+            let index = index.value();
+            self.error(
+                pointer,
+                ValidationErrorKind::IndexOutOfRange { index, count },
+            );
+        }
+    }
+
+    fn optional_index<T>(&mut self, pointer: String, index: Option<Index<T>>, count: usize) {
+        if let Some(index) = index {
+            self.index(pointer, index, count);
+        }
+    }
+
+    fn indices<T>(&mut self, pointer: &str, indices: &[Index<T>], count: usize) {
+        for (position, index) in indices.iter().enumerate() {
+            self.index(format!("{pointer}/{position}"), *index, count);
+        }
+    }
+
+    /// Checks an index that is not typed in the schema, e.g. the accessors of a primitive attribute.
+    fn untyped_index(&mut self, pointer: String, value: &Value, count: usize) {
+        match value.as_u64().and_then(|index| u32::try_from(index).ok()) {
+            Some(index) => self.index(pointer, Index::<()>::new(index), count),
+            None => self.error(
+                pointer,
+                ValidationErrorKind::InvalidIndex(value.to_string()),
+            ),
+        }
+    }
+
+    fn untyped_indices(&mut self, pointer: &str, map: &Map<String, Value>, count: usize) {
+        for (key, value) in map {
+            self.untyped_index(format!("{pointer}/{}", escape(key)), value, count);
+        }
+    }
+
+    /// The extension of an object parsed into its generated type, if it has one that parsed.
+    /// Extensions that failed to parse are reported once by [`Validator::extension_errors`].
+    fn extension<T: GltfExtension + 'static>(&self, object: Object) -> Option<&'a T> {
+        self.extensions.get(object)
+    }
+
+    /// Reports the extensions that do not match the schema of their generated type.
+    fn extension_errors(&mut self) {
+        for error in self.extensions.errors() {
+            self.error(
+                error.pointer.clone(),
+                ValidationErrorKind::InvalidExtension(error.source.to_string()),
+            );
+        }
+    }
+
+    fn texture_info(&mut self, pointer: String, texture: Option<&TextureInfo>) {
+        if let Some(texture) = texture {
+            let count = self.document.textures.len();
+            self.index(format!("{pointer}/index"), texture.index, count);
+        }
+    }
+
+    fn primitive(&mut self, pointer: &str, primitive: &MeshPrimitive) {
+        let document = self.document;
+        let accessors = document.accessors.len();
+        self.untyped_indices(
+            &format!("{pointer}/attributes"),
+            &primitive.attributes,
+            accessors,
+        );
+        self.optional_index(format!("{pointer}/indices"), primitive.indices, accessors);
+        self.optional_index(
+            format!("{pointer}/material"),
+            primitive.material,
+            document.materials.len(),
+        );
+        for (index, target) in primitive.targets.iter().enumerate() {
+            self.untyped_indices(&format!("{pointer}/targets/{index}"), target, accessors);
+        }
+    }
+
+    fn references(&mut self) {
+        let document = self.document;
+        let buffer_views = document.buffer_views.len();
+        let accessors = document.accessors.len();
+        let nodes = document.nodes.len();
+
+        self.optional_index("/scene".to_string(), document.scene, document.scenes.len());
+        for (index, accessor) in document.accessors.iter().enumerate() {
+            let pointer = format!("/accessors/{index}");
+            self.optional_index(
+                format!("{pointer}/bufferView"),
+                accessor.buffer_view,
+                buffer_views,
+            );
+            if let Some(sparse) = &accessor.sparse {
+                self.index(
+                    format!("{pointer}/sparse/indices/bufferView"),
+                    sparse.indices.buffer_view,
+                    buffer_views,
+                );
+                self.index(
+                    format!("{pointer}/sparse/values/bufferView"),
+                    sparse.values.buffer_view,
+                    buffer_views,
+                );
+            }
+        }
+        for (index, animation) in document.animations.iter().enumerate() {
+            let pointer = format!("/animations/{index}");
+            for (channel_index, channel) in animation.channels.iter().enumerate() {
+                let pointer = format!("{pointer}/channels/{channel_index}");
+                let samplers = animation.samplers.len();
+                self.index(format!("{pointer}/sampler"), channel.sampler, samplers);
+                self.optional_index(format!("{pointer}/target/node"), channel.target.node, nodes);
+            }
+            for (sampler_index, sampler) in animation.samplers.iter().enumerate() {
+                let pointer = format!("{pointer}/samplers/{sampler_index}");
+                self.index(format!("{pointer}/input"), sampler.input, accessors);
+                self.index(format!("{pointer}/output"), sampler.output, accessors);
+            }
+        }
+        for (index, buffer_view) in document.buffer_views.iter().enumerate() {
+            let pointer = format!("/bufferViews/{index}/buffer");
+            self.index(pointer, buffer_view.buffer, document.buffers.len());
+        }
+        for (index, image) in document.images.iter().enumerate() {
+            let pointer = format!("/images/{index}/bufferView");
+            self.optional_index(pointer, image.buffer_view, buffer_views);
+        }
+        for (index, material) in document.materials.iter().enumerate() {
+            let pointer = format!("/materials/{index}");
+            if let Some(pbr) = &material.pbr_metallic_roughness {
+                let pbr_pointer = format!("{pointer}/pbrMetallicRoughness");
+                self.texture_info(
+                    format!("{pbr_pointer}/baseColorTexture"),
+                    pbr.base_color_texture.as_ref(),
+                );
+                self.texture_info(
+                    format!("{pbr_pointer}/metallicRoughnessTexture"),
+                    pbr.metallic_roughness_texture.as_ref(),
+                );
+            }
+            let textures = document.textures.len();
+            let normal = material
+                .normal_texture
+                .as_ref()
+                .map(|texture| texture.index);
+            self.optional_index(format!("{pointer}/normalTexture/index"), normal, textures);
+            let occlusion = material
+                .occlusion_texture
+                .as_ref()
+                .map(|texture| texture.index);
+            self.optional_index(
+                format!("{pointer}/occlusionTexture/index"),
+                occlusion,
+                textures,
+            );
+            self.texture_info(
+                format!("{pointer}/emissiveTexture"),
+                material.emissive_texture.as_ref(),
+            );
+        }
+        for (index, mesh) in document.meshes.iter().enumerate() {
+            for (primitive_index, primitive) in mesh.primitives.iter().enumerate() {
+                self.primitive(
+                    &format!("/meshes/{index}/primitives/{primitive_index}"),
+                    primitive,
+                );
+            }
+        }
+        for (index, node) in document.nodes.iter().enumerate() {
+            let pointer = format!("/nodes/{index}");
+            let cameras = document.cameras.len();
+            self.optional_index(format!("{pointer}/camera"), node.camera, cameras);
+            self.indices(&format!("{pointer}/children"), &node.children, nodes);
+            self.optional_index(format!("{pointer}/mesh"), node.mesh, document.meshes.len());
+            self.optional_index(format!("{pointer}/skin"), node.skin, document.skins.len());
+        }
+        for (index, scene) in document.scenes.iter().enumerate() {
+            self.indices(&format!("/scenes/{index}/nodes"), &scene.nodes, nodes);
+        }
+        for (index, skin) in document.skins.iter().enumerate() {
+            let pointer = format!("/skins/{index}");
+            let inverse_bind_matrices = skin.inverse_bind_matrices;
+            self.optional_index(
+                format!("{pointer}/inverseBindMatrices"),
+                inverse_bind_matrices,
+                accessors,
+            );
+            self.indices(&format!("{pointer}/joints"), &skin.joints, nodes);
+            self.optional_index(format!("{pointer}/skeleton"), skin.skeleton, nodes);
+        }
+        for (index, texture) in document.textures.iter().enumerate() {
+            let pointer = format!("/textures/{index}");
+            let samplers = document.samplers.len();
+            self.optional_index(format!("{pointer}/sampler"), texture.sampler, samplers);
+            let images = document.images.len();
+            self.optional_index(format!("{pointer}/source"), texture.source, images);
+        }
+    }
+
+    fn extension_references(&mut self) {
+        let document = self.document;
+        let accessors = document.accessors.len();
+        let images = document.images.len();
+
+        // Collections defined by extensions on the glTF root
+        let punctual_lights = self
+            .extension::<khr_lights_punctual::RootExtension>(Object::Root)
+            .map_or(0, |extension| extension.lights.len());
+        let ies_lights = self.extension::<ext_lights_ies::RootExtension>(Object::Root);
+        let image_based_lights =
+            self.extension::<ext_lights_image_based::RootExtension>(Object::Root);
+        let variants = self
+            .extension::<khr_materials_variants::RootExtension>(Object::Root)
+            .map_or(0, |extension| extension.variants.len());
+
+        if let Some(extension) = ies_lights {
+            for (index, light) in extension.lights.iter().enumerate() {
+                let pointer = format!("/extensions/EXT_lights_ies/lights/{index}/bufferView");
+                let buffer_views = document.buffer_views.len();
+                self.optional_index(pointer, light.buffer_view, buffer_views);
+            }
+        }
+        if let Some(extension) = image_based_lights {
+            for (index, light) in extension.lights.iter().enumerate() {
+                let pointer = format!("/extensions/EXT_lights_image_based/lights/{index}");
+                for (mip, faces) in light.specular_images.iter().enumerate() {
+                    self.indices(&format!("{pointer}/specularImages/{mip}"), faces, images);
+                }
+            }
+        }
+        let ies_lights = ies_lights.map_or(0, |extension| extension.lights.len());
+        let image_based_lights = image_based_lights.map_or(0, |extension| extension.lights.len());
+
+        for index in 0..document.buffer_views.len() {
+            let pointer = format!("/bufferViews/{index}");
+            if let Some(meshopt) = self.extension::<ext_meshopt_compression::BufferViewExtension>(
+                Object::BufferView(index),
+            ) {
+                self.index(
+                    format!("{pointer}/extensions/EXT_meshopt_compression/buffer"),
+                    meshopt.buffer,
+                    document.buffers.len(),
+                );
+            }
+        }
+        for index in 0..document.materials.len() {
+            let pointer = format!("/materials/{index}");
+            if let Some(lod) =
+                self.extension::<msft_lod::MaterialExtension>(Object::Material(index))
+            {
+                let pointer = format!("{pointer}/extensions/MSFT_lod/ids");
+                self.indices(&pointer, &lod.ids, document.materials.len());
+            }
+            self.material_extensions(&pointer, Object::Material(index));
+        }
+        for (index, mesh) in document.meshes.iter().enumerate() {
+            let pointer = format!("/meshes/{index}");
+            if let Some(manifold) =
+                self.extension::<ext_mesh_manifold::MeshExtension>(Object::Mesh(index))
+            {
+                let pointer = format!("{pointer}/extensions/EXT_mesh_manifold");
+                self.primitive(
+                    &format!("{pointer}/manifoldPrimitive"),
+                    &manifold.manifold_primitive,
+                );
+                let merge_indices = manifold.merge_indices;
+                self.optional_index(format!("{pointer}/mergeIndices"), merge_indices, accessors);
+                let merge_values = manifold.merge_values;
+                self.optional_index(format!("{pointer}/mergeValues"), merge_values, accessors);
+            }
+            for primitive_index in 0..mesh.primitives.len() {
+                let pointer = format!("{pointer}/primitives/{primitive_index}");
+                let object = Object::MeshPrimitive(index, primitive_index);
+                self.primitive_extensions(&pointer, object, variants);
+            }
+        }
+        for index in 0..document.nodes.len() {
+            let pointer = format!("/nodes/{index}");
+            if let Some(light) =
+                self.extension::<khr_lights_punctual::NodeExtension>(Object::Node(index))
+            {
+                let pointer = format!("{pointer}/extensions/KHR_lights_punctual/light");
+                self.index(pointer, light.light, punctual_lights);
+            }
+            if let Some(light) =
+                self.extension::<ext_lights_ies::NodeExtension>(Object::Node(index))
+            {
+                let pointer = format!("{pointer}/extensions/EXT_lights_ies/light");
+                self.index(pointer, light.light, ies_lights);
+            }
+            if let Some(lod) = self.extension::<msft_lod::NodeExtension>(Object::Node(index)) {
+                let pointer = format!("{pointer}/extensions/MSFT_lod/ids");
+                self.indices(&pointer, &lod.ids, document.nodes.len());
+            }
+            if let Some(instancing) =
+                self.extension::<ext_mesh_gpu_instancing::NodeExtension>(Object::Node(index))
+            {
+                if let Some(attributes) = &instancing.attributes {
+                    let pointer =
+                        format!("{pointer}/extensions/EXT_mesh_gpu_instancing/attributes");
+                    self.untyped_indices(&pointer, attributes, accessors);
+                }
+            }
+        }
+        for index in 0..document.scenes.len() {
+            let pointer = format!("/scenes/{index}");
+            if let Some(light) =
+                self.extension::<ext_lights_image_based::SceneExtension>(Object::Scene(index))
+            {
+                let pointer = format!("{pointer}/extensions/EXT_lights_image_based/light");
+                self.index(pointer, light.light, image_based_lights);
+            }
+        }
+        for index in 0..document.textures.len() {
+            let pointer = format!("/textures/{index}");
+            if let Some(basisu) =
+                self.extension::<khr_texture_basisu::TextureExtension>(Object::Texture(index))
+            {
+                let pointer = format!("{pointer}/extensions/KHR_texture_basisu/source");
+                self.optional_index(pointer, basisu.source, images);
+            }
+            if let Some(webp) =
+                self.extension::<ext_texture_webp::TextureExtension>(Object::Texture(index))
+            {
+                let pointer = format!("{pointer}/extensions/EXT_texture_webp/source");
+                self.optional_index(pointer, webp.source, images);
+            }
+            if let Some(dds) =
+                self.extension::<msft_texture_dds::TextureExtension>(Object::Texture(index))
+            {
+                let pointer = format!("{pointer}/extensions/MSFT_texture_dds/source");
+                self.optional_index(pointer, dds.source, images);
+            }
+        }
+
+        // KHR_xmp_json_ld does not have a generated type for the objects it extends, as it
+        // extends any object
+        let packets = self
+            .extension::<khr_xmp_json_ld::RootExtension>(Object::Root)
+            .map_or(0, |extension| extension.packets.len());
+        extension::visit_objects(document, &mut |object, extensions| {
+            let packet = extensions
+                .get("KHR_xmp_json_ld")
+                .and_then(|extension| extension.get("packet"));
+            if let Some(packet) = packet {
+                let pointer = format!("{}/extensions/KHR_xmp_json_ld/packet", object.pointer());
+                self.untyped_index(pointer, packet, packets);
+            }
+        });
+    }
+
+    /// Checks the textures referred to by the extensions of a material.
+    fn material_extensions(&mut self, pointer: &str, object: Object) {
+        let textures = self.document.textures.len();
+        let extension = |name: &str| format!("{pointer}/extensions/{name}");
+
+        if let Some(anisotropy) =
+            self.extension::<khr_materials_anisotropy::MaterialExtension>(object)
+        {
+            let pointer = extension("KHR_materials_anisotropy");
+            let texture = anisotropy.anisotropy_texture.as_ref();
+            self.texture_info(format!("{pointer}/anisotropyTexture"), texture);
+        }
+        if let Some(clearcoat) =
+            self.extension::<khr_materials_clearcoat::MaterialExtension>(object)
+        {
+            let pointer = extension("KHR_materials_clearcoat");
+            let texture = clearcoat.clearcoat_texture.as_ref();
+            self.texture_info(format!("{pointer}/clearcoatTexture"), texture);
+            let texture = clearcoat.clearcoat_roughness_texture.as_ref();
+            self.texture_info(format!("{pointer}/clearcoatRoughnessTexture"), texture);
+            let normal = clearcoat
+                .clearcoat_normal_texture
+                .as_ref()
+                .map(|texture| texture.index);
+            let normal_pointer = format!("{pointer}/clearcoatNormalTexture/index");
+            self.optional_index(normal_pointer, normal, textures);
+        }
+        if let Some(iridescence) =
+            self.extension::<khr_materials_iridescence::MaterialExtension>(object)
+        {
+            let pointer = extension("KHR_materials_iridescence");
+            let texture = iridescence.iridescence_texture.as_ref();
+            self.texture_info(format!("{pointer}/iridescenceTexture"), texture);
+            let texture = iridescence.iridescence_thickness_texture.as_ref();
+            self.texture_info(format!("{pointer}/iridescenceThicknessTexture"), texture);
+        }
+        if let Some(sheen) = self.extension::<khr_materials_sheen::MaterialExtension>(object) {
+            let pointer = extension("KHR_materials_sheen");
+            let texture = sheen.sheen_color_texture.as_ref();
+            self.texture_info(format!("{pointer}/sheenColorTexture"), texture);
+            let texture = sheen.sheen_roughness_texture.as_ref();
+            self.texture_info(format!("{pointer}/sheenRoughnessTexture"), texture);
+        }
+        if let Some(specular) = self.extension::<khr_materials_specular::MaterialExtension>(object)
+        {
+            let pointer = extension("KHR_materials_specular");
+            let texture = specular.specular_texture.as_ref();
+            self.texture_info(format!("{pointer}/specularTexture"), texture);
+            let texture = specular.specular_color_texture.as_ref();
+            self.texture_info(format!("{pointer}/specularColorTexture"), texture);
+        }
+        if let Some(transmission) =
+            self.extension::<khr_materials_transmission::MaterialExtension>(object)
+        {
+            let pointer = extension("KHR_materials_transmission");
+            let texture = transmission.transmission_texture.as_ref();
+            self.texture_info(format!("{pointer}/transmissionTexture"), texture);
+        }
+        if let Some(volume) = self.extension::<khr_materials_volume::MaterialExtension>(object) {
+            let pointer = extension("KHR_materials_volume");
+            let texture = volume.thickness_texture.as_ref();
+            self.texture_info(format!("{pointer}/thicknessTexture"), texture);
+        }
+
+        if let Some(specular) =
+            self.extension::<adobe_materials_clearcoat_specular::MaterialExtension>(object)
+        {
+            let pointer = extension("ADOBE_materials_clearcoat_specular");
+            let texture = specular.clearcoat_specular_texture.as_ref();
+            self.texture_info(format!("{pointer}/clearcoatSpecularTexture"), texture);
+        }
+        if let Some(tint) =
+            self.extension::<adobe_materials_clearcoat_tint::MaterialExtension>(object)
+        {
+            let pointer = extension("ADOBE_materials_clearcoat_tint");
+            let texture = tint.clearcoat_tint_texture.as_ref();
+            self.texture_info(format!("{pointer}/clearcoatTintTexture"), texture);
+        }
+        if let Some(transparency) =
+            self.extension::<adobe_materials_thin_transparency::MaterialExtension>(object)
+        {
+            let pointer = extension("ADOBE_materials_thin_transparency");
+            let texture = transparency.transmission_texture.as_ref();
+            self.texture_info(format!("{pointer}/transmissionTexture"), texture);
+        }
+
+        // The packing extensions do not type their texture ids
+        if let Some(packing) =
+            self.extension::<msft_packing_normal_roughness_metallic::MaterialExtension>(object)
+        {
+            let pointer = extension("MSFT_packing_normalRoughnessMetallic");
+            let index = packing
+                .normal_roughness_metallic_texture
+                .as_ref()
+                .and_then(|texture| texture.index);
+            self.untyped_texture_index(format!("{pointer}/normalRoughnessMetallicTexture"), index);
+        }
+        if let Some(packing) =
+            self.extension::<msft_packing_occlusion_roughness_metallic::MaterialExtension>(object)
+        {
+            let pointer = extension("MSFT_packing_occlusionRoughnessMetallic");
+            let slots = [
+                (
+                    "occlusionRoughnessMetallicTexture",
+                    packing
+                        .occlusion_roughness_metallic_texture
+                        .as_ref()
+                        .and_then(|texture| texture.index),
+                ),
+                (
+                    "roughnessMetallicOcclusionTexture",
+                    packing
+                        .roughness_metallic_occlusion_texture
+                        .as_ref()
+                        .and_then(|texture| texture.index),
+                ),
+                (
+                    "normalTexture",
+                    packing
+                        .normal_texture
+                        .as_ref()
+                        .and_then(|texture| texture.index),
+                ),
+            ];
+            for (slot, index) in slots {
+                self.untyped_texture_index(format!("{pointer}/{slot}"), index);
+            }
+        }
+    }
+
+    /// Checks the id of a texture that is not typed in the schema, given the pointer to the
+    /// texture object holding it.
+    fn untyped_texture_index(&mut self, pointer: String, index: Option<i64>) {
+        if let Some(index) = index {
+            let textures = self.document.textures.len();
+            self.untyped_index(format!("{pointer}/index"), &Value::from(index), textures);
+        }
+    }
+
+    fn primitive_extensions(&mut self, pointer: &str, object: Object, variants: usize) {
+        let document = self.document;
+        if let Some(draco) =
+            self.extension::<khr_draco_mesh_compression::MeshPrimitiveExtension>(object)
+        {
+            self.index(
+                format!("{pointer}/extensions/KHR_draco_mesh_compression/bufferView"),
+                draco.buffer_view,
+                document.buffer_views.len(),
+            );
+        }
+        if let Some(outline) =
+            self.extension::<cesium_primitive_outline::MeshPrimitiveExtension>(object)
+        {
+            self.optional_index(
+                format!("{pointer}/extensions/CESIUM_primitive_outline/indices"),
+                outline.indices,
+                document.accessors.len(),
+            );
+        }
+        if let Some(extension) =
+            self.extension::<khr_materials_variants::MeshPrimitiveExtension>(object)
+        {
+            for (index, mapping) in extension.mappings.iter().enumerate() {
+                let pointer =
+                    format!("{pointer}/extensions/KHR_materials_variants/mappings/{index}");
+                let materials = document.materials.len();
+                self.index(format!("{pointer}/material"), mapping.material, materials);
+                for (position, variant) in mapping.variants.iter().enumerate() {
+                    self.untyped_index(
+                        format!("{pointer}/variants/{position}"),
+                        &Value::from(*variant),
+                        variants,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Checks that every node has at most one parent, that no node is its own ancestor, and that
+    /// scenes only refer to root nodes.
+    fn node_hierarchy(&mut self) {
+        let document = self.document;
+        let count = document.nodes.len();
+
+        let mut parents: Vec<Option<usize>> = vec![None; count];
+        for (parent, node) in document.nodes.iter().enumerate() {
+            for (position, child) in node.children.iter().enumerate() {
+                let child = child.value();
+                if child >= count {
+                    continue;
+                }
+                match parents[child] {
+                    Some(first) => self.error(
+                        format!("/nodes/{parent}/children/{position}"),
+                        ValidationErrorKind::MultipleParents {
+                            node: child,
+                            first,
+                            second: parent,
+                        },
+                    ),
+                    None => parents[child] = Some(parent),
+                }
+            }
+        }
+
+        // With a single parent per node, a cycle is found by walking up from every node until a
+        // root or an already visited node is reached.
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            Unvisited,
+            InProgress,
+            Done,
+        }
+        let mut states = vec![State::Unvisited; count];
+        for start in 0..count {
+            let mut path = Vec::new();
+            let mut node = Some(start);
+            while let Some(current) = node.filter(|node| states[*node] == State::Unvisited) {
+                states[current] = State::InProgress;
+                path.push(current);
+                node = parents[current];
+            }
+            if let Some(current) = node.filter(|node| states[*node] == State::InProgress) {
+                self.error(
+                    format!("/nodes/{current}"),
+                    ValidationErrorKind::NodeCycle(current),
+                );
+            }
+            for node in path {
+                states[node] = State::Done;
+            }
+        }
+
+        for (index, scene) in document.scenes.iter().enumerate() {
+            for (position, node) in scene.nodes.iter().enumerate() {
+                let node = node.value();
+                if parents.get(node).is_some_and(Option::is_some) {
+                    self.error(
+                        format!("/scenes/{index}/nodes/{position}"),
+                        ValidationErrorKind::NonRootSceneNode(node),
+                    );
+                }
+            }
+        }
+    }
+}
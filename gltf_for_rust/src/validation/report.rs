@@ -0,0 +1,708 @@
+//! Checks an imported asset against the rules of the specification that go beyond its schema,
+//! e.g. that the `min` and `max` of an accessor match its data, and collects every problem into a
+//! [`Report`] with a severity and a stable code per problem, similar to the Khronos validator.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::accessor::{AccessorError, ComponentType, Layout};
+use crate::import::{Accessor, Gltf};
+use crate::schema::accessor::Type;
+use crate::schema::MeshPrimitive;
+use crate::semantic::Semantic;
+
+use super::{escape, validate_with_extensions, ValidationErrorKind};
+
+/// How serious a problem is, ordered from most to least severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The asset violates the specification.
+    Error,
+    /// The asset is valid but likely not what was intended, or may not work everywhere.
+    Warning,
+    /// Something that could not be checked, e.g. a buffer whose data is not available.
+    Information,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Information => "information",
+        })
+    }
+}
+
+/// Identifies the kind of a problem. The codes follow the naming of the Khronos validator where
+/// it has an equivalent check.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Code {
+    UnresolvedReference,
+    NodeParentOverride,
+    NodeLoop,
+    SceneNonRootNode,
+    InvalidExtension,
+    ValueNotInRange,
+    InvalidValue,
+    PatternMismatch,
+    UnsatisfiedDependency,
+    ForbiddenCombination,
+    OneOfMismatch,
+    BufferByteLengthMismatch,
+    BufferGlbChunkTooBig,
+    BufferViewTooLong,
+    DataUnavailable,
+    AccessorTooLong,
+    AccessorInvalidLayout,
+    AccessorInvalidSparseIndices,
+    AccessorBoundsLength,
+    AccessorMinMismatch,
+    AccessorMaxMismatch,
+    AccessorIndexOutOfRange,
+    AccessorIndexPrimitiveRestart,
+    AccessorNonIndexUnsignedInt,
+    AccessorVertexAttributeAlignment,
+    MeshPrimitiveIndicesInvalidFormat,
+    MeshPrimitiveAttributeInvalidFormat,
+    MeshPrimitivePositionWithoutBounds,
+    MeshPrimitiveUnequalAccessorCount,
+    MeshPrimitiveJointsWeightsMismatch,
+    NodeSkinWithNonSkinnedMesh,
+    NodeSkinnedMeshWithoutSkin,
+}
+
+impl Code {
+    /// The code as it appears in reports, e.g. `ACCESSOR_MIN_MISMATCH`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Code::UnresolvedReference => "UNRESOLVED_REFERENCE",
+            Code::NodeParentOverride => "NODE_PARENT_OVERRIDE",
+            Code::NodeLoop => "NODE_LOOP",
+            Code::SceneNonRootNode => "SCENE_NON_ROOT_NODE",
+            Code::InvalidExtension => "INVALID_EXTENSION",
+            Code::ValueNotInRange => "VALUE_NOT_IN_RANGE",
+            Code::InvalidValue => "INVALID_VALUE",
+            Code::PatternMismatch => "PATTERN_MISMATCH",
+            Code::UnsatisfiedDependency => "UNSATISFIED_DEPENDENCY",
+            Code::ForbiddenCombination => "FORBIDDEN_COMBINATION",
+            Code::OneOfMismatch => "ONE_OF_MISMATCH",
+            Code::BufferByteLengthMismatch => "BUFFER_BYTE_LENGTH_MISMATCH",
+            Code::BufferGlbChunkTooBig => "BUFFER_GLB_CHUNK_TOO_BIG",
+            Code::BufferViewTooLong => "BUFFER_VIEW_TOO_LONG",
+            Code::DataUnavailable => "DATA_UNAVAILABLE",
+            Code::AccessorTooLong => "ACCESSOR_TOO_LONG",
+            Code::AccessorInvalidLayout => "ACCESSOR_INVALID_LAYOUT",
+            Code::AccessorInvalidSparseIndices => "ACCESSOR_INVALID_SPARSE_INDICES",
+            Code::AccessorBoundsLength => "ACCESSOR_BOUNDS_LENGTH",
+            Code::AccessorMinMismatch => "ACCESSOR_MIN_MISMATCH",
+            Code::AccessorMaxMismatch => "ACCESSOR_MAX_MISMATCH",
+            Code::AccessorIndexOutOfRange => "ACCESSOR_INDEX_OOB",
+            Code::AccessorIndexPrimitiveRestart => "ACCESSOR_INDEX_PRIMITIVE_RESTART",
+            Code::AccessorNonIndexUnsignedInt => "ACCESSOR_NON_INDEX_UNSIGNED_INT",
+            Code::AccessorVertexAttributeAlignment => "ACCESSOR_VERTEX_ATTRIBUTE_ALIGNMENT",
+            Code::MeshPrimitiveIndicesInvalidFormat => {
+                "MESH_PRIMITIVE_INDICES_ACCESSOR_INVALID_FORMAT"
+            }
+            Code::MeshPrimitiveAttributeInvalidFormat => {
+                "MESH_PRIMITIVE_ATTRIBUTES_ACCESSOR_INVALID_FORMAT"
+            }
+            Code::MeshPrimitivePositionWithoutBounds => {
+                "MESH_PRIMITIVE_POSITION_ACCESSOR_WITHOUT_BOUNDS"
+            }
+            Code::MeshPrimitiveUnequalAccessorCount => "MESH_PRIMITIVE_UNEQUAL_ACCESSOR_COUNT",
+            Code::MeshPrimitiveJointsWeightsMismatch => "MESH_PRIMITIVE_JOINTS_WEIGHTS_MISMATCH",
+            Code::NodeSkinWithNonSkinnedMesh => "NODE_SKIN_WITH_NON_SKINNED_MESH",
+            Code::NodeSkinnedMeshWithoutSkin => "NODE_SKINNED_MESH_WITHOUT_SKIN",
+        }
+    }
+}
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl ValidationErrorKind {
+    /// The code under which this error appears in a [`Report`].
+    pub fn code(&self) -> Code {
+        match self {
+            ValidationErrorKind::IndexOutOfRange { .. } | ValidationErrorKind::InvalidIndex(_) => {
+                Code::UnresolvedReference
+            }
+            ValidationErrorKind::MultipleParents { .. } => Code::NodeParentOverride,
+            ValidationErrorKind::NodeCycle(_) => Code::NodeLoop,
+            ValidationErrorKind::NonRootSceneNode(_) => Code::SceneNonRootNode,
+            ValidationErrorKind::InvalidExtension(_) => Code::InvalidExtension,
+            ValidationErrorKind::BelowMinimum { .. }
+            | ValidationErrorKind::NotAboveMinimum { .. }
+            | ValidationErrorKind::AboveMaximum { .. } => Code::ValueNotInRange,
+            ValidationErrorKind::TooShort(_) => Code::InvalidValue,
+            ValidationErrorKind::PatternMismatch { .. } => Code::PatternMismatch,
+            ValidationErrorKind::MissingDependency { .. } => Code::UnsatisfiedDependency,
+            ValidationErrorKind::ForbiddenCombination => Code::ForbiddenCombination,
+            ValidationErrorKind::OneOfMismatch(_) => Code::OneOfMismatch,
+        }
+    }
+}
+
+/// A problem found in an asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    pub code: Code,
+    pub severity: Severity,
+    /// JSON pointer to the offending value, e.g. `/accessors/0/min/1`.
+    pub pointer: String,
+    pub message: String,
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}: {}",
+            self.severity, self.code, self.pointer, self.message
+        )
+    }
+}
+
+/// Every problem found in an asset, in the order they were found.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    pub messages: Vec<Message>,
+}
+
+impl Report {
+    /// Returns the messages of a given severity.
+    pub fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &Message> {
+        self.messages
+            .iter()
+            .filter(move |message| message.severity == severity)
+    }
+
+    /// Returns true if the asset violates the specification.
+    pub fn has_errors(&self) -> bool {
+        self.with_severity(Severity::Error).next().is_some()
+    }
+
+    /// The severity of the most serious problem, or None if no problems were found.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.messages.iter().map(|message| message.severity).min()
+    }
+}
+
+/// Validates an imported asset, including the contents of its buffers. Every problem reported by
+/// [`validate`](super::validate) is included as an error. Data that is not available, e.g. the
+/// fallback buffer of `EXT_meshopt_compression`, is reported as information and not checked
+/// further.
+pub fn report(gltf: &Gltf) -> Report {
+    let mut checker = Checker {
+        gltf,
+        messages: Vec::new(),
+    };
+    for error in validate_with_extensions(gltf.document(), gltf.extensions()) {
+        checker.messages.push(Message {
+            code: error.kind.code(),
+            severity: Severity::Error,
+            pointer: error.pointer,
+            message: error.kind.to_string(),
+        });
+    }
+    checker.buffers();
+    checker.accessors();
+    checker.meshes();
+    checker.nodes();
+    Report {
+        messages: checker.messages,
+    }
+}
+
+/// A component type and whether it is normalized.
+type Format = (ComponentType, bool);
+
+/// The accessor types and formats allowed for a vertex attribute, or None if the attribute is not
+/// defined by the specification.
+fn attribute_formats(
+    semantic: &Semantic,
+    quantized: bool,
+) -> Option<(&'static [Type], &'static [Format])> {
+    const FLOAT: Format = (ComponentType::Float, false);
+    const BYTE: Format = (ComponentType::Byte, false);
+    const UNSIGNED_BYTE: Format = (ComponentType::UnsignedByte, false);
+    const SHORT: Format = (ComponentType::Short, false);
+    const UNSIGNED_SHORT: Format = (ComponentType::UnsignedShort, false);
+    const BYTE_NORMALIZED: Format = (ComponentType::Byte, true);
+    const UNSIGNED_BYTE_NORMALIZED: Format = (ComponentType::UnsignedByte, true);
+    const SHORT_NORMALIZED: Format = (ComponentType::Short, true);
+    const UNSIGNED_SHORT_NORMALIZED: Format = (ComponentType::UnsignedShort, true);
+    const VEC2: &[Type] = &[Type::Vec2];
+    const VEC3: &[Type] = &[Type::Vec3];
+    const VEC4: &[Type] = &[Type::Vec4];
+    const COLOR: &[Type] = &[Type::Vec3, Type::Vec4];
+    const UNIT_FORMATS: &[Format] = &[FLOAT, UNSIGNED_BYTE_NORMALIZED, UNSIGNED_SHORT_NORMALIZED];
+    // KHR_mesh_quantization allows integer formats for positions, normals, tangents and texture coordinates
+    const QUANTIZED_FORMATS: &[Format] = &[
+        FLOAT,
+        BYTE,
+        BYTE_NORMALIZED,
+        UNSIGNED_BYTE,
+        UNSIGNED_BYTE_NORMALIZED,
+        SHORT,
+        SHORT_NORMALIZED,
+        UNSIGNED_SHORT,
+        UNSIGNED_SHORT_NORMALIZED,
+    ];
+    const QUANTIZED_DIRECTION_FORMATS: &[Format] = &[FLOAT, BYTE_NORMALIZED, SHORT_NORMALIZED];
+
+    Some(match (semantic, quantized) {
+        (Semantic::Position | Semantic::Normal, false) => (VEC3, &[FLOAT]),
+        (Semantic::Position, true) => (VEC3, QUANTIZED_FORMATS),
+        (Semantic::Normal, true) => (VEC3, QUANTIZED_DIRECTION_FORMATS),
+        (Semantic::Tangent, false) => (VEC4, &[FLOAT]),
+        (Semantic::Tangent, true) => (VEC4, QUANTIZED_DIRECTION_FORMATS),
+        (Semantic::TexCoord(_), false) => (VEC2, UNIT_FORMATS),
+        (Semantic::TexCoord(_), true) => (VEC2, QUANTIZED_FORMATS),
+        (Semantic::Color(_), _) => (COLOR, UNIT_FORMATS),
+        (Semantic::Joints(_), _) => (VEC4, &[UNSIGNED_BYTE, UNSIGNED_SHORT]),
+        (Semantic::Weights(_), _) => (VEC4, UNIT_FORMATS),
+        (Semantic::Custom(_) | Semantic::Other(_), _) => return None,
+    })
+}
+
+/// Reads the components of every element of an accessor as they are stored, ignoring `normalized`.
+fn read_components(accessor: &Accessor, components: usize) -> Result<Vec<f32>, AccessorError> {
+    fn read<const N: usize>(accessor: &Accessor) -> Result<Vec<f32>, AccessorError> {
+        Ok(accessor
+            .read_unnormalized::<[f32; N]>()?
+            .flatten()
+            .collect())
+    }
+
+    match components {
+        1 => read::<1>(accessor),
+        2 => read::<2>(accessor),
+        3 => read::<3>(accessor),
+        4 => read::<4>(accessor),
+        9 => read::<9>(accessor),
+        16 => read::<16>(accessor),
+        _ => unreachable!("accessor types have 1, 2, 3, 4, 9 or 16 components"),
+    }
+}
+
+struct Checker<'a> {
+    gltf: &'a Gltf,
+    messages: Vec<Message>,
+}
+
+impl Checker<'_> {
+    fn message(&mut self, severity: Severity, code: Code, pointer: String, message: String) {
+        self.messages.push(Message {
+            code,
+            severity,
+            pointer,
+            message,
+        });
+    }
+
+    fn error(&mut self, code: Code, pointer: String, message: String) {
+        self.message(Severity::Error, code, pointer, message);
+    }
+
+    /// Checks the declared lengths of the buffers against their data and buffer views.
+    fn buffers(&mut self) {
+        let document = self.gltf.document();
+        for (index, buffer) in document.buffers.iter().enumerate() {
+            let Some(data) = self.gltf.buffer_contents(index) else {
+                continue;
+            };
+            let byte_length = buffer.byte_length as usize;
+            let pointer = format!("/buffers/{index}/byteLength");
+            if buffer.uri.is_some() && data.len() != byte_length {
+                self.error(
+                    Code::BufferByteLengthMismatch,
+                    pointer,
+                    format!(
+                        "Buffer holds {} bytes but its byteLength is {byte_length}",
+                        data.len()
+                    ),
+                );
+            } else if buffer.uri.is_none() && data.len() > byte_length + 3 {
+                // The binary chunk of a GLB container is only padded to a multiple of 4 bytes
+                self.message(
+                    Severity::Warning,
+                    Code::BufferGlbChunkTooBig,
+                    pointer,
+                    format!(
+                        "Binary chunk holds {} bytes, more than the byteLength of {byte_length} and its padding",
+                        data.len()
+                    ),
+                );
+            }
+        }
+
+        for (index, view) in document.buffer_views.iter().enumerate() {
+            let Some(buffer) = document.buffers.get(view.buffer.value()) else {
+                continue;
+            };
+            let message = match view.byte_offset.checked_add(view.byte_length) {
+                Some(end) if end <= buffer.byte_length => continue,
+                Some(end) => format!(
+                    "Buffer view ends at byte {end} but its buffer is {} bytes long",
+                    buffer.byte_length
+                ),
+                None => format!(
+                    "Buffer view ends past the largest possible offset, its buffer is {} bytes long",
+                    buffer.byte_length
+                ),
+            };
+            self.error(
+                Code::BufferViewTooLong,
+                format!("/bufferViews/{index}/byteLength"),
+                message,
+            );
+        }
+    }
+
+    fn accessor_error(&mut self, pointer: String, error: AccessorError) {
+        let (severity, code) = match error {
+            AccessorError::BufferViewUnavailable(_) => {
+                (Severity::Information, Code::DataUnavailable)
+            }
+            AccessorError::OutOfBounds { .. } => (Severity::Error, Code::AccessorTooLong),
+            AccessorError::InvalidSparseIndexType(_)
+            | AccessorError::SparseIndexOutOfRange { .. }
+            | AccessorError::SparseIndicesNotIncreasing { .. } => {
+                (Severity::Error, Code::AccessorInvalidSparseIndices)
+            }
+            _ => (Severity::Error, Code::AccessorInvalidLayout),
+        };
+        self.message(severity, code, pointer, error.to_string());
+    }
+
+    /// Reads the data of every accessor, checking that it fits its buffer view and matches the
+    /// declared `min` and `max`.
+    fn accessors(&mut self) {
+        let document = self.gltf.document();
+        for accessor in self.gltf.accessors() {
+            let index = accessor.index();
+            let inner = &document.accessors[index];
+            let pointer = format!("/accessors/{index}");
+            let values = Layout::new(inner).and_then(|layout| {
+                let components = layout.components();
+                read_components(&accessor, components).map(|values| (components, values))
+            });
+            let (components, values) = match values {
+                Ok(values) => values,
+                Err(error) => {
+                    self.accessor_error(pointer, error);
+                    continue;
+                }
+            };
+
+            let bounds = [
+                ("min", "minimum", &inner.min, Code::AccessorMinMismatch),
+                ("max", "maximum", &inner.max, Code::AccessorMaxMismatch),
+            ];
+            for (name, description, declared, code) in bounds {
+                if declared.is_empty() {
+                    continue;
+                }
+                if declared.len() != components {
+                    self.error(
+                        Code::AccessorBoundsLength,
+                        format!("{pointer}/{name}"),
+                        format!(
+                            "Expected {components} values for the accessor type but found {}",
+                            declared.len()
+                        ),
+                    );
+                    continue;
+                }
+                for (component, declared) in declared.iter().enumerate() {
+                    let column = values.iter().skip(component).step_by(components).copied();
+                    let actual = match name {
+                        "min" => column.reduce(f32::min),
+                        _ => column.reduce(f32::max),
+                    };
+                    // Bounds hold the values as stored, which are at most single precision
+                    if let Some(actual) = actual.filter(|actual| *declared as f32 != *actual) {
+                        self.error(
+                            code,
+                            format!("{pointer}/{name}/{component}"),
+                            format!(
+                                "Declared {description} {declared} does not match the actual {description} {actual}"
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn meshes(&mut self) {
+        let document = self.gltf.document();
+        let quantized = document
+            .extensions_used
+            .iter()
+            .any(|extension| extension == "KHR_mesh_quantization");
+
+        let mut vertex_accessors = BTreeSet::new();
+        for (mesh_index, mesh) in document.meshes.iter().enumerate() {
+            for (primitive_index, primitive) in mesh.primitives.iter().enumerate() {
+                let pointer = format!("/meshes/{mesh_index}/primitives/{primitive_index}");
+                self.primitive(&pointer, primitive, quantized);
+
+                let targets = primitive.targets.iter();
+                let accessors = std::iter::once(&primitive.attributes)
+                    .chain(targets)
+                    .flat_map(|attributes| attributes.values())
+                    .filter_map(|index| index.as_u64().map(|index| index as usize));
+                vertex_accessors.extend(accessors);
+            }
+        }
+
+        // Vertex attributes must be aligned to 4 bytes, regardless of their component type
+        let mut reported_views = BTreeSet::new();
+        for &index in &vertex_accessors {
+            let Some(accessor) = document.accessors.get(index) else {
+                continue;
+            };
+            if accessor.byte_offset % 4 != 0 {
+                self.error(
+                    Code::AccessorVertexAttributeAlignment,
+                    format!("/accessors/{index}/byteOffset"),
+                    format!(
+                        "Offset {} of a vertex attribute is not a multiple of 4",
+                        accessor.byte_offset
+                    ),
+                );
+            }
+            let Some(view_index) = accessor.buffer_view.map(|view| view.value()) else {
+                continue;
+            };
+            let stride = document
+                .buffer_views
+                .get(view_index)
+                .and_then(|view| view.byte_stride);
+            if let Some(stride) = stride.filter(|stride| stride % 4 != 0) {
+                if reported_views.insert(view_index) {
+                    self.error(
+                        Code::AccessorVertexAttributeAlignment,
+                        format!("/bufferViews/{view_index}/byteStride"),
+                        format!("Stride {stride} of vertex attributes is not a multiple of 4"),
+                    );
+                }
+            }
+        }
+
+        // UNSIGNED_INT is only allowed for indices
+        let animations = document.animations.iter().flat_map(|animation| {
+            animation
+                .samplers
+                .iter()
+                .flat_map(|sampler| [sampler.input.value(), sampler.output.value()])
+        });
+        let skins = document
+            .skins
+            .iter()
+            .filter_map(|skin| skin.inverse_bind_matrices.map(|index| index.value()));
+        let non_index_accessors: BTreeSet<usize> = vertex_accessors
+            .into_iter()
+            .chain(animations)
+            .chain(skins)
+            .collect();
+        for index in non_index_accessors {
+            let component_type = document
+                .accessors
+                .get(index)
+                .map(|accessor| accessor.component_type);
+            if component_type == Some(ComponentType::UnsignedInt) {
+                self.error(
+                    Code::AccessorNonIndexUnsignedInt,
+                    format!("/accessors/{index}/componentType"),
+                    "UNSIGNED_INT is only allowed for accessors of indices".to_string(),
+                );
+            }
+        }
+    }
+
+    fn primitive(&mut self, pointer: &str, primitive: &MeshPrimitive, quantized: bool) {
+        let document = self.gltf.document();
+        let attributes: Vec<(&str, usize)> = primitive
+            .attributes
+            .iter()
+            .filter_map(|(semantic, index)| Some((semantic.as_str(), index.as_u64()? as usize)))
+            .filter(|(_, index)| *index < document.accessors.len())
+            .collect();
+
+        for &(semantic, index) in &attributes {
+            let accessor = &document.accessors[index];
+            let attribute_pointer = format!("{pointer}/attributes/{}", escape(semantic));
+            if let Some((types, formats)) = attribute_formats(&Semantic::from(semantic), quantized)
+            {
+                let format = (accessor.component_type, accessor.normalized);
+                if !types.contains(&accessor.ty) || !formats.contains(&format) {
+                    let normalized = if accessor.normalized {
+                        " normalized"
+                    } else {
+                        ""
+                    };
+                    self.error(
+                        Code::MeshPrimitiveAttributeInvalidFormat,
+                        attribute_pointer.clone(),
+                        format!(
+                            "Accessor {index} of type {:?} with{normalized} {:?} components is not valid for {semantic}",
+                            accessor.ty, accessor.component_type
+                        ),
+                    );
+                }
+            }
+            if semantic == "POSITION" && (accessor.min.is_empty() || accessor.max.is_empty()) {
+                self.error(
+                    Code::MeshPrimitivePositionWithoutBounds,
+                    attribute_pointer,
+                    format!("Accessor {index} of POSITION does not define min and max"),
+                );
+            }
+        }
+
+        let vertex_count = attributes
+            .first()
+            .map(|(_, index)| document.accessors[*index].count);
+        if let Some(((first_semantic, _), vertex_count)) = attributes.first().zip(vertex_count) {
+            for &(semantic, index) in &attributes[1..] {
+                let count = document.accessors[index].count;
+                if count != vertex_count {
+                    self.error(
+                        Code::MeshPrimitiveUnequalAccessorCount,
+                        format!("{pointer}/attributes/{}", escape(semantic)),
+                        format!(
+                            "Count {count} of {semantic} differs from the count {vertex_count} of {first_semantic}"
+                        ),
+                    );
+                }
+            }
+        }
+
+        // Every set of joints needs a set of weights and the other way around
+        let sets = |prefix: &str| -> BTreeSet<&str> {
+            attributes
+                .iter()
+                .filter_map(|(semantic, _)| semantic.strip_prefix(prefix))
+                .collect()
+        };
+        let joints = sets("JOINTS_");
+        let weights = sets("WEIGHTS_");
+        for set in joints.symmetric_difference(&weights) {
+            let (present, missing) = match joints.contains(set) {
+                true => ("JOINTS", "WEIGHTS"),
+                false => ("WEIGHTS", "JOINTS"),
+            };
+            self.error(
+                Code::MeshPrimitiveJointsWeightsMismatch,
+                format!("{pointer}/attributes/{present}_{set}"),
+                format!("{present}_{set} has no matching {missing}_{set}"),
+            );
+        }
+
+        if let Some(indices) = primitive.indices.map(|index| index.value()) {
+            let vertex_count = vertex_count.map(|count| count as usize);
+            self.indices(format!("{pointer}/indices"), indices, vertex_count);
+        }
+    }
+
+    /// Checks the format of an indices accessor and that every index refers to a vertex.
+    fn indices(&mut self, pointer: String, index: usize, vertex_count: Option<usize>) {
+        let Some(accessor) = self.gltf.accessor(index) else {
+            return;
+        };
+        let restart = match (accessor.component_type(), accessor.ty()) {
+            _ if accessor.normalized() => None,
+            (ComponentType::UnsignedByte, Type::Scalar) => Some(u8::MAX as u32),
+            (ComponentType::UnsignedShort, Type::Scalar) => Some(u16::MAX as u32),
+            (ComponentType::UnsignedInt, Type::Scalar) => Some(u32::MAX),
+            _ => None,
+        };
+        let Some(restart) = restart else {
+            self.error(
+                Code::MeshPrimitiveIndicesInvalidFormat,
+                pointer,
+                format!(
+                    "Accessor {index} of type {:?} with {:?} components is not valid for indices",
+                    accessor.ty(),
+                    accessor.component_type()
+                ),
+            );
+            return;
+        };
+        // Problems reading the data are reported with the accessor
+        let Ok(values) = accessor.read::<u32>() else {
+            return;
+        };
+
+        let mut out_of_range = (0, None);
+        let mut restarts = (0, None);
+        for (position, value) in values.enumerate() {
+            let counter = if value == restart {
+                &mut restarts
+            } else if vertex_count.is_some_and(|count| value as usize >= count) {
+                &mut out_of_range
+            } else {
+                continue;
+            };
+            counter.0 += 1;
+            counter.1.get_or_insert((position, value));
+        }
+
+        if let (count, Some((position, value))) = out_of_range {
+            let vertex_count = vertex_count.unwrap_or_default();
+            self.error(
+                Code::AccessorIndexOutOfRange,
+                pointer.clone(),
+                format!(
+                    "{count} indices are out of range for {vertex_count} vertices, the first is {value} at position {position}"
+                ),
+            );
+        }
+        if let (count, Some((position, _))) = restarts {
+            self.error(
+                Code::AccessorIndexPrimitiveRestart,
+                pointer,
+                format!(
+                    "{count} indices hold the primitive restart value {restart}, the first at position {position}"
+                ),
+            );
+        }
+    }
+
+    /// Checks that skinned meshes are used together with a skin.
+    fn nodes(&mut self) {
+        let document = self.gltf.document();
+        for (index, node) in document.nodes.iter().enumerate() {
+            let Some(mesh) = node.mesh.and_then(|mesh| document.get(mesh)) else {
+                continue;
+            };
+            let skinned = mesh
+                .primitives
+                .iter()
+                .any(|primitive| primitive.attributes.contains_key("JOINTS_0"));
+            let pointer = format!("/nodes/{index}");
+            match (node.skin.is_some(), skinned) {
+                (true, false) => self.error(
+                    Code::NodeSkinWithNonSkinnedMesh,
+                    format!("{pointer}/skin"),
+                    "Node has a skin but its mesh has no JOINTS_0 attribute".to_string(),
+                ),
+                (false, true) => self.message(
+                    Severity::Warning,
+                    Code::NodeSkinnedMeshWithoutSkin,
+                    format!("{pointer}/mesh"),
+                    "Node has a mesh with JOINTS_0 attributes but no skin".to_string(),
+                ),
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Exercises the public schema and extension API from outside the crate.
+
+use gltf_for_rust::extensions::*;
+use gltf_for_rust::{schema, Get, GltfExtension, GltfObject, Index, ParseExtensionError};
+
+fn extension_name<T: GltfExtension>() -> &'static str {
+    T::extension_name()
+}
+
+macro_rules! assert_extension_names {
+    ($($ty:ty => $name:literal,)*) => {
+        $(assert_eq!(extension_name::<$ty>(), $name);)*
+    };
+}
+
+#[test]
+fn extension_types_are_public() {
+    assert_extension_names! {
+        khr_animation_pointer::AnimationChannelTargetExtension => "KHR_animation_pointer",
+        khr_draco_mesh_compression::MeshPrimitiveExtension => "KHR_draco_mesh_compression",
+        khr_lights_punctual::RootExtension => "KHR_lights_punctual",
+        khr_lights_punctual::NodeExtension => "KHR_lights_punctual",
+        khr_materials_anisotropy::MaterialExtension => "KHR_materials_anisotropy",
+        khr_materials_clearcoat::MaterialExtension => "KHR_materials_clearcoat",
+        khr_materials_dispersion::MaterialExtension => "KHR_materials_dispersion",
+        khr_materials_emissive_strength::MaterialExtension => "KHR_materials_emissive_strength",
+        khr_materials_ior::MaterialExtension => "KHR_materials_ior",
+        khr_materials_iridescence::MaterialExtension => "KHR_materials_iridescence",
+        khr_materials_sheen::MaterialExtension => "KHR_materials_sheen",
+        khr_materials_specular::MaterialExtension => "KHR_materials_specular",
+        khr_materials_transmission::MaterialExtension => "KHR_materials_transmission",
+        khr_materials_unlit::MaterialExtension => "KHR_materials_unlit",
+        khr_materials_variants::RootExtension => "KHR_materials_variants",
+        khr_materials_variants::MeshPrimitiveExtension => "KHR_materials_variants",
+        khr_materials_volume::MaterialExtension => "KHR_materials_volume",
+        khr_texture_basisu::TextureExtension => "KHR_texture_basisu",
+        khr_texture_transform::TextureInfoExtension => "KHR_texture_transform",
+        khr_xmp_json_ld::RootExtension => "KHR_xmp_json_ld",
+        adobe_materials_clearcoat_specular::MaterialExtension => "ADOBE_materials_clearcoat_specular",
+        adobe_materials_clearcoat_tint::MaterialExtension => "ADOBE_materials_clearcoat_tint",
+        adobe_materials_thin_transparency::MaterialExtension => "ADOBE_materials_thin_transparency",
+        agi_articulations::RootExtension => "AGI_articulations",
+        agi_articulations::NodeExtension => "AGI_articulations",
+        agi_stk_metadata::RootExtension => "AGI_stk_metadata",
+        agi_stk_metadata::NodeExtension => "AGI_stk_metadata",
+        cesium_primitive_outline::MeshPrimitiveExtension => "CESIUM_primitive_outline",
+        ext_lights_ies::RootExtension => "EXT_lights_ies",
+        ext_lights_ies::NodeExtension => "EXT_lights_ies",
+        ext_lights_image_based::RootExtension => "EXT_lights_image_based",
+        ext_lights_image_based::SceneExtension => "EXT_lights_image_based",
+        ext_mesh_gpu_instancing::NodeExtension => "EXT_mesh_gpu_instancing",
+        ext_mesh_manifold::MeshExtension => "EXT_mesh_manifold",
+        ext_meshopt_compression::BufferExtension => "EXT_meshopt_compression",
+        ext_meshopt_compression::BufferViewExtension => "EXT_meshopt_compression",
+        ext_texture_webp::TextureExtension => "EXT_texture_webp",
+        fb_geometry_metadata::SceneExtension => "FB_geometry_metadata",
+        griffel_bim_data::RootExtension => "GRIFFEL_bim_data",
+        griffel_bim_data::NodeExtension => "GRIFFEL_bim_data",
+        msft_lod::MaterialExtension => "MSFT_lod",
+        msft_lod::NodeExtension => "MSFT_lod",
+        msft_packing_normal_roughness_metallic::MaterialExtension => "MSFT_packing_normalRoughnessMetallic",
+        msft_packing_occlusion_roughness_metallic::MaterialExtension => "MSFT_packing_occlusionRoughnessMetallic",
+        msft_texture_dds::TextureExtension => "MSFT_texture_dds",
+        nv_materials_mdl::RootExtension => "NV_materials_mdl",
+        nv_materials_mdl::MaterialExtension => "NV_materials_mdl",
+    }
+}
+
+#[test]
+fn parse_extension_with_concrete_type() {
+    let node: schema::Node = serde_json::from_str(
+        r#"{
+            "name": "lamp",
+            "extensions": { "KHR_lights_punctual": { "light": 3 } }
+        }"#,
+    )
+    .unwrap();
+
+    let light = node
+        .parse_extension::<khr_lights_punctual::NodeExtension>()
+        .unwrap();
+    assert_eq!(light.light.value(), 3);
+
+    assert!(matches!(
+        node.parse_extension::<ext_lights_ies::NodeExtension>(),
+        Err(ParseExtensionError::NotFound)
+    ));
+}
+
+#[test]
+fn embedded_schema_types_are_public() {
+    let accessor: schema::Accessor =
+        serde_json::from_str(r#"{ "componentType": 5126, "count": 3, "type": "VEC3" }"#).unwrap();
+    assert!(matches!(accessor.ty, schema::accessor::Type::Vec3));
+}
+
+#[test]
+fn indices_refer_to_typed_collections() {
+    let document: schema::Gltf = serde_json::from_str(
+        r#"{
+            "asset": { "version": "2.0" },
+            "meshes": [{ "name": "cube", "primitives": [{ "attributes": {} }] }],
+            "nodes": [{ "name": "root", "children": [1] }, { "name": "child", "mesh": 0 }],
+            "animations": [{
+                "channels": [{ "sampler": 0, "target": { "node": 1, "path": "rotation" } }],
+                "samplers": [{ "input": 0, "output": 1 }]
+            }]
+        }"#,
+    )
+    .unwrap();
+
+    let root = document.get(Index::<schema::Node>::new(0)).unwrap();
+    let child = document.get(root.children[0]).unwrap();
+    assert_eq!(child.name.as_deref(), Some("child"));
+    let mesh = document.get(child.mesh.unwrap()).unwrap();
+    assert_eq!(mesh.name.as_deref(), Some("cube"));
+    assert!(document.get(Index::<schema::Node>::new(2)).is_none());
+
+    let animation = &document.animations[0];
+    let sampler = animation.get(animation.channels[0].sampler).unwrap();
+    assert_eq!(sampler.output.value(), 1);
+
+    // Ids are never negative
+    assert!(serde_json::from_str::<schema::Node>(r#"{ "mesh": -1 }"#).is_err());
+}
@@ -0,0 +1,185 @@
+//! Checks that documents survive deserialization and serialization without losing properties.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use gltf_for_rust::schema;
+use serde_json::{json, Value};
+
+/// Compares two JSON values, treating numbers as equal when they have the same value regardless of
+/// whether they are written as integers or floats. Returns the path of the first difference.
+fn compare(expected: &Value, actual: &Value, path: &str) -> Result<(), String> {
+    match (expected, actual) {
+        (Value::Number(a), Value::Number(b)) if a.as_f64() == b.as_f64() => Ok(()),
+        (Value::Array(a), Value::Array(b)) if a.len() == b.len() => a
+            .iter()
+            .zip(b)
+            .enumerate()
+            .try_for_each(|(index, (a, b))| compare(a, b, &format!("{path}[{index}]"))),
+        (Value::Object(a), Value::Object(b)) => {
+            if let Some(key) = a
+                .keys()
+                .chain(b.keys())
+                .find(|key| a.contains_key(key.as_str()) != b.contains_key(key.as_str()))
+            {
+                return Err(format!("{path}.{key}"));
+            }
+            a.iter()
+                .try_for_each(|(key, a)| compare(a, &b[key], &format!("{path}.{key}")))
+        }
+        (a, b) if a == b => Ok(()),
+        _ => Err(path.to_string()),
+    }
+}
+
+/// Removes the object properties of `expected` that are not present in `actual`.
+fn strip_omitted(expected: &mut Value, actual: &Value) {
+    match (expected, actual) {
+        (Value::Array(a), Value::Array(b)) => {
+            a.iter_mut().zip(b).for_each(|(a, b)| strip_omitted(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.retain(|key, _| b.contains_key(key));
+            a.iter_mut().for_each(|(key, a)| strip_omitted(a, &b[key]));
+        }
+        _ => {}
+    }
+}
+
+/// Checks that a document serializes to the same JSON it was read from. Properties that hold their
+/// default value may be omitted, as long as reading the document without them gives the same result.
+fn round_trip(json: &[u8]) -> Result<(), String> {
+    let mut expected: Value = serde_json::from_slice(json).map_err(|err| err.to_string())?;
+    let document: schema::Gltf = serde_json::from_slice(json).map_err(|err| err.to_string())?;
+    let actual = serde_json::to_value(&document).map_err(|err| err.to_string())?;
+
+    strip_omitted(&mut expected, &actual);
+    compare(&expected, &actual, "$")?;
+
+    let stripped: schema::Gltf = serde_json::from_value(expected).map_err(|err| err.to_string())?;
+    match format!("{stripped:?}") == format!("{document:?}") {
+        true => Ok(()),
+        false => Err("omitted properties that do not hold their default value".to_string()),
+    }
+}
+
+/// Returns the contents of the JSON chunk of a GLB container.
+fn json_chunk(data: &[u8]) -> &[u8] {
+    let length = u32::from_le_bytes(data[12..16].try_into().unwrap()) as usize;
+    &data[20..20 + length]
+}
+
+fn collect_assets(directory: &Path, assets: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_assets(&path, assets);
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "gltf" || extension == "glb")
+        {
+            assets.push(path);
+        }
+    }
+}
+
+#[test]
+fn unknown_properties() {
+    let json = json!({
+        "asset": {"version": "2.0", "generator": "future exporter"},
+        "futureRootProperty": {"nested": [1, 2.5]},
+        "nodes": [{"name": "root", "futureNodeProperty": true}],
+        "materials": [{"pbrMetallicRoughness": {"metallicFactor": 1, "futureFactor": 0.5}}]
+    });
+    let data = serde_json::to_vec(&json).unwrap();
+    assert_eq!(round_trip(&data), Ok(()));
+
+    let document: schema::Gltf = serde_json::from_slice(&data).unwrap();
+    assert_eq!(document.nodes[0].unknown["futureNodeProperty"], json!(true));
+}
+
+#[test]
+fn extensible_int_enums() {
+    let json = json!({"magFilter": 9729, "minFilter": 1234, "wrapS": 33071});
+    let sampler: schema::Sampler = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(sampler.mag_filter, Some(schema::sampler::MagFilter::Linear));
+    assert_eq!(
+        sampler.min_filter,
+        Some(schema::sampler::MinFilter::Other(1234))
+    );
+    assert_eq!(sampler.wrap_s, schema::sampler::WrapS::ClampToEdge);
+    assert_eq!(sampler.wrap_t, schema::sampler::WrapT::Repeat);
+    assert_eq!(serde_json::to_value(&sampler).unwrap(), json);
+}
+
+#[test]
+fn extensible_string_enums() {
+    let json = json!({"node": 0, "path": "rotation"});
+    let target: schema::AnimationChannelTarget = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(
+        target.path,
+        schema::animation_channel_target::Path::Rotation
+    );
+    assert_eq!(serde_json::to_value(&target).unwrap(), json);
+
+    let json = json!({
+        "path": "pointer",
+        "extensions": {"KHR_animation_pointer": {"pointer": "/materials/0/alphaCutoff"}}
+    });
+    let target: schema::AnimationChannelTarget = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(
+        target.path,
+        schema::animation_channel_target::Path::Other("pointer".to_string())
+    );
+    assert_eq!(serde_json::to_value(&target).unwrap(), json);
+}
+
+#[test]
+fn default_values() {
+    let json = json!({
+        "asset": {"version": "2.0"},
+        "materials": [{"alphaMode": "MASK", "doubleSided": false}],
+        "nodes": [{"scale": [1, 1, 1], "translation": [0, 2, 0]}]
+    });
+    let document: schema::Gltf = serde_json::from_value(json.clone()).unwrap();
+    let material = &document.materials[0];
+    assert_eq!(material.alpha_cutoff, 0.5);
+    assert_eq!(material.emissive_factor, [0.0, 0.0, 0.0]);
+    assert_eq!(document.nodes[0].rotation, [0.0, 0.0, 0.0, 1.0]);
+
+    // Properties equal to their default value are omitted
+    let actual = serde_json::to_value(&document).unwrap();
+    assert_eq!(actual["materials"][0], json!({"alphaMode": "MASK"}));
+    assert_eq!(actual["nodes"][0], json!({"translation": [0.0, 2.0, 0.0]}));
+    assert_eq!(round_trip(&serde_json::to_vec(&json).unwrap()), Ok(()));
+
+    let mut document = document;
+    document.materials[0].alpha_cutoff = 0.25;
+    let actual = serde_json::to_value(&document).unwrap();
+    assert_eq!(actual["materials"][0]["alphaCutoff"], json!(0.25));
+}
+
+#[test]
+fn sample_assets() {
+    let mut directory = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    directory.push("../vendor/gltf_sample_assets/Models");
+
+    let mut assets = Vec::new();
+    collect_assets(&directory, &mut assets);
+    assert!(!assets.is_empty());
+
+    let failures: Vec<String> = assets
+        .iter()
+        .filter_map(|path| {
+            let data = fs::read(path).unwrap();
+            let json = match data.starts_with(b"glTF") {
+                true => json_chunk(&data),
+                false => &data,
+            };
+            round_trip(json)
+                .err()
+                .map(|err| format!("{}: {err}", path.display()))
+        })
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}